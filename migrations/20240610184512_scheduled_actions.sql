@@ -0,0 +1,14 @@
+CREATE TYPE scheduled_action AS ENUM ('unban', 'slowmode', 'unlock');
+
+CREATE TABLE scheduled_actions (
+       id           serial           NOT NULL PRIMARY KEY,
+       guild_id     TEXT             NOT NULL,
+       action       scheduled_action NOT NULL,
+       target_id    TEXT             NOT NULL,
+       payload      TEXT,
+       moderator_id TEXT             NOT NULL,
+       run_at       TIMESTAMPTZ      NOT NULL,
+       created_at   TIMESTAMPTZ      DEFAULT Now()
+);
+
+CREATE INDEX scheduled_actions_run_at_idx ON scheduled_actions (run_at);
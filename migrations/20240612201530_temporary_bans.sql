@@ -0,0 +1,3 @@
+ALTER TABLE punishments ADD COLUMN expires_at TIMESTAMPTZ;
+
+ALTER TABLE user_infractions ADD COLUMN expires_at TIMESTAMPTZ;
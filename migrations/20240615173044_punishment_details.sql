@@ -0,0 +1,13 @@
+ALTER TABLE punishments
+      ADD COLUMN guild_id     TEXT,
+      ADD COLUMN moderator_id TEXT,
+      ADD COLUMN reason       TEXT        NOT NULL DEFAULT '',
+      ADD COLUMN created_at   TIMESTAMPTZ;
+
+-- Rows logged before this migration keep a NULL creation date
+ALTER TABLE punishments ALTER COLUMN created_at SET DEFAULT Now();
+
+ALTER TABLE user_infractions
+      ADD COLUMN guild_id     TEXT,
+      ADD COLUMN moderator_id TEXT,
+      ADD COLUMN reason       TEXT        NOT NULL DEFAULT '';
@@ -0,0 +1,23 @@
+CREATE TYPE case_action AS ENUM ('kick', 'ban', 'unban', 'timeout', 'untimeout', 'strike');
+
+-- Last case number handed out per guild, so numbers are never reused after a deletion
+CREATE TABLE case_counters (
+       guild_id  TEXT    NOT NULL PRIMARY KEY,
+       last_case integer NOT NULL DEFAULT 0
+);
+
+CREATE TABLE cases (
+       id           serial      NOT NULL PRIMARY KEY,
+       guild_id     TEXT        NOT NULL,
+       case_number  integer     NOT NULL,
+       action       case_action NOT NULL,
+       user_id      TEXT        NOT NULL,
+       moderator_id TEXT        NOT NULL,
+       reason       TEXT        NOT NULL DEFAULT '',
+       created_at   TIMESTAMPTZ DEFAULT Now(),
+       UNIQUE (guild_id, case_number)
+);
+
+ALTER TABLE punishments ADD COLUMN case_id integer REFERENCES cases (id) ON DELETE CASCADE;
+
+ALTER TABLE user_infractions ADD COLUMN case_id integer REFERENCES cases (id) ON DELETE CASCADE;
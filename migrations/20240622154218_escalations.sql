@@ -0,0 +1,10 @@
+CREATE TABLE escalations (
+       id         serial     NOT NULL PRIMARY KEY,
+       guild_id   TEXT       NOT NULL,
+       severity   severity   NOT NULL,
+       threshold  integer    NOT NULL CHECK (threshold > 0),
+       days       integer    NOT NULL CHECK (days > 0),
+       punishment punishment NOT NULL,
+       duration   bigint     NOT NULL DEFAULT 0,
+       UNIQUE (guild_id, severity, threshold)
+);
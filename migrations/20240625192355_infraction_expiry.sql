@@ -0,0 +1,6 @@
+-- Number of days after which an infraction stops counting towards escalations
+ALTER TABLE infractions ADD COLUMN expires_after integer CHECK (expires_after > 0);
+
+ALTER TABLE user_infractions
+      ADD COLUMN pardoned_at TIMESTAMPTZ,
+      ADD COLUMN pardoned_by TEXT;
@@ -0,0 +1,4 @@
+CREATE TABLE mod_log_channels (
+       guild_id   TEXT NOT NULL PRIMARY KEY,
+       channel_id TEXT NOT NULL
+);
@@ -0,0 +1,12 @@
+CREATE TABLE guild_settings (
+       guild_id   TEXT NOT NULL,
+       key        TEXT NOT NULL,
+       value      TEXT NOT NULL,
+       updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+       PRIMARY KEY (guild_id, key)
+);
+
+INSERT INTO guild_settings (guild_id, key, value)
+       SELECT guild_id, 'mod_log_channel', channel_id FROM mod_log_channels;
+
+DROP TABLE mod_log_channels;
@@ -0,0 +1,53 @@
+-- Rows created before guild scoping are assigned to the only guild the bot has recorded
+-- moderation activity for. With no or several candidate guilds they are left unassigned ('').
+CREATE TEMPORARY TABLE legacy_guild AS
+       SELECT CASE WHEN COUNT(DISTINCT guild_id) = 1 THEN MIN(guild_id) ELSE '' END AS guild_id
+       FROM (
+            SELECT guild_id FROM cases
+            UNION SELECT guild_id FROM escalations
+            UNION SELECT guild_id FROM guild_settings
+            UNION SELECT guild_id FROM punishments WHERE guild_id IS NOT NULL
+            UNION SELECT guild_id FROM user_infractions WHERE guild_id IS NOT NULL
+       ) guilds;
+
+ALTER TABLE tags             ADD COLUMN guild_id TEXT;
+ALTER TABLE animals          ADD COLUMN guild_id TEXT;
+ALTER TABLE bang_points      ADD COLUMN guild_id TEXT;
+ALTER TABLE infractions      ADD COLUMN guild_id TEXT;
+
+UPDATE tags             SET guild_id = (SELECT guild_id FROM legacy_guild);
+UPDATE animals          SET guild_id = (SELECT guild_id FROM legacy_guild);
+UPDATE bang_points      SET guild_id = (SELECT guild_id FROM legacy_guild);
+UPDATE infractions      SET guild_id = (SELECT guild_id FROM legacy_guild);
+UPDATE punishments      SET guild_id = (SELECT guild_id FROM legacy_guild) WHERE guild_id IS NULL;
+UPDATE user_infractions SET guild_id = (SELECT guild_id FROM legacy_guild) WHERE guild_id IS NULL;
+
+DROP TABLE legacy_guild;
+
+ALTER TABLE tags             ALTER COLUMN guild_id SET NOT NULL;
+ALTER TABLE animals          ALTER COLUMN guild_id SET NOT NULL;
+ALTER TABLE bang_points      ALTER COLUMN guild_id SET NOT NULL;
+ALTER TABLE infractions      ALTER COLUMN guild_id SET NOT NULL;
+ALTER TABLE punishments      ALTER COLUMN guild_id SET NOT NULL;
+ALTER TABLE user_infractions ALTER COLUMN guild_id SET NOT NULL;
+
+-- Duplicated names could never be told apart, so only the oldest one is kept
+DELETE FROM tags a USING tags b WHERE a.guild_id = b.guild_id AND a.name = b.name AND a.id > b.id;
+DELETE FROM animals a USING animals b WHERE a.guild_id = b.guild_id AND a.animal = b.animal AND a.id > b.id;
+
+-- Points of duplicated users are merged into their oldest row
+UPDATE bang_points a SET points = (
+       SELECT SUM(points) FROM bang_points b WHERE b.guild_id = a.guild_id AND b.user_id = a.user_id
+);
+DELETE FROM bang_points a USING bang_points b WHERE a.guild_id = b.guild_id AND a.user_id = b.user_id AND a.id > b.id;
+
+ALTER TABLE tags        ADD UNIQUE (guild_id, name);
+ALTER TABLE animals     ADD UNIQUE (guild_id, animal);
+ALTER TABLE bang_points ADD UNIQUE (guild_id, user_id);
+
+-- Infraction rule IDs are chosen by each guild's staff, so they are only unique per guild
+ALTER TABLE infractions DROP CONSTRAINT infractions_pkey;
+ALTER TABLE infractions ADD PRIMARY KEY (guild_id, id);
+
+CREATE INDEX user_infractions_guild_user_idx ON user_infractions (guild_id, user_id);
+CREATE INDEX punishments_guild_user_idx ON punishments (guild_id, user_id);
@@ -0,0 +1,20 @@
+CREATE TYPE overwrite_kind AS ENUM ('role', 'member');
+
+-- Permission overwrites a channel had right before it was locked, restored on unlock
+CREATE TABLE channel_locks (
+       id           serial      NOT NULL PRIMARY KEY,
+       guild_id     TEXT        NOT NULL,
+       channel_id   TEXT        NOT NULL,
+       moderator_id TEXT        NOT NULL,
+       created_at   TIMESTAMPTZ NOT NULL DEFAULT Now(),
+       UNIQUE (guild_id, channel_id)
+);
+
+CREATE TABLE channel_lock_overwrites (
+       id        serial         NOT NULL PRIMARY KEY,
+       lock_id   integer        NOT NULL REFERENCES channel_locks (id) ON DELETE CASCADE,
+       kind      overwrite_kind NOT NULL,
+       target_id TEXT           NOT NULL,
+       allow     bigint         NOT NULL,
+       deny      bigint         NOT NULL
+);
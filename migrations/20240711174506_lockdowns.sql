@@ -0,0 +1,13 @@
+CREATE TABLE lockdowns (
+       guild_id       TEXT        NOT NULL PRIMARY KEY,
+       moderator_id   TEXT        NOT NULL,
+       reason         TEXT        NOT NULL DEFAULT '',
+       invites_paused boolean     NOT NULL DEFAULT false,
+       created_at     TIMESTAMPTZ NOT NULL DEFAULT Now()
+);
+
+-- Locks taken by a lockdown are restored together when it ends. `slowmode` keeps the
+-- previous rate limit when the lockdown raised it.
+ALTER TABLE channel_locks
+      ADD COLUMN lockdown boolean NOT NULL DEFAULT false,
+      ADD COLUMN slowmode integer;
@@ -0,0 +1,21 @@
+-- Messages removed by a purge, kept so they can be reviewed later, e.g. during appeals
+CREATE TABLE purge_archives (
+       id            serial      NOT NULL PRIMARY KEY,
+       guild_id      TEXT        NOT NULL,
+       channel_id    TEXT        NOT NULL,
+       moderator_id  TEXT        NOT NULL,
+       created_at    TIMESTAMPTZ NOT NULL DEFAULT Now()
+);
+
+CREATE TABLE purged_messages (
+       id          serial      NOT NULL PRIMARY KEY,
+       archive_id  integer     NOT NULL REFERENCES purge_archives (id) ON DELETE CASCADE,
+       message_id  TEXT        NOT NULL,
+       author_id   TEXT        NOT NULL,
+       author_name TEXT        NOT NULL,
+       content     TEXT        NOT NULL,
+       attachments TEXT[]      NOT NULL DEFAULT '{}',
+       sent_at     TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX purged_messages_archive_idx ON purged_messages (archive_id);
@@ -0,0 +1,4 @@
+-- Quarantine assigns the guild's quarantine role instead of removing the member
+ALTER TYPE punishment ADD VALUE 'quarantine';
+
+ALTER TYPE case_action ADD VALUE 'quarantine';
@@ -0,0 +1,13 @@
+CREATE TYPE filter_kind AS ENUM ('word', 'regex');
+
+CREATE TABLE automod_filters (
+       id         serial      NOT NULL PRIMARY KEY,
+       guild_id   TEXT        NOT NULL,
+       kind       filter_kind NOT NULL,
+       pattern    TEXT        NOT NULL,
+       created_by TEXT        NOT NULL,
+       created_at TIMESTAMPTZ NOT NULL DEFAULT Now(),
+       UNIQUE (guild_id, kind, pattern)
+);
+
+CREATE INDEX automod_filters_guild_id_idx ON automod_filters (guild_id);
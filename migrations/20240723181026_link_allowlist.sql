@@ -0,0 +1,15 @@
+CREATE TYPE allowlist_kind AS ENUM ('domain', 'invite');
+
+-- Domains and invite codes let through by the link filter. Invites are matched by code,
+-- so allowing one doesn't require resolving it through Discord.
+CREATE TABLE link_allowlist (
+       id         serial         NOT NULL PRIMARY KEY,
+       guild_id   TEXT           NOT NULL,
+       kind       allowlist_kind NOT NULL,
+       value      TEXT           NOT NULL,
+       created_by TEXT           NOT NULL,
+       created_at TIMESTAMPTZ    NOT NULL DEFAULT Now(),
+       UNIQUE (guild_id, kind, value)
+);
+
+CREATE INDEX link_allowlist_guild_id_idx ON link_allowlist (guild_id);
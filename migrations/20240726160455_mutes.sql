@@ -0,0 +1,13 @@
+ALTER TYPE scheduled_action ADD VALUE 'unmute';
+
+-- Mutes applied through the mute role, for durations beyond Discord's 28 day timeout limit.
+-- Kept until they end, so the role is given back if the member rejoins meanwhile.
+CREATE TABLE mutes (
+       guild_id     TEXT        NOT NULL,
+       user_id      TEXT        NOT NULL,
+       role_id      TEXT        NOT NULL,
+       moderator_id TEXT        NOT NULL,
+       expires_at   TIMESTAMPTZ NOT NULL,
+       created_at   TIMESTAMPTZ NOT NULL DEFAULT Now(),
+       PRIMARY KEY (guild_id, user_id)
+);
@@ -0,0 +1,6 @@
+-- Language chosen by a user with `/language set`, preferred over the server's and the client's
+CREATE TABLE user_locales (
+       user_id    TEXT        PRIMARY KEY,
+       locale     TEXT        NOT NULL,
+       updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
@@ -0,0 +1,18 @@
+CREATE TYPE appeal_status AS ENUM ('pending', 'accepted', 'denied');
+
+-- Punished members contest a case through the button of their punishment DM
+CREATE TABLE appeals (
+       id          serial        NOT NULL PRIMARY KEY,
+       guild_id    TEXT          NOT NULL,
+       case_id     integer       NOT NULL UNIQUE REFERENCES cases (id) ON DELETE CASCADE,
+       user_id     TEXT          NOT NULL,
+       content     TEXT          NOT NULL,
+       status      appeal_status NOT NULL DEFAULT 'pending',
+       reviewer_id TEXT,
+       created_at  TIMESTAMPTZ   NOT NULL DEFAULT Now(),
+       reviewed_at TIMESTAMPTZ
+);
+
+-- Accepted appeals pardon direct punishments too, so they stop counting towards escalations
+ALTER TABLE punishments ADD COLUMN pardoned_at TIMESTAMPTZ;
+ALTER TABLE punishments ADD COLUMN pardoned_by TEXT;
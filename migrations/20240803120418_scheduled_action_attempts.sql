@@ -0,0 +1,2 @@
+-- Failed actions are retried with a growing delay instead of being dropped
+ALTER TABLE scheduled_actions ADD COLUMN attempts integer NOT NULL DEFAULT 0;
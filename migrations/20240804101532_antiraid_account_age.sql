@@ -0,0 +1,2 @@
+-- An account age of 0 days spared every account, 1 day is now the minimum
+UPDATE guild_settings SET value = '1' WHERE key = 'antiraid_account_age' AND value = '0';
@@ -0,0 +1,514 @@
+//! Lets punished members contest a case. The button of a punishment DM opens a form, the appeal
+//! is posted to the guild's appeal channel, and accepting it there pardons the case and lifts
+//! what is still in effect of the punishment.
+
+use serenity::all::{
+    ActionRowComponent, ButtonStyle, ComponentInteraction, Context as SerenityContext,
+    CreateActionRow, CreateButton, CreateEmbed, CreateInputText, CreateInteractionResponse,
+    CreateInteractionResponseFollowup, CreateInteractionResponseMessage, CreateMessage,
+    CreateModal, EditInteractionResponse, EditMember, GuildId, InputTextStyle, Interaction, Member,
+    ModalInteraction, Timestamp, UserId,
+};
+
+use crate::models::{AppealModel, AppealStatus, CaseAction, CaseModel, ScheduledAction};
+use crate::modlog::{self, ModLogEntry};
+use crate::mute;
+use crate::notify;
+use crate::settings::{Setting, Settings};
+use crate::translation::tr_in;
+use crate::utils::{is_discord_error, UNKNOWN_BAN, UNKNOWN_MEMBER};
+use crate::{Data, Error};
+
+/// Button of a punishment DM, followed by the case ID
+const APPEAL: &str = "appeal";
+/// Form opened by the appeal button, followed by the case ID
+const APPEAL_FORM: &str = "appeal-form";
+/// Buttons of the appeal posted to the staff, followed by the appeal ID
+const ACCEPT: &str = "appeal-accept";
+const DENY: &str = "appeal-deny";
+
+/// Custom ID of the button that opens the appeal form of a case
+pub fn button_id(case_id: i32) -> String {
+    format!("{APPEAL}:{case_id}")
+}
+
+/// Handles the buttons and forms of the appeal workflow, ignoring every other interaction
+pub async fn on_interaction(
+    ctx: &SerenityContext,
+    data: &Data,
+    interaction: &Interaction,
+) -> Result<(), Error> {
+    match interaction {
+        Interaction::Component(component) => match parse_custom_id(&component.data.custom_id) {
+            Some((APPEAL, case_id)) => open_form(ctx, data, component, case_id).await,
+            Some((ACCEPT, appeal_id)) => {
+                review(ctx, data, component, appeal_id, AppealStatus::Accepted).await
+            }
+            Some((DENY, appeal_id)) => {
+                review(ctx, data, component, appeal_id, AppealStatus::Denied).await
+            }
+            _ => Ok(()),
+        },
+        Interaction::Modal(modal) => match parse_custom_id(&modal.data.custom_id) {
+            Some((APPEAL_FORM, case_id)) => submit(ctx, data, modal, case_id).await,
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+fn parse_custom_id(custom_id: &str) -> Option<(&str, i32)> {
+    let (kind, id) = custom_id.split_once(':')?;
+    Some((kind, id.parse().ok()?))
+}
+
+/// Ephemeral reply to an interaction
+fn reply(content: String) -> CreateInteractionResponse {
+    CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(content)
+            .ephemeral(true),
+    )
+}
+
+/// Ephemeral message sent after an interaction was acknowledged
+fn followup(content: String) -> CreateInteractionResponseFollowup {
+    CreateInteractionResponseFollowup::new()
+        .content(content)
+        .ephemeral(true)
+}
+
+/// Case the user is allowed to appeal, `None` if it was deleted or belongs to someone else
+async fn appealable_case(
+    data: &Data,
+    case_id: i32,
+    user_id: UserId,
+) -> Result<Option<CaseModel>, Error> {
+    let case = data.database.get_case_by_id(case_id).await?;
+    Ok(case.filter(|case| case.user_id == user_id.to_string()))
+}
+
+async fn open_form(
+    ctx: &SerenityContext,
+    data: &Data,
+    component: &ComponentInteraction,
+    case_id: i32,
+) -> Result<(), Error> {
+    let translations = &data.translations;
+    let user_id = component.user.id;
+
+    let case = match appealable_case(data, case_id, user_id).await? {
+        Some(case) => case,
+        None => {
+            let locale = translations.resolve_locale(Some(user_id), None, Some(&component.locale));
+            let content = tr_in!(translations, locale.as_deref(), "appeal-unknown-case");
+            component.create_response(ctx, reply(content)).await?;
+            return Ok(());
+        }
+    };
+
+    let guild_id = GuildId::new(case.guild_id.parse()?);
+    let locale =
+        translations.resolve_locale(Some(user_id), Some(guild_id), Some(&component.locale));
+    let locale = locale.as_deref();
+
+    let input = CreateInputText::new(
+        InputTextStyle::Paragraph,
+        tr_in!(translations, locale, "appeal-form-label"),
+        "content",
+    )
+    .max_length(1000);
+
+    let title =
+        tr_in!(translations, locale, "appeal-form-title", case: case.case_number.to_string());
+    let modal = CreateModal::new(format!("{APPEAL_FORM}:{case_id}"), title)
+        .components(vec![CreateActionRow::InputText(input)]);
+
+    component
+        .create_response(ctx, CreateInteractionResponse::Modal(modal))
+        .await?;
+    Ok(())
+}
+
+async fn submit(
+    ctx: &SerenityContext,
+    data: &Data,
+    modal: &ModalInteraction,
+    case_id: i32,
+) -> Result<(), Error> {
+    let translations = &data.translations;
+    let user_id = modal.user.id;
+
+    let case = match appealable_case(data, case_id, user_id).await? {
+        Some(case) => case,
+        None => {
+            let locale = translations.resolve_locale(Some(user_id), None, Some(&modal.locale));
+            let content = tr_in!(translations, locale.as_deref(), "appeal-unknown-case");
+            modal.create_response(ctx, reply(content)).await?;
+            return Ok(());
+        }
+    };
+
+    let guild_id = GuildId::new(case.guild_id.parse()?);
+    let locale = translations.resolve_locale(Some(user_id), Some(guild_id), Some(&modal.locale));
+    let locale = locale.as_deref();
+
+    let content = modal
+        .data
+        .components
+        .iter()
+        .flat_map(|row| row.components.iter())
+        .find_map(|component| match component {
+            ActionRowComponent::InputText(input) => input.value.clone(),
+            _ => None,
+        })
+        .unwrap_or_default();
+
+    let channel_id = match data
+        .settings
+        .channel(guild_id, Setting::AppealChannel)
+        .await
+    {
+        Some(channel_id) => channel_id,
+        None => {
+            let content = tr_in!(translations, locale, "appeal-disabled");
+            modal.create_response(ctx, reply(content)).await?;
+            return Ok(());
+        }
+    };
+
+    let appeal = match data
+        .database
+        .add_appeal(guild_id, case.id, user_id, &content)
+        .await?
+    {
+        Some(appeal) => appeal,
+        None => {
+            let content = tr_in!(translations, locale, "appeal-already");
+            modal.create_response(ctx, reply(content)).await?;
+            return Ok(());
+        }
+    };
+
+    // The staff reads appeals in the server's language
+    let staff_locale = translations.resolve_locale(None, Some(guild_id), None);
+    let staff_locale = staff_locale.as_deref();
+
+    let buttons = CreateActionRow::Buttons(vec![
+        CreateButton::new(format!("{ACCEPT}:{}", appeal.id))
+            .label(tr_in!(translations, staff_locale, "appeal-accept"))
+            .style(ButtonStyle::Success),
+        CreateButton::new(format!("{DENY}:{}", appeal.id))
+            .label(tr_in!(translations, staff_locale, "appeal-deny"))
+            .style(ButtonStyle::Danger),
+    ]);
+
+    let message = CreateMessage::new()
+        .embed(appeal_embed(data, staff_locale, &case, &appeal))
+        .components(vec![buttons]);
+
+    let content = match channel_id.send_message(ctx, message).await {
+        Ok(_) => tr_in!(translations, locale, "appeal-sent"),
+        Err(e) => {
+            println!("Failed to post appeal to channel {}: {}", channel_id, e);
+
+            // Nobody could review it, so the member is allowed to try again
+            data.database.remove_appeal(appeal.id).await?;
+            tr_in!(translations, locale, "appeal-failed")
+        }
+    };
+
+    modal.create_response(ctx, reply(content)).await?;
+    Ok(())
+}
+
+fn appeal_embed(
+    data: &Data,
+    locale: Option<&str>,
+    case: &CaseModel,
+    appeal: &AppealModel,
+) -> CreateEmbed {
+    let translations = &data.translations;
+
+    let reason = match case.reason.is_empty() {
+        true => tr_in!(translations, locale, "appeal-no-reason"),
+        false => case.reason.clone(),
+    };
+
+    CreateEmbed::new()
+        .title(tr_in!(translations, locale, "appeal-title", case: case.case_number.to_string()))
+        .field(
+            tr_in!(translations, locale, "appeal-field-member"),
+            format!("<@{}> (`{}`)", appeal.user_id, appeal.user_id),
+            true,
+        )
+        .field(
+            tr_in!(translations, locale, "appeal-field-action"),
+            format!("{:?}", case.action),
+            true,
+        )
+        .field(
+            tr_in!(translations, locale, "appeal-field-reason"),
+            reason,
+            false,
+        )
+        .field(
+            tr_in!(translations, locale, "appeal-field-appeal"),
+            &appeal.content,
+            false,
+        )
+        .timestamp(Timestamp::now())
+}
+
+/// Staff roles can review appeals, besides members allowed to ban or time out
+async fn is_reviewer(settings: &Settings, guild_id: GuildId, member: Option<&Member>) -> bool {
+    let member = match member {
+        Some(member) => member,
+        None => return false,
+    };
+
+    if member
+        .permissions
+        .is_some_and(|permissions| permissions.ban_members() || permissions.moderate_members())
+    {
+        return true;
+    }
+
+    let mod_roles = settings.roles(guild_id, Setting::ModRoles).await;
+    member
+        .roles
+        .iter()
+        .any(|role_id| mod_roles.contains(role_id))
+}
+
+async fn review(
+    ctx: &SerenityContext,
+    data: &Data,
+    component: &ComponentInteraction,
+    appeal_id: i32,
+    status: AppealStatus,
+) -> Result<(), Error> {
+    let translations = &data.translations;
+
+    let guild_id = match component.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Ok(()),
+    };
+
+    let reviewer_id = component.user.id;
+    let locale =
+        translations.resolve_locale(Some(reviewer_id), Some(guild_id), Some(&component.locale));
+    let locale = locale.as_deref();
+
+    // Lifting the punishment takes a few requests, longer than Discord waits for a response
+    component
+        .create_response(ctx, CreateInteractionResponse::Acknowledge)
+        .await?;
+
+    if !is_reviewer(&data.settings, guild_id, component.member.as_ref()).await {
+        let content = tr_in!(translations, locale, "appeal-not-allowed");
+        component.create_followup(ctx, followup(content)).await?;
+        return Ok(());
+    }
+
+    let appeal = match data
+        .database
+        .review_appeal(guild_id, appeal_id, status.clone(), reviewer_id)
+        .await?
+    {
+        Some(appeal) => appeal,
+        None => {
+            let content = tr_in!(translations, locale, "appeal-reviewed-already");
+            component.create_followup(ctx, followup(content)).await?;
+            return Ok(());
+        }
+    };
+
+    // Deleting a case deletes its appeals, so the case is always there
+    let case = data
+        .database
+        .get_case_by_id(appeal.case_id)
+        .await?
+        .ok_or("appealed case not found")?;
+
+    let staff_locale = translations.resolve_locale(None, Some(guild_id), None);
+    let staff_locale = staff_locale.as_deref();
+
+    if status == AppealStatus::Accepted {
+        let reason = tr_in!(
+            translations,
+            staff_locale,
+            "appeal-reversal-reason",
+            case: case.case_number.to_string()
+        );
+
+        // Reopened so it can be accepted again, instead of being stuck with the punishment
+        if let Err(e) = reverse_case(ctx, data, guild_id, &case, reviewer_id, &reason).await {
+            data.database.reopen_appeal(appeal.id).await?;
+
+            let content = tr_in!(translations, locale, "appeal-reversal-failed");
+            component.create_followup(ctx, followup(content)).await?;
+            return Err(e);
+        }
+    }
+
+    let user_id = UserId::new(appeal.user_id.parse()?);
+    let notified = notify_user(ctx, data, guild_id, user_id, &case, &status).await;
+
+    let reviewer = format!("<@{reviewer_id}>");
+    let mut lines = vec![match status {
+        AppealStatus::Accepted => {
+            tr_in!(translations, staff_locale, "appeal-status-accepted", reviewer: reviewer)
+        }
+        AppealStatus::Denied | AppealStatus::Pending => {
+            tr_in!(translations, staff_locale, "appeal-status-denied", reviewer: reviewer)
+        }
+    }];
+
+    if !notified {
+        lines.push(tr_in!(translations, staff_locale, "appeal-dm-failed"));
+    }
+
+    let embed = appeal_embed(data, staff_locale, &case, &appeal).field(
+        tr_in!(translations, staff_locale, "appeal-field-status"),
+        lines.join("\n"),
+        false,
+    );
+
+    // The buttons are removed, an appeal is only reviewed once
+    let message = EditInteractionResponse::new()
+        .embed(embed)
+        .components(vec![]);
+    component.edit_response(ctx, message).await?;
+    Ok(())
+}
+
+/// Tells the member how their appeal went, returning whether the DM was delivered
+async fn notify_user(
+    ctx: &SerenityContext,
+    data: &Data,
+    guild_id: GuildId,
+    user_id: UserId,
+    case: &CaseModel,
+    status: &AppealStatus,
+) -> bool {
+    let translations = &data.translations;
+    let locale = translations.resolve_locale(Some(user_id), Some(guild_id), None);
+    let locale = locale.as_deref();
+
+    let guild = notify::guild_name(ctx, guild_id).await;
+    let case = case.case_number.to_string();
+
+    let content = match status {
+        AppealStatus::Accepted => {
+            tr_in!(translations, locale, "appeal-accepted", case: case, guild: guild)
+        }
+        AppealStatus::Denied | AppealStatus::Pending => {
+            tr_in!(translations, locale, "appeal-denied", case: case, guild: guild)
+        }
+    };
+
+    match user_id.create_dm_channel(ctx).await {
+        Ok(channel) => channel.say(ctx, content).await.is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// Pardons the case and lifts what is still in effect of its punishment, recording the unban or
+/// untimeout as a new case
+async fn reverse_case(
+    ctx: &SerenityContext,
+    data: &Data,
+    guild_id: GuildId,
+    case: &CaseModel,
+    reviewer_id: UserId,
+    reason: &str,
+) -> Result<(), Error> {
+    let user_id = UserId::new(case.user_id.parse()?);
+
+    let reversal = match case.action {
+        CaseAction::Ban => {
+            // The ban may have expired or been lifted by hand already, any other failure leaves
+            // the member banned, so the appeal must stay open
+            let unbanned = match guild_id.unban(ctx, user_id).await {
+                Ok(()) => true,
+                Err(e) if is_discord_error(&e, &[UNKNOWN_BAN]) => false,
+                Err(e) => return Err(e.into()),
+            };
+
+            data.database.end_user_bans(guild_id, user_id).await?;
+            data.database
+                .remove_target_scheduled_actions(
+                    guild_id,
+                    ScheduledAction::Unban,
+                    user_id.to_string(),
+                )
+                .await?;
+
+            unbanned.then_some(CaseAction::Unban)
+        }
+        CaseAction::Timeout => {
+            let builder = EditMember::new()
+                .enable_communication()
+                .audit_log_reason(reason);
+            // Members that left can't be timed out anymore
+            let untimedout = match guild_id.edit_member(ctx, user_id, builder).await {
+                Ok(_) => true,
+                Err(e) if is_discord_error(&e, &[UNKNOWN_MEMBER]) => false,
+                Err(e) => return Err(e.into()),
+            };
+
+            // Timeouts beyond Discord's limit are applied through the mute role instead
+            if mute::unmute_member(&ctx.http, &data.database, guild_id, user_id).await? {
+                data.database
+                    .remove_target_scheduled_actions(
+                        guild_id,
+                        ScheduledAction::Unmute,
+                        user_id.to_string(),
+                    )
+                    .await?;
+            }
+
+            untimedout.then_some(CaseAction::Untimeout)
+        }
+        CaseAction::Quarantine => {
+            if let Some(role_id) = data.settings.role(guild_id, Setting::QuarantineRole).await {
+                let removed = ctx
+                    .http
+                    .remove_member_role(guild_id, user_id, role_id, Some(reason))
+                    .await;
+
+                // The member may have left the server
+                if let Err(e) = removed {
+                    if !is_discord_error(&e, &[UNKNOWN_MEMBER]) {
+                        return Err(e.into());
+                    }
+                }
+            }
+
+            None
+        }
+        CaseAction::Kick | CaseAction::Strike | CaseAction::Unban | CaseAction::Untimeout => None,
+    };
+
+    // Pardoned last, so a failure above leaves the case as it was
+    data.database.pardon_case(case.id, reviewer_id).await?;
+
+    if let Some(action) = reversal {
+        let case = data
+            .database
+            .log_case(guild_id, action, &user_id, reviewer_id, reason)
+            .await?;
+
+        modlog::send(
+            ctx,
+            &data.settings,
+            &data.translations,
+            guild_id,
+            ModLogEntry::case(&case),
+        )
+        .await;
+    }
+
+    Ok(())
+}
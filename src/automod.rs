@@ -0,0 +1,308 @@
+//! Automatic moderation driven by gateway events. Punishments issued here are recorded as
+//! cases with the bot as the moderator, just like the ones issued through commands.
+
+pub mod antiraid;
+pub mod filter;
+pub mod gate;
+pub mod links;
+pub mod spam;
+
+use serenity::all::{ChannelId, Context as SerenityContext, GuildId, Message, UserId};
+
+use crate::commands::moderation;
+use crate::models::{Punishment, Severity};
+use crate::modlog::{self, ModLogAction, ModLogEntry};
+use crate::settings::{Setting, Settings};
+use crate::translation::tr_in;
+use crate::{Data, Error};
+
+/// Runs every message rule against a new message
+pub async fn on_message(
+    ctx: &SerenityContext,
+    data: &Data,
+    message: &Message,
+) -> Result<(), Error> {
+    if message.author.bot || message.guild_id.is_none() {
+        return Ok(());
+    }
+
+    if filter::check(ctx, data, message).await? || links::check(ctx, data, message).await? {
+        return Ok(());
+    }
+
+    spam::check(ctx, data, message).await?;
+    Ok(())
+}
+
+/// Runs the content rules against an edited message. Spam is only tracked for new messages.
+pub async fn on_message_edit(
+    ctx: &SerenityContext,
+    data: &Data,
+    message: &Message,
+) -> Result<(), Error> {
+    if message.author.bot || message.guild_id.is_none() {
+        return Ok(());
+    }
+
+    if !filter::check(ctx, data, message).await? {
+        links::check(ctx, data, message).await?;
+    }
+
+    Ok(())
+}
+
+/// Whether the message was sent in an exempt channel or by a member with an exempt role
+pub async fn is_exempt(ctx: &SerenityContext, settings: &Settings, message: &Message) -> bool {
+    let guild_id = match message.guild_id {
+        Some(guild_id) => guild_id,
+        None => return true,
+    };
+
+    let channels = settings
+        .channels(guild_id, Setting::AutomodExemptChannels)
+        .await;
+
+    if channels.contains(&message.channel_id) {
+        return true;
+    }
+
+    let mut exempt_roles = settings.roles(guild_id, Setting::ModRoles).await;
+    exempt_roles.extend(settings.roles(guild_id, Setting::AutomodExemptRoles).await);
+
+    if exempt_roles.is_empty() {
+        return false;
+    }
+
+    let roles = match &message.member {
+        Some(member) => member.roles.clone(),
+        None => match guild_id.member(ctx, message.author.id).await {
+            Ok(member) => member.roles,
+            Err(_) => return false,
+        },
+    };
+
+    roles.iter().any(|role_id| exempt_roles.contains(role_id))
+}
+
+/// Deletes a message that broke a rule, logs it and applies the guild's automod action
+pub async fn enforce(
+    ctx: &SerenityContext,
+    data: &Data,
+    message: &Message,
+    reason: &str,
+) -> Result<(), Error> {
+    let guild_id = message.guild_id.ok_or("message was not sent in a guild")?;
+    let user_id = message.author.id;
+
+    message.delete(ctx).await?;
+
+    // Embed fields are limited to 1024 characters
+    let content = message.content.chars().take(900).collect::<String>();
+
+    let locale = data.translations.resolve_locale(None, Some(guild_id), None);
+    let channel = format!("<#{}>", message.channel_id);
+    let deleted = tr_in!(data.translations, locale.as_deref(), "automod-deleted", channel: channel);
+
+    let entry = ModLogEntry::member(ModLogAction::Automod, user_id, ctx.cache.current_user().id)
+        .reason(reason)
+        .details(format!("{deleted}\n{content}"));
+    modlog::send(ctx, &data.settings, &data.translations, guild_id, entry).await;
+
+    apply_action(ctx, data, guild_id, message.channel_id, user_id, reason).await
+}
+
+/// Applies the action configured for automod violations to the author of a deleted message
+pub async fn apply_action(
+    ctx: &SerenityContext,
+    data: &Data,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    user_id: UserId,
+    reason: &str,
+) -> Result<(), Error> {
+    let action = data
+        .settings
+        .get(guild_id, Setting::AutomodAction)
+        .await
+        .unwrap_or_default();
+
+    match action.as_str() {
+        "warn" => {
+            // Said in the channel, so it follows the server's language
+            let locale = data.translations.resolve_locale(None, Some(guild_id), None);
+            let warning = tr_in!(
+                data.translations,
+                locale.as_deref(),
+                "automod-warning",
+                user: format!("<@{user_id}>"),
+                reason: reason
+            );
+            channel_id.say(ctx, warning).await?;
+        }
+        "strike" => {
+            punish(
+                ctx,
+                data,
+                guild_id,
+                user_id,
+                Punishment::Strike,
+                0,
+                reason,
+                None,
+            )
+            .await?;
+        }
+        "timeout" => {
+            let duration = data
+                .settings
+                .number(guild_id, Setting::AutomodTimeout)
+                .await
+                .unwrap_or(600);
+
+            punish(
+                ctx,
+                data,
+                guild_id,
+                user_id,
+                Punishment::Timeout,
+                duration as i64,
+                reason,
+                None,
+            )
+            .await?;
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Punishes the member for one of the guild's infractions, then applies the escalation ladder
+pub async fn punish_infraction(
+    ctx: &SerenityContext,
+    data: &Data,
+    guild_id: GuildId,
+    user_id: UserId,
+    infraction_id: i32,
+    reason: &str,
+) -> Result<(), Error> {
+    let infraction = data
+        .database
+        .get_infraction(guild_id, infraction_id)
+        .await?;
+
+    punish(
+        ctx,
+        data,
+        guild_id,
+        user_id,
+        infraction.punishment,
+        infraction.duration,
+        reason,
+        Some(infraction.id),
+    )
+    .await?;
+
+    escalate(ctx, data, guild_id, user_id, infraction.severity).await
+}
+
+/// Applies the guild's escalation ladder, with the reason in the server's language
+async fn escalate(
+    ctx: &SerenityContext,
+    data: &Data,
+    guild_id: GuildId,
+    user_id: UserId,
+    severity: Severity,
+) -> Result<(), Error> {
+    let locale = data.translations.resolve_locale(None, Some(guild_id), None);
+    let moderator_id = ctx.cache.current_user().id;
+
+    moderation::escalate_users(
+        &ctx.http,
+        data,
+        moderator_id,
+        guild_id,
+        &[user_id],
+        severity,
+        locale.as_deref(),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Applies the punishment and records it, on its own or as a hit of the given infraction.
+/// `duration` is in seconds and only used by timeouts and bans.
+#[allow(clippy::too_many_arguments)]
+pub async fn punish(
+    ctx: &SerenityContext,
+    data: &Data,
+    guild_id: GuildId,
+    user_id: UserId,
+    punishment: Punishment,
+    duration: i64,
+    reason: &str,
+    infraction: Option<i32>,
+) -> Result<(), Error> {
+    let http = &ctx.http;
+    let moderator_id = ctx.cache.current_user().id;
+    let user = [user_id];
+
+    let (punished, _, _) = match punishment {
+        Punishment::Strike => {
+            moderation::strike_users(http, data, moderator_id, guild_id, user, reason, infraction)
+                .await?
+        }
+        Punishment::Timeout => {
+            moderation::timeout_users(
+                http,
+                data,
+                moderator_id,
+                guild_id,
+                user,
+                duration,
+                reason,
+                infraction,
+            )
+            .await?
+        }
+        Punishment::Kick => {
+            moderation::kick_users(http, data, moderator_id, guild_id, user, reason, infraction)
+                .await?
+        }
+        Punishment::Ban => {
+            let duration = Some(duration).filter(|d| *d > 0);
+            moderation::ban_users(
+                http,
+                data,
+                moderator_id,
+                guild_id,
+                user,
+                reason,
+                duration,
+                infraction,
+            )
+            .await?
+        }
+        Punishment::Quarantine => {
+            moderation::quarantine_users(
+                http,
+                data,
+                moderator_id,
+                guild_id,
+                user,
+                reason,
+                infraction,
+            )
+            .await?
+        }
+    };
+
+    if punished.is_empty() {
+        return Err(format!("could not {:?} the member", punishment)
+            .to_lowercase()
+            .into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,303 @@
+//! Detects raids by counting member joins per guild in a sliding window. Once the configured
+//! threshold is reached the guild stays in raid mode until a full window passes without
+//! joins, and every member joining meanwhile is handled as part of the raid.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use serenity::all::{Context as SerenityContext, GuildId, Member, UserId};
+use sqlx::types::chrono::Utc;
+use tokio::sync::Mutex;
+
+use crate::automod;
+use crate::lock;
+use crate::models::Punishment;
+use crate::modlog::{self, ModLogAction, ModLogEntry};
+use crate::settings::Setting;
+use crate::translation::tr_in;
+use crate::{Data, Error};
+
+/// How long new accounts caught in a raid are timed out for
+const RAID_TIMEOUT: i64 = 60 * 60 * 24;
+
+#[derive(Default)]
+pub struct RaidMonitor {
+    guilds: Mutex<HashMap<GuildId, JoinWindow>>,
+}
+
+#[derive(Default)]
+struct JoinWindow {
+    joins: VecDeque<(Instant, UserId)>,
+    raid_until: Option<Instant>,
+}
+
+enum RaidState {
+    Calm,
+    /// The join crossed the threshold, holds every member that joined within the window
+    Started(Vec<UserId>),
+    Ongoing,
+}
+
+impl RaidMonitor {
+    /// Records a join that happened at `now`
+    async fn record_join(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        threshold: usize,
+        window: Duration,
+        now: Instant,
+    ) -> RaidState {
+        let mut guilds = self.guilds.lock().await;
+        let guild = guilds.entry(guild_id).or_default();
+
+        guild.joins.push_back((now, user_id));
+
+        while guild
+            .joins
+            .front()
+            .is_some_and(|(joined_at, _)| now.duration_since(*joined_at) > window)
+        {
+            guild.joins.pop_front();
+        }
+
+        if guild.raid_until.is_some_and(|until| now < until) {
+            guild.raid_until = Some(now + window);
+            return RaidState::Ongoing;
+        }
+
+        if guild.joins.len() < threshold {
+            return RaidState::Calm;
+        }
+
+        guild.raid_until = Some(now + window);
+        RaidState::Started(guild.joins.iter().map(|(_, user_id)| *user_id).collect())
+    }
+}
+
+pub async fn on_member_join(
+    ctx: &SerenityContext,
+    data: &Data,
+    member: &Member,
+) -> Result<(), Error> {
+    let guild_id = member.guild_id;
+    let settings = &data.settings;
+
+    let threshold = match settings.number(guild_id, Setting::AntiRaidThreshold).await {
+        Some(threshold) => threshold as usize,
+        None => return Ok(()),
+    };
+    let window = settings
+        .number(guild_id, Setting::AntiRaidWindow)
+        .await
+        .unwrap_or(10);
+    let action = settings
+        .get(guild_id, Setting::AntiRaidAction)
+        .await
+        .unwrap_or_default();
+
+    let state = data
+        .raid_monitor
+        .record_join(
+            guild_id,
+            member.user.id,
+            threshold,
+            Duration::from_secs(window),
+            Instant::now(),
+        )
+        .await;
+
+    let bot_id = ctx.cache.current_user().id;
+
+    // Reasons are DMed to the raiders and logged, so they follow the server's language
+    let translations = &data.translations;
+    let locale = translations.resolve_locale(None, Some(guild_id), None);
+    let locale = locale.as_deref();
+
+    let targets = match state {
+        RaidState::Calm => return Ok(()),
+        RaidState::Ongoing => vec![member.user.id],
+        RaidState::Started(joined) => {
+            let details = tr_in!(
+                translations,
+                locale,
+                "automod-raid-details",
+                count: joined.len(),
+                seconds: window,
+                action: action.as_str()
+            );
+            let entry = ModLogEntry::guild(ModLogAction::Raid, bot_id)
+                .reason(tr_in!(translations, locale, "automod-raid-detected"))
+                .details(details)
+                .mentions(settings.roles(guild_id, Setting::ModRoles).await);
+            modlog::send(ctx, settings, translations, guild_id, entry).await;
+
+            if action == "lockdown" && data.database.get_lockdown(guild_id).await?.is_none() {
+                let reason = tr_in!(translations, locale, "automod-raid-lockdown-reason");
+                let (locked, not_locked) = lock::start_lockdown(
+                    &ctx.http,
+                    &data.database,
+                    settings,
+                    translations,
+                    guild_id,
+                    bot_id,
+                    &reason,
+                    None,
+                )
+                .await?;
+
+                let details = tr_in!(
+                    translations,
+                    locale,
+                    "lockdown-modlog-start",
+                    locked: locked.len(),
+                    failed: not_locked.len()
+                );
+                let entry = ModLogEntry::guild(ModLogAction::LockdownStart, bot_id)
+                    .reason(reason)
+                    .details(details);
+                modlog::send(ctx, settings, translations, guild_id, entry).await;
+            }
+
+            joined
+        }
+    };
+
+    if action != "timeout" && action != "kick" {
+        return Ok(());
+    }
+
+    let max_age = settings
+        .number(guild_id, Setting::AntiRaidAccountAge)
+        .await
+        .unwrap_or(7) as i64
+        * 60
+        * 60
+        * 24;
+
+    let raid_reason = tr_in!(translations, locale, "automod-raid-reason");
+
+    for user_id in targets.into_iter() {
+        // Established accounts are rarely part of a raid, only new ones are punished
+        if Utc::now().timestamp() - user_id.created_at().unix_timestamp() >= max_age {
+            continue;
+        }
+
+        let (punishment, duration) = match action.as_str() {
+            "timeout" => (Punishment::Timeout, RAID_TIMEOUT),
+            _ => (Punishment::Kick, 0),
+        };
+
+        let result = automod::punish(
+            ctx,
+            data,
+            guild_id,
+            user_id,
+            punishment,
+            duration,
+            &raid_reason,
+            None,
+        )
+        .await;
+
+        if let Err(e) = result {
+            println!("Failed to {} raider {}: {}", action, user_id, e);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GUILD: GuildId = GuildId::new(1);
+    const WINDOW: Duration = Duration::from_secs(10);
+
+    async fn join(
+        monitor: &RaidMonitor,
+        user: u64,
+        threshold: usize,
+        window: Duration,
+        at: Instant,
+    ) -> RaidState {
+        monitor
+            .record_join(GUILD, UserId::new(user), threshold, window, at)
+            .await
+    }
+
+    #[tokio::test]
+    async fn raid_starts_at_exactly_the_threshold() {
+        let monitor = RaidMonitor::default();
+        let now = Instant::now();
+
+        assert!(matches!(
+            join(&monitor, 1, 3, WINDOW, now).await,
+            RaidState::Calm
+        ));
+        assert!(matches!(
+            join(&monitor, 2, 3, WINDOW, now).await,
+            RaidState::Calm
+        ));
+
+        match join(&monitor, 3, 3, WINDOW, now).await {
+            RaidState::Started(joined) => {
+                assert_eq!(joined, vec![UserId::new(1), UserId::new(2), UserId::new(3)])
+            }
+            _ => panic!("the third join should start the raid"),
+        }
+    }
+
+    #[tokio::test]
+    async fn joins_during_a_raid_are_ongoing() {
+        let monitor = RaidMonitor::default();
+        let now = Instant::now();
+
+        join(&monitor, 1, 2, WINDOW, now).await;
+        assert!(matches!(
+            join(&monitor, 2, 2, WINDOW, now).await,
+            RaidState::Started(_)
+        ));
+        assert!(matches!(
+            join(&monitor, 3, 2, WINDOW, now).await,
+            RaidState::Ongoing
+        ));
+        assert!(matches!(
+            join(&monitor, 4, 2, WINDOW, now).await,
+            RaidState::Ongoing
+        ));
+    }
+
+    #[tokio::test]
+    async fn raid_ends_after_a_quiet_window() {
+        let monitor = RaidMonitor::default();
+        let now = Instant::now();
+
+        join(&monitor, 1, 2, WINDOW, now).await;
+        assert!(matches!(
+            join(&monitor, 2, 2, WINDOW, now).await,
+            RaidState::Started(_)
+        ));
+
+        // The earlier joins left the window too, so a single join is calm again
+        let later = now + WINDOW * 2;
+        assert!(matches!(
+            join(&monitor, 3, 2, WINDOW, later).await,
+            RaidState::Calm
+        ));
+    }
+
+    #[tokio::test]
+    async fn guilds_are_tracked_separately() {
+        let monitor = RaidMonitor::default();
+        let now = Instant::now();
+
+        join(&monitor, 1, 2, WINDOW, now).await;
+        let other = monitor
+            .record_join(GuildId::new(2), UserId::new(2), 2, WINDOW, now)
+            .await;
+
+        assert!(matches!(other, RaidState::Calm));
+    }
+}
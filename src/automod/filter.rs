@@ -0,0 +1,233 @@
+//! Deletes messages containing blocked words or matching blocked patterns. The filters of a
+//! guild are compiled once and cached until they change.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use regex::Regex;
+use serenity::all::{Context as SerenityContext, GuildId, Message};
+use tokio::sync::RwLock;
+
+use crate::automod;
+use crate::database::Database;
+use crate::models::{AutomodFilterModel, FilterKind};
+use crate::translation::tr_in;
+use crate::{Data, Error};
+
+/// Why a message was blocked
+#[derive(Debug, PartialEq)]
+enum Blocked {
+    /// The blocked word, as written in the message
+    Word(String),
+    /// Patterns aren't named, they'd tell the member how to get around them
+    Pattern,
+}
+
+struct CompiledFilters {
+    /// Every blocked word joined into a single case insensitive pattern, capturing the word
+    words: Option<Regex>,
+    patterns: Vec<Regex>,
+}
+
+impl CompiledFilters {
+    fn new(filters: &[AutomodFilterModel]) -> Self {
+        let words = filters
+            .iter()
+            .filter(|filter| filter.kind == FilterKind::Word)
+            .map(|filter| regex::escape(&filter.pattern))
+            .collect::<Vec<_>>();
+
+        // `\b` needs a letter or digit on the inside, so words like `:)` or `@here` never matched
+        let words = match words.is_empty() {
+            true => None,
+            false => Regex::new(&format!(r"(?i)(?:^|\W)({})(?:$|\W)", words.join("|"))).ok(),
+        };
+
+        // Patterns are validated when added, one that stopped compiling is just skipped
+        let patterns = filters
+            .iter()
+            .filter(|filter| filter.kind == FilterKind::Regex)
+            .filter_map(|filter| Regex::new(&filter.pattern).ok())
+            .collect();
+
+        Self { words, patterns }
+    }
+
+    /// Returns why the content is blocked, if it is
+    fn check(&self, content: &str) -> Option<Blocked> {
+        if let Some(captures) = self
+            .words
+            .as_ref()
+            .and_then(|words| words.captures(content))
+        {
+            return Some(Blocked::Word(captures[1].to_owned()));
+        }
+
+        self.patterns
+            .iter()
+            .any(|pattern| pattern.is_match(content))
+            .then_some(Blocked::Pattern)
+    }
+}
+
+pub struct FilterCache {
+    database: Arc<Database>,
+    cache: RwLock<HashMap<GuildId, Arc<CompiledFilters>>>,
+}
+
+impl FilterCache {
+    pub fn new(database: Arc<Database>) -> Self {
+        Self {
+            database,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    async fn get(&self, guild_id: GuildId) -> Result<Arc<CompiledFilters>, Error> {
+        if let Some(filters) = self.cache.read().await.get(&guild_id) {
+            return Ok(Arc::clone(filters));
+        }
+
+        let filters = self.database.get_automod_filters(guild_id).await?;
+        let filters = Arc::new(CompiledFilters::new(&filters));
+
+        self.cache
+            .write()
+            .await
+            .insert(guild_id, Arc::clone(&filters));
+
+        Ok(filters)
+    }
+
+    /// Forgets the compiled filters of the guild, so the next message loads them again
+    pub async fn invalidate(&self, guild_id: GuildId) {
+        self.cache.write().await.remove(&guild_id);
+    }
+}
+
+/// Returns whether the message was blocked
+pub async fn check(ctx: &SerenityContext, data: &Data, message: &Message) -> Result<bool, Error> {
+    let guild_id = message.guild_id.ok_or("message was not sent in a guild")?;
+
+    let blocked = match data.filters.get(guild_id).await?.check(&message.content) {
+        Some(blocked) => blocked,
+        None => return Ok(false),
+    };
+
+    if automod::is_exempt(ctx, &data.settings, message).await {
+        return Ok(false);
+    }
+
+    // The reason is shown to the member, so it follows the server's language
+    let locale = data.translations.resolve_locale(None, Some(guild_id), None);
+    let reason = match blocked {
+        Blocked::Word(word) => {
+            tr_in!(data.translations, locale.as_deref(), "automod-blocked-word", word: word)
+        }
+        Blocked::Pattern => tr_in!(
+            data.translations,
+            locale.as_deref(),
+            "automod-blocked-pattern"
+        ),
+    };
+
+    automod::enforce(ctx, data, message, &reason).await?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::types::chrono::Utc;
+
+    fn filters(words: &[&str], patterns: &[&str]) -> CompiledFilters {
+        let filter = |kind: FilterKind, pattern: &&str| AutomodFilterModel {
+            id: 1,
+            guild_id: "1".to_owned(),
+            kind,
+            pattern: pattern.to_string(),
+            created_by: "1".to_owned(),
+            created_at: Utc::now(),
+        };
+
+        let filters = words
+            .iter()
+            .map(|word| filter(FilterKind::Word, word))
+            .chain(
+                patterns
+                    .iter()
+                    .map(|pattern| filter(FilterKind::Regex, pattern)),
+            )
+            .collect::<Vec<_>>();
+
+        CompiledFilters::new(&filters)
+    }
+
+    #[test]
+    fn words_match_whole_words_only() {
+        let filters = filters(&["ass"], &[]);
+
+        assert!(filters.check("you ass").is_some());
+        assert!(filters.check("ass.").is_some());
+        assert!(filters.check("(ass)").is_some());
+        assert!(filters.check("class").is_none());
+        assert!(filters.check("assess").is_none());
+    }
+
+    #[test]
+    fn words_are_case_insensitive() {
+        let filters = filters(&["spam"], &[]);
+
+        assert_eq!(
+            filters.check("SPAM here"),
+            Some(Blocked::Word("SPAM".to_owned()))
+        );
+    }
+
+    #[test]
+    fn words_with_symbols_match() {
+        let filters = filters(&["f*ck", ":)", "@here"], &[]);
+
+        assert_eq!(
+            filters.check("oh f*ck"),
+            Some(Blocked::Word("f*ck".to_owned()))
+        );
+        assert_eq!(filters.check(":)"), Some(Blocked::Word(":)".to_owned())));
+        assert_eq!(
+            filters.check("hey @here!"),
+            Some(Blocked::Word("@here".to_owned()))
+        );
+        assert!(filters.check("fuck").is_none());
+        assert!(filters.check("a@here").is_none());
+    }
+
+    #[test]
+    fn words_are_not_regexes() {
+        let filters = filters(&["a.c"], &[]);
+
+        assert!(filters.check("a.c").is_some());
+        assert!(filters.check("abc").is_none());
+    }
+
+    #[test]
+    fn patterns_match_anywhere() {
+        let filters = filters(&[], &[r"discord\.gg/\w+"]);
+
+        assert_eq!(filters.check("join discord.gg/abc"), Some(Blocked::Pattern));
+        assert!(filters.check("discord.gg/").is_none());
+    }
+
+    #[test]
+    fn invalid_patterns_are_skipped() {
+        let filters = filters(&["bad"], &["(", "good"]);
+
+        assert!(filters.check("bad").is_some());
+        assert!(filters.check("good").is_some());
+        assert!(filters.check("(").is_none());
+    }
+
+    #[test]
+    fn no_filters_match_nothing() {
+        assert!(filters(&[], &[]).check("anything").is_none());
+    }
+}
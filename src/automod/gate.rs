@@ -0,0 +1,93 @@
+//! Screens members as they join, stopping accounts that look disposable before they can
+//! interact with the server
+
+use regex::Regex;
+use serenity::all::{Context as SerenityContext, Member};
+use sqlx::types::chrono::Utc;
+
+use crate::automod;
+use crate::models::Punishment;
+use crate::settings::Setting;
+use crate::translation::tr_in;
+use crate::{Data, Error};
+
+/// How long members stopped at the gate are timed out for
+const GATE_TIMEOUT: i64 = 60 * 60 * 24;
+
+pub async fn on_member_join(
+    ctx: &SerenityContext,
+    data: &Data,
+    member: &Member,
+) -> Result<(), Error> {
+    if member.user.bot {
+        return Ok(());
+    }
+
+    let guild_id = member.guild_id;
+    let settings = &data.settings;
+
+    // The reason is DMed to the member, so it follows the server's language
+    let translations = &data.translations;
+    let locale = translations.resolve_locale(None, Some(guild_id), None);
+    let locale = locale.as_deref();
+
+    let mut failed = vec![];
+
+    if let Some(days) = settings.number(guild_id, Setting::GateAccountAge).await {
+        let age = Utc::now().timestamp() - member.user.id.created_at().unix_timestamp();
+
+        if age < days as i64 * 60 * 60 * 24 {
+            failed.push(tr_in!(translations, locale, "gate-account-age", days: days));
+        }
+    }
+
+    if settings.flag(guild_id, Setting::GateDefaultAvatar).await && member.user.avatar.is_none() {
+        failed.push(tr_in!(translations, locale, "gate-default-avatar"));
+    }
+
+    if let Some(pattern) = settings.get(guild_id, Setting::GateUsernamePattern).await {
+        let regex = Regex::new(&pattern)?;
+        let names = [Some(&member.user.name), member.user.global_name.as_ref()];
+
+        if names.into_iter().flatten().any(|name| regex.is_match(name)) {
+            failed.push(tr_in!(translations, locale, "gate-username"));
+        }
+    }
+
+    if failed.is_empty() {
+        return Ok(());
+    }
+
+    let reason = tr_in!(
+        translations,
+        locale,
+        "gate-reason",
+        checks: failed.join(", ")
+    );
+    let action = settings
+        .get(guild_id, Setting::GateAction)
+        .await
+        .unwrap_or_default();
+
+    let user_id = member.user.id;
+
+    let (punishment, duration) = match action.as_str() {
+        "kick" => (Punishment::Kick, 0),
+        "quarantine" => (Punishment::Quarantine, 0),
+        _ => (Punishment::Timeout, GATE_TIMEOUT),
+    };
+
+    let result = automod::punish(
+        ctx, data, guild_id, user_id, punishment, duration, &reason, None,
+    )
+    .await;
+
+    if let Err(e) = result {
+        println!(
+            "Failed to {} member {} at the join gate: {}",
+            action, user_id, e
+        );
+    }
+
+    Ok(())
+}
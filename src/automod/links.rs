@@ -0,0 +1,336 @@
+//! Deletes messages with Discord invites or links to domains outside the guild's allowlist.
+//! Allowed invites are matched by their code, so messages never wait on an invite lookup.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
+
+use regex::Regex;
+use serenity::all::{Context as SerenityContext, GuildId, Message};
+use tokio::sync::RwLock;
+
+use crate::automod;
+use crate::database::Database;
+use crate::models::{AllowlistEntryModel, AllowlistKind};
+use crate::settings::Setting;
+use crate::translation::tr_in;
+use crate::{Data, Error};
+
+fn invite_regex() -> &'static Regex {
+    static INVITE: OnceLock<Regex> = OnceLock::new();
+
+    INVITE.get_or_init(|| {
+        Regex::new(
+            r"(?i)(?:https?://)?(?:www\.)?(?:discord(?:app)?\.com/invite|discord\.gg)/([a-z0-9-]+)",
+        )
+        .unwrap()
+    })
+}
+
+fn url_regex() -> &'static Regex {
+    static URL: OnceLock<Regex> = OnceLock::new();
+
+    URL.get_or_init(|| Regex::new(r"(?i)\bhttps?://(?:[^\s/?#@<>]*@)?([^\s/?#:<>]+)").unwrap())
+}
+
+/// Extracts the invite code from an invite link, or returns the input if it's already a code
+pub fn invite_code(input: &str) -> Option<String> {
+    if let Some(captures) = invite_regex().captures(input) {
+        return Some(captures[1].to_owned());
+    }
+
+    let code_regex = Regex::new(r"^[A-Za-z0-9-]+$").unwrap();
+    code_regex.is_match(input).then(|| input.to_owned())
+}
+
+/// Extracts the lowercase domain from a link, or validates the input as a bare domain
+pub fn domain(input: &str) -> Option<String> {
+    let input = input.to_lowercase();
+
+    let domain = match url_regex().captures(&input) {
+        Some(captures) => captures[1].to_owned(),
+        None => input,
+    };
+
+    let domain_regex = Regex::new(r"^(?:[a-z0-9-]+\.)+[a-z]{2,}$").unwrap();
+    domain_regex.is_match(&domain).then_some(domain)
+}
+
+/// Why a message was blocked
+#[derive(Debug, PartialEq)]
+enum Blocked {
+    /// Code of an invite that isn't allowed
+    Invite(String),
+    /// Domain of a link outside the allowlist
+    Link(String),
+}
+
+#[derive(Default)]
+struct Allowlist {
+    domains: HashSet<String>,
+    invites: HashSet<String>,
+}
+
+impl Allowlist {
+    fn new(entries: Vec<AllowlistEntryModel>) -> Self {
+        let mut allowlist = Self::default();
+
+        for entry in entries.into_iter() {
+            match entry.kind {
+                AllowlistKind::Domain => allowlist.domains.insert(entry.value),
+                AllowlistKind::Invite => allowlist.invites.insert(entry.value),
+            };
+        }
+
+        allowlist
+    }
+
+    /// Subdomains of an allowed domain are allowed too
+    fn allows_domain(&self, domain: &str) -> bool {
+        let mut rest = domain;
+
+        loop {
+            if self.domains.contains(rest) {
+                return true;
+            }
+
+            match rest.split_once('.') {
+                Some((_, parent)) if parent.contains('.') => rest = parent,
+                _ => return false,
+            }
+        }
+    }
+
+    /// Returns why the content is blocked, if it is
+    fn check(&self, content: &str, all_links: bool) -> Option<Blocked> {
+        for captures in invite_regex().captures_iter(content) {
+            let code = &captures[1];
+
+            if !self.invites.contains(code) {
+                return Some(Blocked::Invite(code.to_owned()));
+            }
+        }
+
+        if !all_links {
+            return None;
+        }
+
+        // Invites were already checked above, their links shouldn't also need the domain
+        let content = invite_regex().replace_all(content, "");
+
+        for captures in url_regex().captures_iter(&content) {
+            let domain = captures[1].to_lowercase();
+
+            if !self.allows_domain(&domain) {
+                return Some(Blocked::Link(domain));
+            }
+        }
+
+        None
+    }
+}
+
+pub struct AllowlistCache {
+    database: Arc<Database>,
+    cache: RwLock<HashMap<GuildId, Arc<Allowlist>>>,
+}
+
+impl AllowlistCache {
+    pub fn new(database: Arc<Database>) -> Self {
+        Self {
+            database,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    async fn get(&self, guild_id: GuildId) -> Result<Arc<Allowlist>, Error> {
+        if let Some(allowlist) = self.cache.read().await.get(&guild_id) {
+            return Ok(Arc::clone(allowlist));
+        }
+
+        let entries = self.database.get_allowlist(guild_id).await?;
+        let allowlist = Arc::new(Allowlist::new(entries));
+
+        self.cache
+            .write()
+            .await
+            .insert(guild_id, Arc::clone(&allowlist));
+
+        Ok(allowlist)
+    }
+
+    /// Forgets the allowlist of the guild, so the next message loads it again
+    pub async fn invalidate(&self, guild_id: GuildId) {
+        self.cache.write().await.remove(&guild_id);
+    }
+}
+
+/// Returns whether the message was blocked
+pub async fn check(ctx: &SerenityContext, data: &Data, message: &Message) -> Result<bool, Error> {
+    let guild_id = message.guild_id.ok_or("message was not sent in a guild")?;
+
+    let all_links = match data
+        .settings
+        .get(guild_id, Setting::LinkFilter)
+        .await
+        .as_deref()
+    {
+        Some("invites") => false,
+        Some("all") => true,
+        _ => return Ok(false),
+    };
+
+    let blocked = match data
+        .allowlist
+        .get(guild_id)
+        .await?
+        .check(&message.content, all_links)
+    {
+        Some(blocked) => blocked,
+        None => return Ok(false),
+    };
+
+    if automod::is_exempt(ctx, &data.settings, message).await {
+        return Ok(false);
+    }
+
+    // The reason is shown to the member, so it follows the server's language
+    let locale = data.translations.resolve_locale(None, Some(guild_id), None);
+    let reason = match blocked {
+        Blocked::Invite(code) => {
+            tr_in!(data.translations, locale.as_deref(), "automod-invite", code: code)
+        }
+        Blocked::Link(domain) => {
+            tr_in!(data.translations, locale.as_deref(), "automod-link", domain: domain)
+        }
+    };
+
+    automod::enforce(ctx, data, message, &reason).await?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::types::chrono::Utc;
+
+    fn allowlist(domains: &[&str], invites: &[&str]) -> Allowlist {
+        let entry = |kind: AllowlistKind, value: &&str| AllowlistEntryModel {
+            id: 1,
+            guild_id: "1".to_owned(),
+            kind,
+            value: value.to_string(),
+            created_by: "1".to_owned(),
+            created_at: Utc::now(),
+        };
+
+        let entries = domains
+            .iter()
+            .map(|domain| entry(AllowlistKind::Domain, domain))
+            .chain(
+                invites
+                    .iter()
+                    .map(|invite| entry(AllowlistKind::Invite, invite)),
+            )
+            .collect();
+
+        Allowlist::new(entries)
+    }
+
+    #[test]
+    fn subdomains_of_allowed_domains_are_allowed() {
+        let allowlist = allowlist(&["example.com"], &[]);
+
+        assert!(allowlist.allows_domain("example.com"));
+        assert!(allowlist.allows_domain("www.example.com"));
+        assert!(allowlist.allows_domain("a.b.example.com"));
+    }
+
+    #[test]
+    fn domains_sharing_a_suffix_are_not_allowed() {
+        let allowlist = allowlist(&["example.com"], &[]);
+
+        assert!(!allowlist.allows_domain("evil-example.com"));
+        assert!(!allowlist.allows_domain("example.com.evil.net"));
+        assert!(!allowlist.allows_domain("com"));
+    }
+
+    #[test]
+    fn top_level_domains_never_allow_everything() {
+        let allowlist = allowlist(&["com"], &[]);
+
+        assert!(!allowlist.allows_domain("example.com"));
+    }
+
+    #[test]
+    fn invites_are_detected_in_every_form() {
+        let allowlist = allowlist(&[], &[]);
+
+        for content in [
+            "join discord.gg/abc",
+            "https://discord.gg/abc",
+            "https://www.discord.com/invite/abc",
+            "discordapp.com/invite/abc",
+            "HTTPS://DISCORD.GG/abc",
+        ] {
+            assert_eq!(
+                allowlist.check(content, false),
+                Some(Blocked::Invite("abc".to_owned())),
+                "{content}"
+            );
+        }
+    }
+
+    #[test]
+    fn allowed_invites_pass() {
+        let allowlist = allowlist(&[], &["abc"]);
+
+        assert!(allowlist.check("discord.gg/abc", false).is_none());
+        assert_eq!(
+            allowlist.check("discord.gg/abc discord.gg/xyz", false),
+            Some(Blocked::Invite("xyz".to_owned()))
+        );
+    }
+
+    #[test]
+    fn invite_codes_keep_their_case() {
+        let allowlist = allowlist(&[], &["AbC"]);
+
+        assert!(allowlist.check("discord.gg/AbC", false).is_none());
+        assert_eq!(
+            allowlist.check("discord.gg/abc", false),
+            Some(Blocked::Invite("abc".to_owned()))
+        );
+    }
+
+    #[test]
+    fn links_are_only_blocked_when_all_links_are() {
+        let allowlist = allowlist(&["example.com"], &[]);
+
+        assert!(allowlist.check("https://other.net", false).is_none());
+        assert_eq!(
+            allowlist.check("https://other.net/page", true),
+            Some(Blocked::Link("other.net".to_owned()))
+        );
+        assert!(allowlist
+            .check("see https://docs.example.com/page", true)
+            .is_none());
+    }
+
+    #[test]
+    fn link_domains_are_case_insensitive() {
+        let allowlist = allowlist(&["example.com"], &[]);
+
+        assert!(allowlist.check("https://WWW.Example.COM/x", true).is_none());
+        assert_eq!(
+            allowlist.check("https://Evil-Example.com", true),
+            Some(Blocked::Link("evil-example.com".to_owned()))
+        );
+    }
+
+    #[test]
+    fn invite_links_are_not_checked_as_domains() {
+        let allowlist = allowlist(&[], &["abc"]);
+
+        assert!(allowlist.check("https://discord.gg/abc", true).is_none());
+    }
+}
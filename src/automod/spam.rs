@@ -0,0 +1,457 @@
+//! Detects members flooding a channel, either by sending messages too fast, repeating the
+//! same message or cramming mentions and emojis into a single one
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use regex::Regex;
+use serenity::all::{ChannelId, Context as SerenityContext, GuildId, Message, MessageId, UserId};
+use tokio::sync::Mutex;
+
+use crate::automod;
+use crate::modlog::{self, ModLogAction, ModLogEntry};
+use crate::settings::{Setting, Settings};
+use crate::translation::{tr_in, Translations};
+use crate::{Data, Error};
+
+/// Longest window allowed by the `spam_window` setting
+const MAX_WINDOW: Duration = Duration::from_secs(60);
+
+/// Number of tracked channel and member pairs before idle ones are dropped
+const MAX_TRACKED: usize = 10_000;
+
+#[derive(Default)]
+pub struct SpamTracker {
+    recent: Mutex<HashMap<(ChannelId, UserId), VecDeque<RecentMessage>>>,
+}
+
+struct RecentMessage {
+    id: MessageId,
+    sent_at: Instant,
+    /// Hash of the normalized content, `None` for messages without text
+    hash: Option<u64>,
+}
+
+struct Thresholds {
+    window: Duration,
+    messages: usize,
+    duplicates: usize,
+    mentions: usize,
+    emojis: usize,
+}
+
+impl Thresholds {
+    async fn load(settings: &Settings, guild_id: GuildId) -> Self {
+        let number = |setting, default| async move {
+            settings.number(guild_id, setting).await.unwrap_or(default)
+        };
+
+        Self {
+            window: Duration::from_secs(number(Setting::SpamWindow, 5).await),
+            messages: number(Setting::SpamMessages, 6).await as usize,
+            duplicates: number(Setting::SpamDuplicates, 3).await as usize,
+            mentions: number(Setting::SpamMentions, 5).await as usize,
+            emojis: number(Setting::SpamEmojis, 10).await as usize,
+        }
+    }
+}
+
+/// Why a message was considered spam, with the count that crossed the threshold
+#[derive(Debug, PartialEq)]
+enum Spam {
+    Mentions(usize),
+    Emojis(usize),
+    Flood(usize),
+    Repeated(usize),
+}
+
+impl Spam {
+    fn reason(
+        &self,
+        translations: &Translations,
+        locale: Option<&str>,
+        window: Duration,
+    ) -> String {
+        match self {
+            Spam::Mentions(count) => {
+                tr_in!(translations, locale, "automod-mention-spam", count: *count)
+            }
+            Spam::Emojis(count) => {
+                tr_in!(translations, locale, "automod-emoji-spam", count: *count)
+            }
+            Spam::Flood(count) => tr_in!(
+                translations,
+                locale,
+                "automod-flood",
+                count: *count,
+                seconds: window.as_secs()
+            ),
+            Spam::Repeated(count) => {
+                tr_in!(translations, locale, "automod-repeated", count: *count)
+            }
+        }
+    }
+}
+
+impl SpamTracker {
+    /// Records the message, returning the messages to delete and why if it's spam
+    async fn record(
+        &self,
+        message: &Message,
+        thresholds: &Thresholds,
+    ) -> Option<(Vec<MessageId>, Spam)> {
+        self.record_at(message, thresholds, Instant::now()).await
+    }
+
+    /// Like [`SpamTracker::record`], for a message received at `now`
+    async fn record_at(
+        &self,
+        message: &Message,
+        thresholds: &Thresholds,
+        now: Instant,
+    ) -> Option<(Vec<MessageId>, Spam)> {
+        let mentions = message.mentions.len()
+            + message.mention_roles.len()
+            + message.mention_everyone as usize;
+
+        if mentions >= thresholds.mentions {
+            return Some((vec![message.id], Spam::Mentions(mentions)));
+        }
+
+        let emojis = count_emojis(&message.content);
+
+        if emojis >= thresholds.emojis {
+            return Some((vec![message.id], Spam::Emojis(emojis)));
+        }
+
+        let mut recent = self.recent.lock().await;
+
+        if recent.len() > MAX_TRACKED {
+            recent.retain(|_, messages| {
+                messages
+                    .back()
+                    .is_some_and(|last| now.duration_since(last.sent_at) < MAX_WINDOW)
+            });
+        }
+
+        let messages = recent
+            .entry((message.channel_id, message.author.id))
+            .or_default();
+
+        let hash = normalize(&message.content).map(|content| {
+            let mut hasher = DefaultHasher::new();
+            content.hash(&mut hasher);
+            hasher.finish()
+        });
+
+        messages.push_back(RecentMessage {
+            id: message.id,
+            sent_at: now,
+            hash,
+        });
+
+        while messages
+            .front()
+            .is_some_and(|first| now.duration_since(first.sent_at) > thresholds.window)
+        {
+            messages.pop_front();
+        }
+
+        if messages.len() >= thresholds.messages {
+            let spam = Spam::Flood(messages.len());
+            let ids = messages.drain(..).map(|recent| recent.id).collect();
+            return Some((ids, spam));
+        }
+
+        let hash = hash?;
+        let duplicates = messages
+            .iter()
+            .filter(|recent| recent.hash == Some(hash))
+            .count();
+
+        if duplicates >= thresholds.duplicates {
+            let ids = messages
+                .iter()
+                .filter(|recent| recent.hash == Some(hash))
+                .map(|recent| recent.id)
+                .collect();
+            messages.retain(|recent| recent.hash != Some(hash));
+
+            return Some((ids, Spam::Repeated(duplicates)));
+        }
+
+        None
+    }
+}
+
+/// Returns whether the message was spam
+pub async fn check(ctx: &SerenityContext, data: &Data, message: &Message) -> Result<bool, Error> {
+    let guild_id = message.guild_id.ok_or("message was not sent in a guild")?;
+    let settings = &data.settings;
+
+    if !settings.flag(guild_id, Setting::SpamFilter).await {
+        return Ok(false);
+    }
+
+    let thresholds = Thresholds::load(settings, guild_id).await;
+
+    let (message_ids, spam) = match data.spam.record(message, &thresholds).await {
+        Some(spam) => spam,
+        None => return Ok(false),
+    };
+
+    if automod::is_exempt(ctx, settings, message).await {
+        return Ok(false);
+    }
+
+    let channel_id = message.channel_id;
+    let user_id = message.author.id;
+
+    let deleted = match message_ids.as_slice() {
+        [id] => channel_id.delete_message(ctx, id).await,
+        ids => channel_id.delete_messages(ctx, ids).await,
+    };
+
+    if let Err(e) = deleted {
+        println!("Failed to delete spam in channel {}: {}", channel_id, e);
+    }
+
+    // The reason reaches the member through the punishment, so it follows the server's language
+    let translations = &data.translations;
+    let locale = translations.resolve_locale(None, Some(guild_id), None);
+    let reason = spam.reason(translations, locale.as_deref(), thresholds.window);
+    let details = tr_in!(
+        translations,
+        locale.as_deref(),
+        "automod-spam-deleted",
+        count: message_ids.len(),
+        channel: format!("<#{channel_id}>")
+    );
+
+    let entry = ModLogEntry::member(ModLogAction::Automod, user_id, ctx.cache.current_user().id)
+        .reason(&reason)
+        .details(details);
+    modlog::send(ctx, settings, translations, guild_id, entry).await;
+
+    // With an infraction configured, repeated spam climbs the guild's escalation ladder
+    match settings.number(guild_id, Setting::SpamInfraction).await {
+        Some(infraction_id) => {
+            automod::punish_infraction(ctx, data, guild_id, user_id, infraction_id as i32, &reason)
+                .await?
+        }
+        None => automod::apply_action(ctx, data, guild_id, channel_id, user_id, &reason).await?,
+    }
+
+    Ok(true)
+}
+
+/// Lowercases and collapses whitespace so trivially altered copies still count as duplicates
+fn normalize(content: &str) -> Option<String> {
+    let content = content
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+
+    Some(content).filter(|content| !content.is_empty())
+}
+
+fn count_emojis(content: &str) -> usize {
+    static CUSTOM_EMOJI: OnceLock<Regex> = OnceLock::new();
+
+    let custom = CUSTOM_EMOJI
+        .get_or_init(|| Regex::new(r"<a?:\w+:\d+>").unwrap())
+        .find_iter(content)
+        .count();
+
+    let unicode = content
+        .chars()
+        .filter(|c| matches!(*c as u32, 0x1F300..=0x1FAFF | 0x2600..=0x27BF))
+        .count();
+
+    custom + unicode
+}
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::RoleId;
+
+    use super::*;
+
+    fn thresholds(messages: usize, duplicates: usize) -> Thresholds {
+        Thresholds {
+            window: Duration::from_secs(5),
+            messages,
+            duplicates,
+            mentions: 5,
+            emojis: 10,
+        }
+    }
+
+    fn message(id: u64, channel: u64, content: &str) -> Message {
+        let mut message = Message::default();
+        message.id = MessageId::new(id);
+        message.channel_id = ChannelId::new(channel);
+        message.author.id = UserId::new(1);
+        message.content = content.to_owned();
+        message
+    }
+
+    fn ids(ids: &[u64]) -> Vec<MessageId> {
+        ids.iter().copied().map(MessageId::new).collect()
+    }
+
+    #[tokio::test]
+    async fn flood_starts_at_exactly_the_threshold() {
+        let tracker = SpamTracker::default();
+        let thresholds = thresholds(3, 10);
+
+        assert!(tracker
+            .record(&message(1, 1, "a"), &thresholds)
+            .await
+            .is_none());
+        assert!(tracker
+            .record(&message(2, 1, "b"), &thresholds)
+            .await
+            .is_none());
+
+        let (deleted, _) = tracker
+            .record(&message(3, 1, "c"), &thresholds)
+            .await
+            .unwrap();
+        assert_eq!(deleted, ids(&[1, 2, 3]));
+
+        // Flooded messages are forgotten once they're deleted
+        assert!(tracker
+            .record(&message(4, 1, "d"), &thresholds)
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn messages_outside_the_window_are_forgotten() {
+        let tracker = SpamTracker::default();
+        let thresholds = thresholds(2, 10);
+        let now = Instant::now();
+
+        assert!(tracker
+            .record_at(&message(1, 1, "a"), &thresholds, now)
+            .await
+            .is_none());
+
+        let later = now + thresholds.window * 2;
+        assert!(tracker
+            .record_at(&message(2, 1, "b"), &thresholds, later)
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn channels_are_tracked_separately() {
+        let tracker = SpamTracker::default();
+        let thresholds = thresholds(2, 10);
+
+        assert!(tracker
+            .record(&message(1, 1, "a"), &thresholds)
+            .await
+            .is_none());
+        assert!(tracker
+            .record(&message(2, 2, "b"), &thresholds)
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn duplicates_keep_the_other_messages() {
+        let tracker = SpamTracker::default();
+        let thresholds = thresholds(10, 3);
+
+        for (id, content) in [(1, "Hello  world"), (2, "other"), (3, "hello world")] {
+            assert!(tracker
+                .record(&message(id, 1, content), &thresholds)
+                .await
+                .is_none());
+        }
+
+        let (deleted, _) = tracker
+            .record(&message(4, 1, " HELLO WORLD "), &thresholds)
+            .await
+            .unwrap();
+        assert_eq!(deleted, ids(&[1, 3, 4]));
+
+        // Only the duplicates were dropped, the earlier `other` still counts
+        assert!(tracker
+            .record(&message(5, 1, "other"), &thresholds)
+            .await
+            .is_none());
+        let (deleted, _) = tracker
+            .record(&message(6, 1, "other"), &thresholds)
+            .await
+            .unwrap();
+        assert_eq!(deleted, ids(&[2, 5, 6]));
+    }
+
+    #[tokio::test]
+    async fn messages_without_text_are_never_duplicates() {
+        let tracker = SpamTracker::default();
+        let thresholds = thresholds(10, 2);
+
+        assert!(tracker
+            .record(&message(1, 1, ""), &thresholds)
+            .await
+            .is_none());
+        assert!(tracker
+            .record(&message(2, 1, "  "), &thresholds)
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn mentions_start_at_exactly_the_threshold() {
+        let tracker = SpamTracker::default();
+        let thresholds = thresholds(10, 10);
+
+        let mut below = message(1, 1, "");
+        below.mention_roles = (1..5).map(RoleId::new).collect();
+        assert!(tracker.record(&below, &thresholds).await.is_none());
+
+        let mut at = message(2, 1, "");
+        at.mention_roles = (1..4).map(RoleId::new).collect();
+        at.mention_everyone = true;
+        at.mentions.push(Default::default());
+
+        let (deleted, _) = tracker.record(&at, &thresholds).await.unwrap();
+        assert_eq!(deleted, ids(&[2]));
+    }
+
+    #[tokio::test]
+    async fn emojis_start_at_exactly_the_threshold() {
+        let tracker = SpamTracker::default();
+        let thresholds = thresholds(10, 10);
+
+        let below = message(1, 1, &"😀".repeat(9));
+        assert!(tracker.record(&below, &thresholds).await.is_none());
+
+        let at = message(2, 1, &"😀".repeat(10));
+        let (deleted, _) = tracker.record(&at, &thresholds).await.unwrap();
+        assert_eq!(deleted, ids(&[2]));
+    }
+
+    #[test]
+    fn count_emojis_counts_custom_and_unicode() {
+        assert_eq!(count_emojis("<:kode:123> 😀 ☀ <a:dance:456>"), 4);
+        assert_eq!(count_emojis("<:broken:> :smile: text"), 0);
+    }
+
+    #[test]
+    fn normalize_ignores_case_and_spacing() {
+        assert_eq!(
+            normalize("  Hello \n  World "),
+            Some("hello world".to_owned())
+        );
+        assert_eq!(normalize(" \t "), None);
+    }
+}
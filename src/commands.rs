@@ -1,9 +1,18 @@
+pub mod allowlist;
 pub mod animal;
 pub mod bang;
+pub mod case;
+pub mod config;
 pub mod emoji;
+pub mod escalation;
+pub mod filter;
+pub mod history;
 pub mod infractions;
+pub mod language;
+pub mod lockdown;
 pub mod misc;
 pub mod moderation;
+pub mod schedule;
 pub mod sticker;
 pub mod tags;
 pub mod utility;
@@ -0,0 +1,134 @@
+use crate::automod::links;
+use crate::models::{AllowlistEntryModel, AllowlistKind};
+use crate::{Context, Error};
+
+#[poise::command(
+    slash_command,
+    prefix_command,
+    subcommands("add", "list", "remove"),
+    subcommand_required,
+    required_permissions = "MANAGE_GUILD",
+    category = "Moderation"
+)]
+pub async fn allowlist(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "MANAGE_GUILD",
+    guild_only
+)]
+pub async fn add(
+    ctx: Context<'_>,
+    kind: AllowlistKind,
+    #[max_length = 200] value: String,
+) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+    let value = value.trim();
+
+    let value = match kind {
+        AllowlistKind::Domain => links::domain(value),
+        AllowlistKind::Invite => links::invite_code(value),
+    };
+
+    let value = match value {
+        Some(value) => value,
+        None => {
+            let res = match kind {
+                AllowlistKind::Domain => ":warning: Invalid domain!",
+                AllowlistKind::Invite => ":warning: Invalid invite link or code!",
+            };
+            ctx.reply(res).await?;
+            return Ok(());
+        }
+    };
+
+    let res = match ctx
+        .data()
+        .database
+        .add_allowlist_entry(guild_id, kind, &value, ctx.author().id)
+        .await
+    {
+        Ok(entry) => {
+            ctx.data().allowlist.invalidate(guild_id).await;
+            format!(
+                ":white_check_mark: Allowlist entry created!\n{}",
+                format_entry(&entry)
+            )
+        }
+        Err(_) => ":x: Failed to create allowlist entry! It may already exist.".to_owned(),
+    };
+
+    ctx.reply(res).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "MANAGE_GUILD",
+    guild_only
+)]
+pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    let res = match ctx.data().database.get_allowlist(guild_id).await {
+        Ok(entries) if entries.is_empty() => ":x: The allowlist is empty!".to_owned(),
+        Ok(entries) => entries
+            .iter()
+            .map(format_entry)
+            .collect::<Vec<String>>()
+            .join("\n"),
+        Err(_) => ":x: Failed to retrieve the allowlist!".to_owned(),
+    };
+
+    ctx.reply(res).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "MANAGE_GUILD",
+    guild_only
+)]
+pub async fn remove(ctx: Context<'_>, id: i32) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    let res = match ctx
+        .data()
+        .database
+        .remove_allowlist_entry(guild_id, id)
+        .await
+    {
+        Ok(result) => match result.rows_affected() {
+            0 => format!(":warning: There is no allowlist entry ID `{id}`!"),
+            _ => {
+                ctx.data().allowlist.invalidate(guild_id).await;
+                format!(":white_check_mark: Allowlist entry ID `{id}` removed successfully!")
+            }
+        },
+        Err(_) => format!(":x: Failed to remove allowlist entry ID `{id}`!"),
+    };
+
+    ctx.reply(res).await?;
+    Ok(())
+}
+
+fn format_entry(entry: &AllowlistEntryModel) -> String {
+    format!(
+        "- ID: `{}` | {:?}: `{}` | Added by <@{}>",
+        entry.id, entry.kind, entry.value, entry.created_by
+    )
+}
@@ -1,3 +1,4 @@
+use crate::translation::tr;
 use crate::{models::AnimalModel, Context, Error};
 
 #[poise::command(
@@ -26,20 +27,26 @@ pub async fn add(
 ) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
+    let guild_id = ctx.guild_id().unwrap();
+
     if let Ok(_) = ctx
         .data()
         .database
-        .add_animal(&animal, &emoji, points)
+        .add_animal(guild_id, &animal, &emoji, points)
         .await
     {
-        let res = format!(
-            "New animal added: {emoji} `{animal}` which is equivalent to `{points} points!`"
+        let res = tr!(
+            ctx,
+            "animal-added",
+            emoji: emoji,
+            animal: animal,
+            points: points
         );
         ctx.reply(res).await?;
         return Ok(());
     }
 
-    let res = format!("Failed to add new animal: `{animal}`!");
+    let res = tr!(ctx, "animal-add-failed", animal: animal);
     ctx.reply(res).await?;
     Ok(())
 }
@@ -48,15 +55,21 @@ pub async fn add(
 pub async fn see(ctx: Context<'_>, animal: String) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
-    if let Ok(model) = ctx.data().database.get_animal(&animal).await {
-        let emoji = model.emoji;
-        let points = model.points;
-        let res = format!("Animal: `{animal}`\nEmoji: `{emoji}`\nPoints: `{points}`");
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(model) = ctx.data().database.get_animal(guild_id, &animal).await {
+        let res = tr!(
+            ctx,
+            "animal-details",
+            animal: animal,
+            emoji: model.emoji,
+            points: model.points
+        );
         ctx.reply(res).await?;
         return Ok(());
     }
 
-    ctx.reply(format!("Failed to retrieve animal `{animal}`!"))
+    ctx.reply(tr!(ctx, "animal-see-failed", animal: animal))
         .await?;
     Ok(())
 }
@@ -71,18 +84,16 @@ pub async fn see(ctx: Context<'_>, animal: String) -> Result<(), Error> {
 pub async fn remove(ctx: Context<'_>, animal: String) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
-    if let Ok(result) = ctx.data().database.remove_animal(&animal).await {
-        let res = match result.rows_affected() {
-            1 => "Animal removed successfully!".to_owned(),
-            0 => "No animal removed!".to_owned(),
-            _ => "Animals removed successfully!".to_owned(),
-        };
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(result) = ctx.data().database.remove_animal(guild_id, &animal).await {
+        let res = tr!(ctx, "animal-removed", count: result.rows_affected());
 
         ctx.reply(res).await?;
         return Ok(());
     }
 
-    ctx.reply(format!("Failed to remove animal: `{animal}`"))
+    ctx.reply(tr!(ctx, "animal-remove-failed", animal: animal))
         .await?;
     Ok(())
 }
@@ -91,26 +102,32 @@ pub async fn remove(ctx: Context<'_>, animal: String) -> Result<(), Error> {
 pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
-    if let Ok(result) = ctx.data().database.get_animals().await {
-        let res = parse_animals(&result);
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(result) = ctx.data().database.get_animals(guild_id).await {
+        let res = parse_animals(ctx, &result);
         ctx.reply(res).await?;
         return Ok(());
     }
 
-    ctx.reply("No animals!".to_owned()).await?;
+    ctx.reply(tr!(ctx, "animal-empty")).await?;
     Ok(())
 }
 
-fn parse_animals(animals: &[AnimalModel]) -> String {
+fn parse_animals(ctx: Context<'_>, animals: &[AnimalModel]) -> String {
     if animals.is_empty() {
-        return "No animals!".to_owned();
+        return tr!(ctx, "animal-empty");
     }
 
     let mut lines = Vec::new();
-    lines.extend(
-        animals
-            .iter()
-            .map(|a| format!("- `{}` | `{}` | `{}` points", a.animal, a.emoji, a.points)),
-    );
+    lines.extend(animals.iter().map(|a| {
+        tr!(
+            ctx,
+            "animal-line",
+            animal: a.animal.as_str(),
+            emoji: a.emoji.as_str(),
+            points: a.points
+        )
+    }));
     lines.join("\n")
 }
@@ -1,44 +1,78 @@
 use crate::database::Database;
 use crate::models::{AnimalModel, BangPointModel};
+use crate::settings::Setting;
+use crate::translation::{tr, tr_in, Translations};
 use crate::{Context, Error};
 use rand::seq::SliceRandom;
 use rand::Rng;
-use serenity::all::{ChannelId, Http};
+use serenity::all::{ChannelId, GuildId, Http};
 use serenity::json::json;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+/// State of the bang mini-game running in a guild
+#[derive(Default)]
+pub struct BangGame {
+    pub channel: Option<ChannelId>,
+    /// Animal waiting to be shot, none between spawns
+    pub animal: Option<AnimalModel>,
+    pub handles: Vec<JoinHandle<Result<(), Error>>>,
+}
 
-#[poise::command(slash_command, prefix_command, category = "Bang")]
-pub async fn startbang(ctx: Context<'_>, channel: ChannelId) -> Result<(), Error> {
+#[poise::command(slash_command, prefix_command, guild_only, category = "Bang")]
+pub async fn startbang(ctx: Context<'_>, channel: Option<ChannelId>) -> Result<(), Error> {
     ctx.defer().await?;
 
-    {
-        let mut bang_channel = ctx.data().bang_channel.lock().await;
-        *bang_channel = channel.get();
+    let guild_id = ctx.guild_id().unwrap();
+
+    let channel = match channel {
+        Some(channel) => channel,
+        None => match ctx
+            .data()
+            .settings
+            .channel(guild_id, Setting::BangChannel)
+            .await
+        {
+            Some(channel) => channel,
+            None => {
+                ctx.reply(tr!(ctx, "bang-no-channel")).await?;
+                return Ok(());
+            }
+        },
+    };
+
+    // Without animals there is nothing to spawn, so the game wouldn't ever start
+    if ctx.data().database.get_animals(guild_id).await?.is_empty() {
+        ctx.reply(tr!(ctx, "bang-no-animals")).await?;
+        return Ok(());
     }
 
-    let mut handles = ctx.data().bang_handles.lock().await;
-    let bang_available = Arc::clone(&ctx.data().bang_available);
-    let database = Arc::clone(&ctx.data().database);
-    let last_animal = Arc::clone(&ctx.data().last_animal);
-    handles.push(tokio::spawn(generate_bang(
+    let mut games = ctx.data().bang_games.lock().await;
+    let game = games.entry(guild_id).or_default();
+    game.channel = Some(channel);
+    game.handles.push(tokio::spawn(generate_bang(
+        guild_id,
         channel,
-        bang_available,
-        database,
-        last_animal,
+        Arc::clone(&ctx.data().bang_games),
+        Arc::clone(&ctx.data().database),
+        Arc::clone(&ctx.data().translations),
     )));
+    drop(games);
 
-    ctx.reply(format!("Bang mini-game started at channel: <#{channel}>"))
+    ctx.reply(tr!(ctx, "bang-started", channel: format!("<#{channel}>")))
         .await?;
     Ok(())
 }
 
 async fn generate_bang(
+    guild_id: GuildId,
     channel_id: ChannelId,
-    bang_available: Arc<Mutex<bool>>,
+    games: Arc<Mutex<HashMap<GuildId, BangGame>>>,
     database: Arc<Database>,
-    last_animal: Arc<Mutex<AnimalModel>>,
+    translations: Arc<Translations>,
 ) -> Result<(), Error> {
     let interval: u64 = {
         let min_interval = 3; // 5 minute
@@ -51,122 +85,140 @@ async fn generate_bang(
     let token = std::env::var("DISCORD_TOKEN").unwrap();
     let http = Http::new(&token);
 
-    let animals = database.get_animals().await.unwrap();
+    let animals = database.get_animals(guild_id).await?;
+
+    // Every animal may have been removed while the game was running
+    let animal = match animals.choose(&mut rand::thread_rng()) {
+        Some(animal) => animal.clone(),
+        None => return Ok(()),
+    };
 
-    let animal = animals.choose(&mut rand::thread_rng()).unwrap();
+    let locale = translations.resolve_locale(None, Some(guild_id), None);
+    let content = tr_in!(
+        translations,
+        locale.as_deref(),
+        "bang-appeared",
+        emoji: animal.emoji.as_str(),
+        animal: animal.animal.as_str()
+    );
 
-    let map = json!({ "content": format!("{} A wild {} appeared!", animal.emoji.clone(), animal.animal.clone()) });
+    let map = json!({ "content": content });
     http.send_message(channel_id, vec![], &map).await?;
 
-    let mut last_animal_mut = last_animal.lock().await;
-    *last_animal_mut = AnimalModel {
-        id: animal.id,
-        emoji: animal.emoji.clone(),
-        animal: animal.animal.clone(),
-        points: animal.points,
-    };
+    // The game may have been stopped meanwhile
+    if let Some(game) = games.lock().await.get_mut(&guild_id) {
+        game.animal = Some(animal);
+    }
 
-    let mut is_bang_available = bang_available.lock().await;
-    *is_bang_available = true;
     Ok(())
 }
 
-#[poise::command(slash_command, prefix_command, category = "Bang")]
+#[poise::command(slash_command, prefix_command, guild_only, category = "Bang")]
 pub async fn bang(ctx: Context<'_>) -> Result<(), Error> {
-    let mut bang_available = ctx.data().bang_available.lock().await;
-
-    let res = if *bang_available {
-        *bang_available = false;
-        drop(bang_available);
+    let guild_id = ctx.guild_id().unwrap();
 
-        let mut handles = ctx.data().bang_handles.lock().await;
-        let channel_id = ctx.channel_id();
-        let arc_bang_available = Arc::clone(&ctx.data().bang_available);
-        let database = Arc::clone(&ctx.data().database);
-        let arc_last_animal = Arc::clone(&ctx.data().last_animal);
+    let mut games = ctx.data().bang_games.lock().await;
 
-        let last_animal = arc_last_animal.lock().await;
-        let animal_emoji = last_animal.emoji.clone();
-        let animal_name = last_animal.animal.clone();
-        let animal_points = last_animal.points;
+    // Taking the animal makes sure only the first shot counts
+    let shot = games
+        .get_mut(&guild_id)
+        .and_then(|game| Some((game.animal.take()?, game.channel)));
 
-        drop(last_animal);
+    let (animal, channel_id) = match shot {
+        Some((animal, channel)) => (animal, channel.unwrap_or(ctx.channel_id())),
+        None => {
+            drop(games);
+            ctx.reply(tr!(ctx, "bang-unavailable")).await?;
+            return Ok(());
+        }
+    };
 
-        let user_id = ctx.author().id.to_string();
+    let user_id = ctx.author().id.to_string();
 
-        if let Ok(_) = database
-            .create_or_add_user_bang_points(user_id, animal_points)
-            .await
-        {
-            handles.push(tokio::spawn(generate_bang(
+    let res = if let Ok(_) = ctx
+        .data()
+        .database
+        .create_or_add_user_bang_points(guild_id, user_id, animal.points)
+        .await
+    {
+        if let Some(game) = games.get_mut(&guild_id) {
+            game.handles.push(tokio::spawn(generate_bang(
+                guild_id,
                 channel_id,
-                arc_bang_available,
-                database,
-                arc_last_animal,
+                Arc::clone(&ctx.data().bang_games),
+                Arc::clone(&ctx.data().database),
+                Arc::clone(&ctx.data().translations),
             )));
-
-            format!(
-                "Nice! You just shot a {} {} and gained `{}` points!",
-                animal_emoji, animal_name, animal_points
-            )
-        } else {
-            format!("Failed to update user points! Stopping bang minigame...")
         }
+
+        tr!(
+            ctx,
+            "bang-shot",
+            emoji: animal.emoji,
+            animal: animal.animal,
+            points: animal.points
+        )
     } else {
-        format!("Bang isn't available yet!")
+        tr!(ctx, "bang-failed")
     };
 
+    drop(games);
+
     ctx.reply(res).await?;
 
     Ok(())
 }
 
-#[poise::command(slash_command, prefix_command, category = "Bang")]
+#[poise::command(slash_command, prefix_command, guild_only, category = "Bang")]
 pub async fn stopbang(ctx: Context<'_>) -> Result<(), Error> {
     ctx.defer().await?;
-    let mut handles = ctx.data().bang_handles.lock().await;
 
-    // TODO: find a more efficient way to manage handles
+    let guild_id = ctx.guild_id().unwrap();
 
-    while let Some(handle) = handles.pop() {
-        if handle.is_finished() {
-            continue;
-        }
+    let game = ctx.data().bang_games.lock().await.remove(&guild_id);
 
-        handle.abort();
+    // Only this guild's game is stopped, the others keep running
+    if let Some(game) = game {
+        for handle in game.handles {
+            if !handle.is_finished() {
+                handle.abort();
+            }
+        }
     }
 
-    let mut bang_available = ctx.data().bang_available.lock().await;
-    *bang_available = false;
-
-    ctx.reply("Bang mini-game stopped!").await?;
+    ctx.reply(tr!(ctx, "bang-stopped")).await?;
     Ok(())
 }
 
-#[poise::command(slash_command, prefix_command, category = "Bang")]
+#[poise::command(slash_command, prefix_command, guild_only, category = "Bang")]
 pub async fn ranking(ctx: Context<'_>) -> Result<(), Error> {
     ctx.defer().await?;
 
-    if let Ok(result) = ctx.data().database.get_bang_ranking().await {
-        let res = parse_ranking(&result);
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(result) = ctx.data().database.get_bang_ranking(guild_id).await {
+        let res = parse_ranking(ctx, &result);
         ctx.reply(res).await?;
         return Ok(());
     }
 
-    ctx.reply("No one is ranked!").await?;
+    ctx.reply(tr!(ctx, "bang-ranking-empty")).await?;
     Ok(())
 }
 
-fn parse_ranking(bang_points: &[BangPointModel]) -> String {
+fn parse_ranking(ctx: Context<'_>, bang_points: &[BangPointModel]) -> String {
     if bang_points.is_empty() {
-        return "No one is ranked!".to_owned();
+        return tr!(ctx, "bang-ranking-empty");
     }
 
     let mut lines = Vec::new();
-    lines.extend(
-        bang_points
-            .iter()
-            .map(|b| format!("- `{}` | `{}` points", b.user_id, b.points)),
-    );
+    lines.extend(bang_points.iter().map(|b| {
+        tr!(
+            ctx,
+            "bang-ranking-line",
+            user: b.user_id.as_str(),
+            points: b.points
+        )
+    }));
     lines.join("\n")
 }
@@ -0,0 +1,145 @@
+use crate::models::CaseModel;
+use crate::translation::{case_action_name, tr};
+use crate::{Context, Error};
+
+#[poise::command(
+    slash_command,
+    prefix_command,
+    subcommands("view", "reason", "delete"),
+    subcommand_required,
+    required_permissions = "KICK_MEMBERS | BAN_MEMBERS | MODERATE_MEMBERS",
+    category = "Moderation"
+)]
+pub async fn case(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "KICK_MEMBERS | BAN_MEMBERS | MODERATE_MEMBERS",
+    guild_only
+)]
+pub async fn view(ctx: Context<'_>, id: i32) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+    let database = &ctx.data().database;
+
+    let case = match database.get_case(guild_id, id).await {
+        Ok(case) => case,
+        Err(_) => {
+            ctx.reply(tr!(ctx, "case-not-found", case: id.to_string()))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let mut lines = format_case(ctx, &case);
+
+    if let Ok(Some(punishment)) = database.get_case_punishment(case.id).await {
+        if punishment.duration > 0 {
+            lines.push(tr!(ctx, "case-duration", seconds: punishment.duration));
+        }
+
+        if let Some(expires_at) = punishment.expires_at {
+            let time = format!("<t:{}:F>", expires_at.timestamp());
+            lines.push(tr!(ctx, "case-expires", time: time));
+        }
+    }
+
+    if let Ok(Some(infraction)) = database.get_case_infraction(case.id).await {
+        lines.push(tr!(ctx, "case-infraction", id: infraction.infraction_id));
+
+        if let Some(expires_at) = infraction.expires_at {
+            let time = format!("<t:{}:F>", expires_at.timestamp());
+            lines.push(tr!(ctx, "case-expires", time: time));
+        }
+    }
+
+    ctx.reply(lines.join("\n")).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "KICK_MEMBERS | BAN_MEMBERS | MODERATE_MEMBERS",
+    guild_only
+)]
+pub async fn reason(ctx: Context<'_>, id: i32, reason: String) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    let res = match ctx
+        .data()
+        .database
+        .update_case_reason(guild_id, id, &reason)
+        .await
+    {
+        Err(_) => tr!(ctx, "case-not-found", case: id.to_string()),
+        Ok(case) => {
+            let mut lines =
+                vec![tr!(ctx, "case-reason-updated", case: case.case_number.to_string())];
+            lines.extend(format_case(ctx, &case));
+            lines.join("\n")
+        }
+    };
+
+    ctx.reply(res).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "ADMINISTRATOR",
+    guild_only
+)]
+pub async fn delete(ctx: Context<'_>, id: i32) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(result) = ctx.data().database.remove_case(guild_id, id).await {
+        let res = match result.rows_affected() {
+            0 => tr!(ctx, "case-not-found", case: id.to_string()),
+            _ => tr!(ctx, "case-deleted", case: id.to_string()),
+        };
+
+        ctx.reply(res).await?;
+        return Ok(());
+    }
+
+    ctx.reply(tr!(ctx, "case-delete-failed", case: id.to_string()))
+        .await?;
+    Ok(())
+}
+
+fn format_case(ctx: Context<'_>, case: &CaseModel) -> Vec<String> {
+    let reason = match case.reason.is_empty() {
+        true => tr!(ctx, "case-no-reason"),
+        false => case.reason.clone(),
+    };
+
+    let mut lines = vec![tr!(
+        ctx,
+        "case-details",
+        case: case.case_number.to_string(),
+        action: case_action_name(ctx, &case.action),
+        user: format!("<@{}>", case.user_id),
+        moderator: format!("<@{}>", case.moderator_id),
+        reason: reason
+    )];
+
+    if let Some(created_at) = case.created_at {
+        let time = format!("<t:{}:F>", created_at.timestamp());
+        lines.push(tr!(ctx, "case-created", time: time));
+    }
+
+    lines
+}
@@ -0,0 +1,268 @@
+use crate::notify::PLACEHOLDERS;
+use crate::settings::{InvalidValue, Setting};
+use crate::translation::tr;
+use crate::{Context, Error};
+
+#[poise::command(
+    slash_command,
+    prefix_command,
+    subcommands("get", "set", "reset", "list"),
+    subcommand_required,
+    required_permissions = "ADMINISTRATOR",
+    category = "Configuration"
+)]
+pub async fn config(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "ADMINISTRATOR",
+    guild_only
+)]
+pub async fn get(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_setting"] key: String,
+) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    let setting = match Setting::from_key(&key) {
+        Some(setting) => setting,
+        None => {
+            ctx.reply(tr!(ctx, "config-unknown", key: key)).await?;
+            return Ok(());
+        }
+    };
+
+    let res = match ctx.data().settings.get_all(guild_id).await {
+        Ok(settings) => format_setting(ctx, setting, settings.get(&setting)),
+        Err(_) => tr!(ctx, "config-get-failed", key: key),
+    };
+
+    ctx.reply(res).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "ADMINISTRATOR",
+    guild_only
+)]
+pub async fn set(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_setting"] key: String,
+    #[rest] value: String,
+) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    let setting = match Setting::from_key(&key) {
+        Some(setting) => setting,
+        None => {
+            ctx.reply(tr!(ctx, "config-unknown", key: key)).await?;
+            return Ok(());
+        }
+    };
+
+    let value = match setting.parse(&value) {
+        Ok(value) => value,
+        Err(e) => {
+            let error = invalid_value(ctx, e);
+            ctx.reply(tr!(ctx, "config-invalid", key: key, error: error))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // Another server's channels or roles would send mod-logs and appeals outside this one
+    let channels = guild_id.channels(&ctx).await?;
+    if let Some(channel_id) = setting
+        .channel_ids(&value)
+        .into_iter()
+        .find(|channel_id| !channels.contains_key(channel_id))
+    {
+        let channel = format!("<#{channel_id}>");
+        ctx.reply(tr!(ctx, "config-foreign-channel", channel: channel))
+            .await?;
+        return Ok(());
+    }
+
+    let roles = guild_id.roles(&ctx).await?;
+    if let Some(role_id) = setting
+        .role_ids(&value)
+        .into_iter()
+        .find(|role_id| !roles.contains_key(role_id))
+    {
+        ctx.reply(tr!(ctx, "config-foreign-role", role: role_id.to_string()))
+            .await?;
+        return Ok(());
+    }
+
+    if setting == Setting::Locale && !ctx.data().translations.has_locale(&value) {
+        ctx.reply(tr!(ctx, "language-unknown", locale: value))
+            .await?;
+        return Ok(());
+    }
+
+    if ctx
+        .data()
+        .settings
+        .set(guild_id, setting, &value)
+        .await
+        .is_err()
+    {
+        ctx.reply(tr!(ctx, "config-set-failed", key: key)).await?;
+        return Ok(());
+    }
+
+    if setting == Setting::Locale {
+        ctx.data()
+            .translations
+            .set_guild_locale(guild_id, Some(value.clone()));
+    }
+
+    ctx.reply(format!(
+        "{}\n{}",
+        tr!(ctx, "config-set", key: key),
+        format_setting(ctx, setting, Some(&value))
+    ))
+    .await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "ADMINISTRATOR",
+    guild_only
+)]
+pub async fn reset(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_setting"] key: String,
+) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    let setting = match Setting::from_key(&key) {
+        Some(setting) => setting,
+        None => {
+            ctx.reply(tr!(ctx, "config-unknown", key: key)).await?;
+            return Ok(());
+        }
+    };
+
+    let reset = ctx.data().settings.reset(guild_id, setting).await;
+
+    if setting == Setting::Locale && reset.is_ok() {
+        ctx.data().translations.set_guild_locale(guild_id, None);
+    }
+
+    let res = match reset {
+        Ok(true) => format!(
+            "{}\n{}",
+            tr!(ctx, "config-reset", key: key.clone()),
+            format_setting(ctx, setting, None)
+        ),
+        Ok(false) => tr!(ctx, "config-reset-default", key: key),
+        Err(_) => tr!(ctx, "config-reset-failed", key: key),
+    };
+
+    ctx.reply(res).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "ADMINISTRATOR",
+    guild_only
+)]
+pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    let res = match ctx.data().settings.get_all(guild_id).await {
+        Ok(settings) => Setting::ALL
+            .iter()
+            .map(|setting| format_setting(ctx, *setting, settings.get(setting)))
+            .collect::<Vec<String>>()
+            .join("\n"),
+        Err(_) => tr!(ctx, "config-list-failed"),
+    };
+
+    ctx.reply(res).await?;
+    Ok(())
+}
+
+async fn autocomplete_setting<'a>(
+    _ctx: Context<'_>,
+    partial: &'a str,
+) -> impl Iterator<Item = String> + 'a {
+    Setting::ALL
+        .iter()
+        .map(|setting| setting.key())
+        .filter(move |key| key.contains(partial))
+        .map(str::to_owned)
+}
+
+fn format_setting(ctx: Context<'_>, setting: Setting, value: Option<&String>) -> String {
+    let value = match (value, setting.default_value()) {
+        (Some(value), _) => setting.display(value),
+        (None, Some(default)) => tr!(ctx, "config-default", value: setting.display(default)),
+        (None, None) => tr!(ctx, "config-not-set"),
+    };
+
+    format!(
+        "- `{}`: {} | {}",
+        setting.key(),
+        value,
+        // Checked by `--check-translations`, as the ID is built at runtime
+        tr!(ctx, &format!("setting-{}", setting.key()))
+    )
+}
+
+fn invalid_value(ctx: Context<'_>, error: InvalidValue) -> String {
+    let list = |items: &[&str], format: fn(&str) -> String| {
+        items
+            .iter()
+            .map(|item| format(item))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    match error {
+        InvalidValue::Empty => tr!(ctx, "config-invalid-empty"),
+        InvalidValue::TooLong(max) => tr!(ctx, "config-invalid-too-long", max: max),
+        InvalidValue::Spaces => tr!(ctx, "config-invalid-spaces"),
+        InvalidValue::Placeholder => {
+            let placeholders = list(PLACEHOLDERS, |placeholder| format!("`{{{placeholder}}}`"));
+            tr!(ctx, "config-invalid-placeholder", placeholders: placeholders)
+        }
+        InvalidValue::Link => tr!(ctx, "config-invalid-link"),
+        InvalidValue::Locale => tr!(ctx, "config-invalid-locale"),
+        InvalidValue::Channel => tr!(ctx, "config-invalid-channel"),
+        InvalidValue::Channels => tr!(ctx, "config-invalid-channels"),
+        InvalidValue::Role => tr!(ctx, "config-invalid-role"),
+        InvalidValue::Roles => tr!(ctx, "config-invalid-roles"),
+        InvalidValue::Flag => tr!(ctx, "config-invalid-flag"),
+        InvalidValue::Pattern => tr!(ctx, "config-invalid-pattern"),
+        InvalidValue::Number { min, max } => {
+            tr!(ctx, "config-invalid-number", min: min, max: max)
+        }
+        InvalidValue::Choice(choices) => {
+            let choices = list(choices, |choice| format!("`{choice}`"));
+            tr!(ctx, "config-invalid-choice", choices: choices)
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::translation::tr;
 use crate::{Context, Error};
 use poise::samples::paginate;
 use serenity::builder::CreateAttachment;
@@ -37,8 +38,8 @@ pub async fn add(ctx: Context<'_>, name: String, attachment: Attachment) -> Resu
         .create_emoji(&ctx, &name, &builder.to_base64())
         .await
     {
-        Err(_) => format!(":x: Failed to create emoji `{name}`"),
-        Ok(emoji) => format!(":white_check_mark: Emoji created: {}", emoji),
+        Err(_) => tr!(ctx, "emoji-create-failed", name: name),
+        Ok(emoji) => tr!(ctx, "emoji-created", emoji: emoji.to_string()),
     };
 
     ctx.reply(res).await?;
@@ -53,7 +54,7 @@ pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
 
     if let Ok(emojis) = guild_id.emojis(&ctx).await {
         if emojis.is_empty() {
-            ctx.reply(":warning: Server has no emojis!").await?;
+            ctx.reply(tr!(ctx, "emoji-empty")).await?;
             return Ok(());
         }
 
@@ -73,7 +74,7 @@ pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
         return Ok(());
     }
 
-    ctx.reply(":x: Failed to retrieve server emojis!").await?;
+    ctx.reply(tr!(ctx, "emoji-list-failed")).await?;
     Ok(())
 }
 
@@ -88,11 +89,8 @@ pub async fn remove(ctx: Context<'_>, emoji: Emoji) -> Result<(), Error> {
     let guild_id = ctx.guild_id().unwrap();
 
     let res = match guild_id.delete_emoji(&ctx, &emoji).await {
-        Err(_) => format!(":x: Failed to delete emoji `{}`", emoji.name),
-        Ok(()) => format!(
-            ":white_check_mark: Emoji `{}` deleted with success!",
-            emoji.name
-        ),
+        Err(_) => tr!(ctx, "emoji-delete-failed", name: emoji.name),
+        Ok(()) => tr!(ctx, "emoji-deleted", name: emoji.name),
     };
 
     ctx.reply(res).await?;
@@ -0,0 +1,121 @@
+use crate::models::{EscalationModel, Punishment, Severity};
+use crate::translation::{punishment_name, severity_name, tr};
+use crate::{Context, Error};
+
+#[poise::command(
+    slash_command,
+    prefix_command,
+    subcommands("add", "list", "remove"),
+    subcommand_required,
+    required_permissions = "ADMINISTRATOR",
+    category = "Infractions"
+)]
+pub async fn escalation(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "ADMINISTRATOR",
+    guild_only
+)]
+pub async fn add(
+    ctx: Context<'_>,
+    severity: Severity,
+    #[min = 1] threshold: i32,
+    #[min = 1] days: i32,
+    punishment: Punishment,
+    duration: i64,
+) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(escalation) = ctx
+        .data()
+        .database
+        .add_escalation(guild_id, severity, threshold, days, punishment, duration)
+        .await
+    {
+        let data = format_escalation(ctx, &escalation);
+        ctx.reply(format!("{}\n{data}", tr!(ctx, "escalation-created")))
+            .await?;
+        return Ok(());
+    }
+
+    ctx.reply(tr!(ctx, "escalation-create-failed")).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "ADMINISTRATOR",
+    guild_only
+)]
+pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(escalations) = ctx.data().database.get_escalations(guild_id).await {
+        let res = if escalations.is_empty() {
+            tr!(ctx, "escalation-empty")
+        } else {
+            escalations
+                .iter()
+                .map(|escalation| format_escalation(ctx, escalation))
+                .collect::<Vec<String>>()
+                .join("\n")
+        };
+
+        ctx.reply(res).await?;
+        return Ok(());
+    }
+
+    ctx.reply(tr!(ctx, "escalation-empty")).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "ADMINISTRATOR",
+    guild_only
+)]
+pub async fn remove(ctx: Context<'_>, id: i32) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(result) = ctx.data().database.remove_escalation(guild_id, id).await {
+        let res = match result.rows_affected() {
+            0 => tr!(ctx, "escalation-not-found", id: id),
+            _ => tr!(ctx, "escalation-removed", id: id),
+        };
+
+        ctx.reply(res).await?;
+        return Ok(());
+    }
+
+    ctx.reply(tr!(ctx, "escalation-remove-failed", id: id))
+        .await?;
+    Ok(())
+}
+
+fn format_escalation(ctx: Context<'_>, escalation: &EscalationModel) -> String {
+    tr!(
+        ctx,
+        "escalation-line",
+        id: escalation.id,
+        threshold: escalation.threshold,
+        severity: severity_name(ctx, &escalation.severity),
+        days: escalation.days,
+        punishment: punishment_name(ctx, &escalation.punishment),
+        duration: escalation.duration
+    )
+}
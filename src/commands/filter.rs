@@ -0,0 +1,142 @@
+use regex::Regex;
+
+use crate::models::{AutomodFilterModel, FilterKind};
+use crate::translation::tr;
+use crate::{Context, Error};
+
+#[poise::command(
+    slash_command,
+    prefix_command,
+    subcommands("add", "list", "remove"),
+    subcommand_required,
+    required_permissions = "MANAGE_GUILD",
+    category = "Moderation"
+)]
+pub async fn filter(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "MANAGE_GUILD",
+    guild_only
+)]
+pub async fn add(
+    ctx: Context<'_>,
+    kind: FilterKind,
+    #[rest]
+    #[max_length = 200]
+    pattern: String,
+) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+    let pattern = pattern.trim();
+
+    if pattern.is_empty() {
+        ctx.reply(tr!(ctx, "filter-empty-pattern")).await?;
+        return Ok(());
+    }
+
+    if kind == FilterKind::Regex && Regex::new(pattern).is_err() {
+        ctx.reply(tr!(ctx, "filter-invalid-regex")).await?;
+        return Ok(());
+    }
+
+    // Words are matched case insensitively, so they're stored in lowercase to avoid duplicates
+    let pattern = match kind {
+        FilterKind::Word => pattern.to_lowercase(),
+        FilterKind::Regex => pattern.to_owned(),
+    };
+
+    let res = match ctx
+        .data()
+        .database
+        .add_automod_filter(guild_id, kind, &pattern, ctx.author().id)
+        .await
+    {
+        Ok(filter) => {
+            ctx.data().filters.invalidate(guild_id).await;
+            format!(
+                "{}\n{}",
+                tr!(ctx, "filter-created"),
+                format_filter(ctx, &filter)
+            )
+        }
+        Err(_) => tr!(ctx, "filter-create-failed"),
+    };
+
+    ctx.reply(res).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "MANAGE_GUILD",
+    guild_only
+)]
+pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    let res = match ctx.data().database.get_automod_filters(guild_id).await {
+        Ok(filters) if filters.is_empty() => tr!(ctx, "filter-empty"),
+        Ok(filters) => filters
+            .iter()
+            .map(|filter| format_filter(ctx, filter))
+            .collect::<Vec<String>>()
+            .join("\n"),
+        Err(_) => tr!(ctx, "filter-list-failed"),
+    };
+
+    ctx.reply(res).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "MANAGE_GUILD",
+    guild_only
+)]
+pub async fn remove(ctx: Context<'_>, id: i32) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    let res = match ctx
+        .data()
+        .database
+        .remove_automod_filter(guild_id, id)
+        .await
+    {
+        Ok(result) => match result.rows_affected() {
+            0 => tr!(ctx, "filter-not-found", id: id),
+            _ => {
+                ctx.data().filters.invalidate(guild_id).await;
+                tr!(ctx, "filter-removed", id: id)
+            }
+        },
+        Err(_) => tr!(ctx, "filter-remove-failed", id: id),
+    };
+
+    ctx.reply(res).await?;
+    Ok(())
+}
+
+fn format_filter(ctx: Context<'_>, filter: &AutomodFilterModel) -> String {
+    tr!(
+        ctx,
+        "filter-line",
+        id: filter.id,
+        kind: format!("{:?}", filter.kind).to_lowercase(),
+        pattern: filter.pattern.as_str(),
+        user: format!("<@{}>", filter.created_by)
+    )
+}
@@ -0,0 +1,119 @@
+use crate::models::HistoryEntryModel;
+use crate::translation::{punishment_name, severity_name, tr};
+use crate::{Context, Error};
+use poise::samples::paginate;
+use serenity::model::id::UserId;
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    guild_only,
+    required_permissions = "KICK_MEMBERS | BAN_MEMBERS | MODERATE_MEMBERS",
+    category = "Moderation"
+)]
+pub async fn history(ctx: Context<'_>, member: UserId) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    let entries = match ctx.data().database.get_user_history(guild_id, member).await {
+        Ok(entries) => entries,
+        Err(_) => {
+            ctx.reply(tr!(ctx, "history-failed")).await?;
+            return Ok(());
+        }
+    };
+
+    if entries.is_empty() {
+        ctx.reply(tr!(ctx, "history-clean", user: format!("<@{member}>")))
+            .await?;
+        return Ok(());
+    }
+
+    let header = tr!(
+        ctx,
+        "history-header",
+        user: format!("<@{member}>"),
+        count: entries.len()
+    );
+
+    let entries_vec = entries
+        .iter()
+        .map(|entry| format_entry(ctx, entry))
+        .collect::<Vec<_>>();
+
+    let chunks = entries_vec
+        .chunks(5)
+        .map(|c| format!("{header}\n\n{}", c.join("\n\n")))
+        .collect::<Vec<_>>();
+
+    let pages: Vec<&str> = chunks.iter().map(|s| s.as_ref()).collect();
+
+    paginate(ctx, &pages).await?;
+    Ok(())
+}
+
+fn format_entry(ctx: Context<'_>, entry: &HistoryEntryModel) -> String {
+    let case = match entry.case_number {
+        Some(number) => tr!(ctx, "history-case", case: number.to_string()),
+        None => tr!(ctx, "history-no-case"),
+    };
+
+    let punishment = match &entry.punishment {
+        Some(punishment) => punishment_name(ctx, punishment),
+        None => tr!(ctx, "history-unknown"),
+    };
+
+    let mut title = tr!(ctx, "history-title", case: case, punishment: punishment);
+
+    if entry.pardoned {
+        title.push_str(&tr!(ctx, "history-pardoned"));
+    } else if entry.expired {
+        title.push_str(&tr!(ctx, "history-expired"));
+    }
+
+    let mut lines = vec![title];
+
+    if let Some(infraction_id) = entry.infraction_id {
+        let severity = match &entry.severity {
+            Some(severity) => severity_name(ctx, severity),
+            None => tr!(ctx, "history-unknown"),
+        };
+
+        let record_id = entry.user_infraction_id.unwrap_or_default();
+
+        lines.push(tr!(
+            ctx,
+            "history-infraction",
+            record: record_id,
+            infraction: infraction_id,
+            severity: severity
+        ));
+    }
+
+    if !entry.reason.is_empty() {
+        lines.push(tr!(ctx, "history-reason", reason: entry.reason.as_str()));
+    }
+
+    if let Some(moderator_id) = &entry.moderator_id {
+        let moderator = format!("<@{moderator_id}>");
+        lines.push(tr!(ctx, "history-moderator", moderator: moderator));
+    }
+
+    if entry.duration > 0 {
+        lines.push(tr!(ctx, "history-duration", seconds: entry.duration));
+    }
+
+    if let Some(created_at) = entry.created_at {
+        let time = format!("<t:{}:f>", created_at.timestamp());
+        lines.push(tr!(ctx, "history-date", time: time));
+    }
+
+    if let Some(expires_at) = entry.expires_at {
+        let time = format!("<t:{}:R>", expires_at.timestamp());
+        lines.push(tr!(ctx, "history-expires", time: time));
+    }
+
+    lines.join("\n")
+}
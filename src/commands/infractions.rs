@@ -1,11 +1,12 @@
 use crate::models::{InfractionModel, Punishment, Severity};
+use crate::translation::{punishment_name, severity_name, tr};
 use crate::{Context, Error};
 use serenity::model::id::UserId;
 
 #[poise::command(
     slash_command,
     prefix_command,
-    subcommands("add", "list", "remove", "user", "edit"),
+    subcommands("add", "list", "remove", "user", "edit", "pardon"),
     subcommand_required,
     required_permissions = "ADMINISTRATOR",
     category = "Infractions"
@@ -27,28 +28,30 @@ pub async fn add(
     severity: Severity,
     punishment: Punishment,
     duration: i64,
+    #[min = 1] expires_after: Option<i32>,
 ) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
-    if let Ok(_) = ctx.data().database.get_infraction(id).await {
-        ctx.reply(format!(":warning: Infraction ID `{id}` already exists!"))
-            .await?;
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(_) = ctx.data().database.get_infraction(guild_id, id).await {
+        ctx.reply(tr!(ctx, "infractions-exists", id: id)).await?;
         return Ok(());
     }
 
     if let Ok(infraction) = ctx
         .data()
         .database
-        .add_infraction(id, severity, punishment, duration)
+        .add_infraction(guild_id, id, severity, punishment, duration, expires_after)
         .await
     {
-        let data = format_infraction(infraction);
-        ctx.reply(format!(":white_check_mark: Infraction created!\n{data}"))
+        let data = format_infraction(ctx, infraction);
+        ctx.reply(format!("{}\n{data}", tr!(ctx, "infractions-created")))
             .await?;
         return Ok(());
     }
 
-    ctx.reply(format!(":x: Failed to create infraction ID `{id}`!"))
+    ctx.reply(tr!(ctx, "infractions-create-failed", id: id))
         .await?;
     Ok(())
 }
@@ -63,16 +66,23 @@ pub async fn add(
 pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
-    if let Ok(infractions) = ctx.data().database.get_infractions().await {
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(infractions) = ctx.data().database.get_infractions(guild_id).await {
         let res = if infractions.is_empty() {
-            ":x: No infractions found!".to_owned()
+            tr!(ctx, "infractions-empty")
         } else {
             infractions
                 .iter()
                 .map(|i| {
-                    format!(
-                        "- ID: `{}` | Severity: `{:?}` | Punishment: `{:?}` | Duration: `{}`",
-                        i.id, i.severity, i.punishment, i.duration
+                    tr!(
+                        ctx,
+                        "infractions-line",
+                        id: i.id,
+                        severity: severity_name(ctx, &i.severity),
+                        punishment: punishment_name(ctx, &i.punishment),
+                        duration: i.duration,
+                        expires: format_expiry(ctx, i.expires_after)
                     )
                 })
                 .collect::<Vec<String>>()
@@ -83,7 +93,7 @@ pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
         return Ok(());
     }
 
-    ctx.reply(":x: No infractions found!").await?;
+    ctx.reply(tr!(ctx, "infractions-empty")).await?;
     Ok(())
 }
 
@@ -97,18 +107,19 @@ pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
 pub async fn remove(ctx: Context<'_>, id: i32) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
-    if let Ok(result) = ctx.data().database.remove_infraction(id).await {
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(result) = ctx.data().database.remove_infraction(guild_id, id).await {
         let res = match result.rows_affected() {
-            0 => format!(":warning: There is no infraction ID `{id}`!"),
-            1 => format!(":white_check_mark: Infraction ID `{id}` removed successfully!"),
-            _ => format!(":white_check_mark: Infractions ID `{id}` removed successfully!"),
+            0 => tr!(ctx, "infractions-not-found", id: id),
+            count => tr!(ctx, "infractions-removed", count: count, id: id),
         };
 
         ctx.reply(res).await?;
         return Ok(());
     }
 
-    ctx.reply(format!(":x: Failed to remove infraction ID `{id}`!"))
+    ctx.reply(tr!(ctx, "infractions-remove-failed", id: id))
         .await?;
     Ok(())
 }
@@ -126,29 +137,28 @@ pub async fn edit(
     severity: Severity,
     punishment: Punishment,
     duration: i64,
+    #[min = 1] expires_after: Option<i32>,
 ) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
-    if let Err(_) = ctx.data().database.get_infraction(id).await {
-        ctx.reply(format!(":warning: Infraction ID `{id}` doesn't exist!"))
-            .await?;
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Err(_) = ctx.data().database.get_infraction(guild_id, id).await {
+        ctx.reply(tr!(ctx, "infractions-not-found", id: id)).await?;
         return Ok(());
     }
 
     if let Ok(_) = ctx
         .data()
         .database
-        .update_infraction(id, severity, punishment, duration)
+        .update_infraction(guild_id, id, severity, punishment, duration, expires_after)
         .await
     {
-        ctx.reply(format!(
-            ":white_check_mark: Infraction ID `{id}` updated with success!"
-        ))
-        .await?;
+        ctx.reply(tr!(ctx, "infractions-updated", id: id)).await?;
         return Ok(());
     }
 
-    ctx.reply(format!(":x: Failed to edit infraction ID `{id}`!"))
+    ctx.reply(tr!(ctx, "infractions-edit-failed", id: id))
         .await?;
     Ok(())
 }
@@ -163,43 +173,129 @@ pub async fn edit(
 pub async fn user(ctx: Context<'_>, member: UserId) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
-    if let Ok(infractions) = ctx.data().database.get_user_infractions(member).await {
+    let guild_id = ctx.guild_id().unwrap();
+
+    let mut message = String::new();
+
+    if let Ok(Some(expires_at)) = ctx
+        .data()
+        .database
+        .get_user_ban_expiry(guild_id, member)
+        .await
+    {
+        let time = format!("<t:{}:F>", expires_at.timestamp());
+        message.push_str(&tr!(ctx, "infractions-banned-until", time: time));
+        message.push('\n');
+    }
+
+    if let Ok(infractions) = ctx
+        .data()
+        .database
+        .get_user_infractions(guild_id, member)
+        .await
+    {
         let res = if infractions.is_empty() {
-            ":x: User has no infractions!".to_owned()
+            tr!(ctx, "infractions-user-empty")
         } else {
             infractions
                 .iter()
                 .map(|i| {
-                    format!(
-                        "- ID: `{}` | User ID: `{}` | Infraction ID: `{}` | Created At: `{}`",
-                        i.id,
-                        i.user_id,
-                        i.infraction_id,
-                        i.created_at.unwrap()
-                    )
+                    let mut line = tr!(
+                        ctx,
+                        "infractions-record",
+                        id: i.id,
+                        user: i.user_id.as_str(),
+                        infraction: i.infraction_id,
+                        created: i.created_at.unwrap().to_string()
+                    );
+
+                    if let Some(expires_at) = i.expires_at {
+                        line.push_str(" | ");
+                        line.push_str(&tr!(
+                            ctx,
+                            "infractions-record-expires",
+                            expires: expires_at.to_string()
+                        ));
+                    }
+
+                    if i.pardoned_at.is_some() {
+                        line.push_str(" | ");
+                        line.push_str(&tr!(ctx, "infractions-record-pardoned"));
+                    }
+
+                    line
                 })
                 .collect::<Vec<String>>()
                 .join("\n")
         };
 
+        message.push_str(&res);
+        ctx.reply(message).await?;
+        return Ok(());
+    }
+
+    message.push_str(&tr!(ctx, "infractions-user-empty"));
+    ctx.reply(message).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "ADMINISTRATOR",
+    guild_only
+)]
+pub async fn pardon(ctx: Context<'_>, id: i32) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(result) = ctx
+        .data()
+        .database
+        .pardon_user_infraction(guild_id, id, ctx.author().id)
+        .await
+    {
+        let res = match result.rows_affected() {
+            0 => tr!(ctx, "infractions-pardon-not-found", id: id),
+            _ => tr!(ctx, "infractions-pardoned", id: id),
+        };
+
         ctx.reply(res).await?;
         return Ok(());
     }
 
-    ctx.reply(":x: User has no infractions!").await?;
+    ctx.reply(tr!(ctx, "infractions-pardon-failed", id: id))
+        .await?;
     Ok(())
 }
 
 fn format_infraction(
+    ctx: Context<'_>,
     InfractionModel {
         id,
         severity,
         punishment,
         duration,
+        expires_after,
+        ..
     }: InfractionModel,
 ) -> String {
-    format!(
-        ":information_source: ID: {}\n:information_source: Severity: {:?}\n:information_source: Punishment: {:?}\n:information_source: Duration: {}\r\n",
-        id, severity, punishment, duration
+    tr!(
+        ctx,
+        "infractions-details",
+        id: id,
+        severity: severity_name(ctx, &severity),
+        punishment: punishment_name(ctx, &punishment),
+        duration: duration,
+        expires: format_expiry(ctx, expires_after)
     )
 }
+
+fn format_expiry(ctx: Context<'_>, days: Option<i32>) -> String {
+    match days {
+        Some(days) => tr!(ctx, "infractions-expires-days", days: days),
+        None => tr!(ctx, "infractions-never"),
+    }
+}
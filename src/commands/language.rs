@@ -0,0 +1,126 @@
+use crate::settings::Setting;
+use crate::translation::tr;
+use crate::{Context, Error};
+
+#[poise::command(
+    slash_command,
+    prefix_command,
+    subcommands("set", "reset", "server"),
+    subcommand_required,
+    category = "Configuration"
+)]
+pub async fn language(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(ephemeral, slash_command, prefix_command)]
+pub async fn set(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_locale"] locale: String,
+) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    if !ctx.data().translations.has_locale(&locale) {
+        ctx.reply(tr!(ctx, "language-unknown", locale: locale))
+            .await?;
+        return Ok(());
+    }
+
+    if ctx
+        .data()
+        .database
+        .set_user_locale(ctx.author().id, &locale)
+        .await
+        .is_err()
+    {
+        ctx.reply(tr!(ctx, "language-set-failed")).await?;
+        return Ok(());
+    }
+
+    ctx.data()
+        .translations
+        .set_user_locale(ctx.author().id, Some(locale.clone()));
+
+    ctx.reply(tr!(ctx, "language-set", locale: locale)).await?;
+    Ok(())
+}
+
+#[poise::command(ephemeral, slash_command, prefix_command)]
+pub async fn reset(ctx: Context<'_>) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let res = match ctx
+        .data()
+        .database
+        .remove_user_locale(ctx.author().id)
+        .await
+    {
+        Ok(result) => {
+            ctx.data()
+                .translations
+                .set_user_locale(ctx.author().id, None);
+
+            match result.rows_affected() {
+                0 => tr!(ctx, "language-reset-none"),
+                _ => tr!(ctx, "language-reset"),
+            }
+        }
+        Err(_) => tr!(ctx, "language-reset-failed"),
+    };
+
+    ctx.reply(res).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "MANAGE_GUILD",
+    guild_only
+)]
+pub async fn server(
+    ctx: Context<'_>,
+    #[autocomplete = "autocomplete_locale"] locale: String,
+) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    if !ctx.data().translations.has_locale(&locale) {
+        ctx.reply(tr!(ctx, "language-unknown", locale: locale))
+            .await?;
+        return Ok(());
+    }
+
+    if ctx
+        .data()
+        .settings
+        .set(guild_id, Setting::Locale, &locale)
+        .await
+        .is_err()
+    {
+        ctx.reply(tr!(ctx, "language-server-failed")).await?;
+        return Ok(());
+    }
+
+    ctx.data()
+        .translations
+        .set_guild_locale(guild_id, Some(locale.clone()));
+
+    ctx.reply(tr!(ctx, "language-server", locale: locale))
+        .await?;
+    Ok(())
+}
+
+async fn autocomplete_locale<'a>(
+    ctx: Context<'_>,
+    partial: &'a str,
+) -> impl Iterator<Item = String> + 'a {
+    let mut locales = ctx.data().translations.locales();
+    locales.sort();
+
+    locales
+        .into_iter()
+        .filter(move |locale| locale.to_lowercase().contains(&partial.to_lowercase()))
+}
@@ -0,0 +1,182 @@
+use crate::lock;
+use crate::modlog::{self, ModLogAction, ModLogEntry};
+use crate::translation::{tr, tr_in};
+use crate::{Context, Error};
+use serenity::all::ChannelId;
+
+#[poise::command(
+    slash_command,
+    prefix_command,
+    subcommands("start", "end"),
+    subcommand_required,
+    required_permissions = "MANAGE_GUILD | MANAGE_CHANNELS",
+    category = "Moderation"
+)]
+pub async fn lockdown(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD | MANAGE_CHANNELS",
+    required_bot_permissions = "MANAGE_GUILD | MANAGE_CHANNELS"
+)]
+pub async fn start(
+    ctx: Context<'_>,
+    #[max = 21600] slowmode: Option<u16>,
+    #[rest] reason: Option<String>,
+) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+    let reason = reason.unwrap_or_default();
+
+    if let Ok(Some(_)) = ctx.data().database.get_lockdown(guild_id).await {
+        ctx.reply(tr!(ctx, "lockdown-already")).await?;
+        return Ok(());
+    }
+
+    let (locked, not_locked) = match lock::start_lockdown(
+        ctx.http(),
+        &ctx.data().database,
+        &ctx.data().settings,
+        &ctx.data().translations,
+        guild_id,
+        ctx.author().id,
+        &reason,
+        slowmode,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(_) => {
+            ctx.reply(tr!(ctx, "lockdown-start-failed")).await?;
+            return Ok(());
+        }
+    };
+
+    let mut lines = vec![];
+
+    if !locked.is_empty() {
+        let channels = channel_mentions(&locked);
+        lines.push(tr!(ctx, "lockdown-locked", channels: channels));
+    }
+
+    if !not_locked.is_empty() {
+        let channels = channel_mentions(&not_locked);
+        lines.push(tr!(ctx, "lockdown-lock-failed", channels: channels));
+    }
+
+    if !reason.is_empty() {
+        lines.push(tr!(ctx, "lockdown-reason", reason: reason.as_str()));
+    }
+
+    if lines.is_empty() {
+        lines.push(tr!(ctx, "lockdown-nothing-locked"));
+    }
+
+    let translations = &ctx.data().translations;
+    let locale = translations.resolve_locale(None, Some(guild_id), None);
+    let entry = ModLogEntry::guild(ModLogAction::LockdownStart, ctx.author().id)
+        .reason(reason)
+        .details(tr_in!(
+            translations,
+            locale.as_deref(),
+            "lockdown-modlog-start",
+            locked: locked.len(),
+            failed: not_locked.len()
+        ));
+    modlog::send(
+        ctx.http(),
+        &ctx.data().settings,
+        translations,
+        guild_id,
+        entry,
+    )
+    .await;
+
+    ctx.reply(lines.join("\n")).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD | MANAGE_CHANNELS",
+    required_bot_permissions = "MANAGE_GUILD | MANAGE_CHANNELS"
+)]
+pub async fn end(ctx: Context<'_>) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(None) = ctx.data().database.get_lockdown(guild_id).await {
+        ctx.reply(tr!(ctx, "lockdown-not-active")).await?;
+        return Ok(());
+    }
+
+    let (unlocked, not_unlocked) = match lock::end_lockdown(
+        ctx.http(),
+        &ctx.data().database,
+        &ctx.data().translations,
+        guild_id,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(_) => {
+            ctx.reply(tr!(ctx, "lockdown-end-failed")).await?;
+            return Ok(());
+        }
+    };
+
+    let mut lines = vec![];
+
+    if !unlocked.is_empty() {
+        let channels = channel_mentions(&unlocked);
+        lines.push(tr!(ctx, "lockdown-restored", channels: channels));
+    }
+
+    if !not_unlocked.is_empty() {
+        let channels = channel_mentions(&not_unlocked);
+        lines.push(tr!(ctx, "lockdown-restore-failed", channels: channels));
+    }
+
+    if lines.is_empty() {
+        lines.push(tr!(ctx, "lockdown-nothing-restored"));
+    }
+
+    let translations = &ctx.data().translations;
+    let locale = translations.resolve_locale(None, Some(guild_id), None);
+    let entry = ModLogEntry::guild(ModLogAction::LockdownEnd, ctx.author().id).details(tr_in!(
+        translations,
+        locale.as_deref(),
+        "lockdown-modlog-end",
+        restored: unlocked.len(),
+        failed: not_unlocked.len()
+    ));
+    modlog::send(
+        ctx.http(),
+        &ctx.data().settings,
+        translations,
+        guild_id,
+        entry,
+    )
+    .await;
+
+    ctx.reply(lines.join("\n")).await?;
+    Ok(())
+}
+
+fn channel_mentions(channel_ids: &[ChannelId]) -> String {
+    channel_ids
+        .iter()
+        .map(|id| format!("<#{id}>"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -20,3 +20,25 @@ pub async fn database(ctx: Context<'_>) -> Result<(), Error> {
     ctx.reply(res).await?;
     Ok(())
 }
+
+/// Reloads the translation files without restarting the bot
+#[poise::command(
+    slash_command,
+    prefix_command,
+    owners_only,
+    hide_in_help,
+    category = "Misc"
+)]
+pub async fn reloadtranslations(ctx: Context<'_>) -> Result<(), Error> {
+    let res = match ctx.data().translations.reload() {
+        Ok(()) => tr!(
+            ctx,
+            "translations-reloaded",
+            count: ctx.data().translations.locales().len()
+        ),
+        Err(e) => tr!(ctx, "translations-reload-failed", error: e.to_string()),
+    };
+
+    ctx.reply(res).await?;
+    Ok(())
+}
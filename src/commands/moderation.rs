@@ -1,15 +1,22 @@
-use std::time::{Duration, SystemTime};
-
-use crate::models::Punishment;
-use crate::utils::user_ids_from;
-use crate::{Context, Error};
-use serenity::all::{
-    ChannelId, EditChannel, GetMessages, GuildId, Http, PermissionOverwrite,
-    PermissionOverwriteType, Permissions, RoleId,
-};
+use std::cmp::Reverse;
+
+use crate::lock;
+use crate::models::{CaseAction, CaseModel, Punishment, ScheduledAction, Severity};
+use crate::modlog::{self, ModLogAction, ModLogEntry};
+use crate::mute::{self, MAX_TIMEOUT};
+use crate::notify::{self, Notice, NoticeKind};
+use crate::purge::{self, PurgeFilter};
+use crate::settings::Setting;
+use crate::translation::{self, punishment_name, tr, tr_in};
+use crate::utils::{format_duration, from_now, user_ids_from};
+use crate::{Context, Data, Error};
+use regex::Regex;
+use serenity::all::{CreateAttachment, EditChannel, GuildId, Http, Message, MessageId, RoleId};
 use serenity::builder::EditMember;
 use serenity::model::{channel::GuildChannel, id::UserId};
-use sqlx::types::chrono::{DateTime, FixedOffset, Utc};
+
+/// Users that were punished, users that couldn't be, and punished users that couldn't be DMed
+type Punished = (Vec<UserId>, Vec<UserId>, Vec<UserId>);
 
 #[derive(poise::ChoiceParameter, Debug)]
 enum TimeUnit {
@@ -33,58 +40,68 @@ pub async fn kick(ctx: Context<'_>, users: String, reason: String) -> Result<(),
     let user_ids: Vec<UserId> = user_ids_from(&users);
 
     if users.is_empty() || user_ids.is_empty() {
-        ctx.reply("You must provide at least 1 valid user mention or user ID.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-no-users")).await?;
 
         return Ok(());
     }
 
     if let None = check_greater_hierarchy(&ctx, ctx.author().id, &user_ids) {
-        ctx.reply("One of the users have a role higher than yours.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-higher-role")).await?;
 
         return Ok(());
     }
 
     let guild_id = ctx.guild_id().unwrap();
 
-    let (punished_users, not_punished_users) =
-        kick_users(ctx, guild_id, user_ids, &reason, None).await?;
+    let (punished_users, not_punished_users, not_notified_users) = kick_users(
+        ctx.http(),
+        ctx.data(),
+        ctx.author().id,
+        guild_id,
+        user_ids,
+        &reason,
+        None,
+    )
+    .await?;
 
-    let mut message = String::new();
+    let mut lines = vec![];
 
     if !punished_users.is_empty() {
-        let punished_mentions = user_ids_to_mentions(punished_users).join(", ");
-
-        let response = format!(
-            ":white_check_mark: **Successfully kicked members:** {}\n",
-            punished_mentions
-        );
-
-        message.push_str(&response);
+        lines.push(tr!(
+            ctx,
+            "kick-success",
+            count: punished_users.len(),
+            users: user_ids_to_mentions(punished_users).join(", ")
+        ));
     }
 
     if !not_punished_users.is_empty() {
-        let not_punished_mentions = user_ids_to_mentions(not_punished_users).join(", ");
-
-        let response = format!(
-            ":warning: **Failed to kick members:** {}\n",
-            not_punished_mentions
-        );
+        lines.push(tr!(
+            ctx,
+            "kick-failed",
+            count: not_punished_users.len(),
+            users: user_ids_to_mentions(not_punished_users).join(", ")
+        ));
+    }
 
-        message.push_str(&response);
+    if !not_notified_users.is_empty() {
+        lines.push(tr!(
+            ctx,
+            "moderation-dm-failed",
+            count: not_notified_users.len(),
+            users: user_ids_to_mentions(not_notified_users).join(", ")
+        ));
     }
 
     if !reason.is_empty() {
-        let response = format!(":information: **Kick reason:** {}", reason);
-        message.push_str(&response);
+        lines.push(tr!(ctx, "kick-reason", reason: reason));
     }
 
-    if message.is_empty() {
-        message.push_str("Failed to execute kick command!");
+    if lines.is_empty() {
+        lines.push(tr!(ctx, "kick-error"));
     }
 
-    ctx.reply(message).await?;
+    ctx.reply(lines.join("\n")).await?;
     Ok(())
 }
 
@@ -99,78 +116,99 @@ pub async fn kick(ctx: Context<'_>, users: String, reason: String) -> Result<(),
 pub async fn timeout(
     ctx: Context<'_>,
     users: String,
+    #[min = 1]
+    #[max = 1_000_000] // keeps days within the representable dates
     time: i64,
     unit: TimeUnit,
+    reason: Option<String>,
 ) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
     let user_ids: Vec<UserId> = user_ids_from(&users);
 
     if user_ids.is_empty() {
-        ctx.reply("You must provide at least 1 valid user mention or user ID.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-no-users")).await?;
 
         return Ok(());
     }
 
     if let None = check_greater_hierarchy(&ctx, ctx.author().id, &user_ids) {
-        ctx.reply("One of the users have a role higher than yours.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-higher-role")).await?;
 
         return Ok(());
     }
 
-    let duration_i64 = match unit {
-        TimeUnit::Seconds => time,
-        TimeUnit::Minutes => time * 60,
-        TimeUnit::Hours => time * 60 * 60,
-        TimeUnit::Days => {
-            let time_fix = if time > 28 { 28 } else { time };
-            time_fix * 60 * 60 * 24
+    let duration = match to_seconds(time, &unit) {
+        Some(duration) => duration,
+        None => {
+            ctx.reply(tr!(ctx, "timeout-invalid-duration")).await?;
+
+            return Ok(());
         }
     };
 
-    let duration = to_iso8601(duration_i64);
+    let reason = reason.unwrap_or_default();
     let guild_id = ctx.guild_id().unwrap();
 
-    let (punished_users, not_punished_users) =
-        timeout_users(ctx, guild_id, user_ids, duration, None).await?;
+    let mute_role = mute_role_for(ctx.data(), guild_id, duration).await;
+
+    let (punished_users, not_punished_users, not_notified_users) = timeout_users(
+        ctx.http(),
+        ctx.data(),
+        ctx.author().id,
+        guild_id,
+        user_ids,
+        duration,
+        &reason,
+        None,
+    )
+    .await?;
 
-    let mut message = String::new();
+    let mut lines = vec![];
 
     if !punished_users.is_empty() {
-        let punished_mentions = user_ids_to_mentions(punished_users).join(", ");
-
-        let response = format!(
-            ":white_check_mark: **Successfully timed out members:** {}\n",
-            punished_mentions
-        );
-
-        message.push_str(&response);
+        lines.push(tr!(
+            ctx,
+            "timeout-success",
+            count: punished_users.len(),
+            users: user_ids_to_mentions(punished_users).join(", ")
+        ));
     }
 
     if !not_punished_users.is_empty() {
-        let not_punished_mentions = user_ids_to_mentions(not_punished_users).join(", ");
-
-        let response = format!(
-            ":warning: **Failed to time out members:** {}\n",
-            not_punished_mentions
-        );
+        lines.push(tr!(
+            ctx,
+            "timeout-failed",
+            count: not_punished_users.len(),
+            users: user_ids_to_mentions(not_punished_users).join(", ")
+        ));
+    }
 
-        message.push_str(&response);
+    if !not_notified_users.is_empty() {
+        lines.push(tr!(
+            ctx,
+            "moderation-dm-failed",
+            count: not_notified_users.len(),
+            users: user_ids_to_mentions(not_notified_users).join(", ")
+        ));
     }
 
-    if time > 0 {
-        let units = format!("{unit:?}").to_lowercase();
-        let response = format!(":information: **Time out duration:** {} {}", time, units);
-        message.push_str(&response);
+    lines.push(tr!(ctx, "timeout-duration", duration: format_duration(duration)));
+
+    lines.push(match mute_role {
+        Some(role_id) => tr!(ctx, "timeout-mute-role", role: format!("<@&{role_id}>")),
+        None => tr!(ctx, "timeout-discord"),
+    });
+
+    if mute_role.is_none() && duration > MAX_TIMEOUT {
+        lines.push(tr!(ctx, "timeout-clamped"));
     }
 
-    if message.is_empty() {
-        message.push_str("Failed to execute kick command!");
+    if !reason.is_empty() {
+        lines.push(tr!(ctx, "timeout-reason", reason: reason));
     }
 
-    ctx.reply(message).await?;
+    ctx.reply(lines.join("\n")).await?;
     Ok(())
 }
 
@@ -188,47 +226,47 @@ pub async fn untimeout(ctx: Context<'_>, users: String) -> Result<(), Error> {
     let user_ids: Vec<UserId> = user_ids_from(&users);
 
     if user_ids.is_empty() {
-        ctx.reply("You must provide at least 1 valid user mention or user ID.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-no-users")).await?;
 
         return Ok(());
     }
 
     if let None = check_greater_hierarchy(&ctx, ctx.author().id, &user_ids) {
-        ctx.reply("One of the users have a role higher than yours.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-higher-role")).await?;
 
         return Ok(());
     }
 
     let guild_id = ctx.guild_id().unwrap();
 
-    let (unpunished_users, not_unpunished_users) = untimeout_users(ctx, guild_id, user_ids).await?;
+    let (unpunished_users, not_unpunished_users) =
+        untimeout_users(ctx.http(), ctx.data(), ctx.author().id, guild_id, user_ids).await?;
 
-    let mut message = String::new();
+    let mut lines = vec![];
 
     if !unpunished_users.is_empty() {
-        let mentions = user_ids_to_mentions(unpunished_users).join(", ");
-
-        let res = format!(
-            ":white_check_mark: **Successfully untimedout members:** {}\n",
-            mentions
-        );
-
-        message.push_str(&res);
+        lines.push(tr!(
+            ctx,
+            "untimeout-success",
+            count: unpunished_users.len(),
+            users: user_ids_to_mentions(unpunished_users).join(", ")
+        ));
     }
 
     if !not_unpunished_users.is_empty() {
-        let mentions = user_ids_to_mentions(not_unpunished_users).join(", ");
-        let res = format!(":warning: **Failed to untimeout members:** {}\n", mentions);
-        message.push_str(&res);
+        lines.push(tr!(
+            ctx,
+            "untimeout-failed",
+            count: not_unpunished_users.len(),
+            users: user_ids_to_mentions(not_unpunished_users).join(", ")
+        ));
     }
 
-    if message.is_empty() {
-        message.push_str("Failed to execute untimeout command!");
+    if lines.is_empty() {
+        lines.push(tr!(ctx, "untimeout-error"));
     }
 
-    ctx.reply(message).await?;
+    ctx.reply(lines.join("\n")).await?;
     Ok(())
 }
 
@@ -240,64 +278,103 @@ pub async fn untimeout(ctx: Context<'_>, users: String) -> Result<(), Error> {
     required_permissions = "BAN_MEMBERS",
     category = "Moderation"
 )]
-pub async fn ban(ctx: Context<'_>, users: String, reason: String) -> Result<(), Error> {
+pub async fn ban(
+    ctx: Context<'_>,
+    users: String,
+    reason: String,
+    #[min = 1]
+    #[max = 1_000_000] // keeps days within the representable dates
+    time: Option<i64>,
+    unit: Option<TimeUnit>,
+) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
     let user_ids: Vec<UserId> = user_ids_from(&users);
 
     if user_ids.is_empty() {
-        ctx.reply("You must provide at least 1 valid user mention or user ID.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-no-users")).await?;
 
         return Ok(());
     }
 
+    // Temporary bans need both halves of a duration that ends within the representable dates
+    let duration = match (time, &unit) {
+        (Some(time), Some(unit)) if time > 0 => to_seconds(time, unit).map(Some),
+        (None, None) => Some(None),
+        _ => None,
+    };
+
+    let duration = match duration {
+        Some(duration) => duration,
+        None => {
+            ctx.reply(tr!(ctx, "ban-invalid-duration")).await?;
+
+            return Ok(());
+        }
+    };
+
     if let None = check_greater_hierarchy(&ctx, ctx.author().id, &user_ids) {
-        ctx.reply("One of the users have a role higher than yours.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-higher-role")).await?;
 
         return Ok(());
     }
 
     let guild_id = ctx.guild_id().unwrap();
 
-    let (punished_users, not_punished_users) =
-        ban_users(ctx, guild_id, user_ids, &reason, None).await?;
+    let (punished_users, not_punished_users, not_notified_users) = ban_users(
+        ctx.http(),
+        ctx.data(),
+        ctx.author().id,
+        guild_id,
+        user_ids,
+        &reason,
+        duration,
+        None,
+    )
+    .await?;
 
-    let mut message = String::new();
+    let mut lines = vec![];
 
     if !punished_users.is_empty() {
-        let punished_mentions = user_ids_to_mentions(punished_users).join(", ");
-
-        let response = format!(
-            ":white_check_mark: **Successfully banned members:** {}\n",
-            punished_mentions
-        );
-
-        message.push_str(&response);
+        lines.push(tr!(
+            ctx,
+            "ban-success",
+            count: punished_users.len(),
+            users: user_ids_to_mentions(punished_users).join(", ")
+        ));
     }
 
     if !not_punished_users.is_empty() {
-        let not_punished_mentions = user_ids_to_mentions(not_punished_users).join(", ");
+        lines.push(tr!(
+            ctx,
+            "ban-failed",
+            count: not_punished_users.len(),
+            users: user_ids_to_mentions(not_punished_users).join(", ")
+        ));
+    }
 
-        let response = format!(
-            ":warning: **Failed to ban members:** {}\n",
-            not_punished_mentions
-        );
+    if !not_notified_users.is_empty() {
+        lines.push(tr!(
+            ctx,
+            "moderation-dm-failed",
+            count: not_notified_users.len(),
+            users: user_ids_to_mentions(not_notified_users).join(", ")
+        ));
+    }
 
-        message.push_str(&response);
+    if let Some(duration) = duration {
+        lines.push(tr!(ctx, "ban-duration", duration: format_duration(duration)));
     }
 
     if !reason.is_empty() {
-        let response = format!(":information: **Ban reason:** {}", reason);
-        message.push_str(&response);
+        lines.push(tr!(ctx, "ban-reason", reason: reason));
     }
 
-    if message.is_empty() {
-        message.push_str("Failed to execute ban command!");
+    if lines.is_empty() {
+        lines.push(tr!(ctx, "ban-error"));
     }
 
-    ctx.reply(message).await?;
+    ctx.reply(lines.join("\n")).await?;
     Ok(())
 }
 
@@ -315,40 +392,41 @@ pub async fn unban(ctx: Context<'_>, users: String) -> Result<(), Error> {
     let user_ids: Vec<UserId> = user_ids_from(&users);
 
     if user_ids.is_empty() {
-        ctx.reply("You must provide at least 1 valid user mention or user ID.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-no-users")).await?;
 
         return Ok(());
     }
 
     let guild_id = ctx.guild_id().unwrap();
 
-    let (unpunished_users, not_unpunished_users) = unban_users(ctx, guild_id, user_ids).await?;
+    let (unpunished_users, not_unpunished_users) =
+        unban_users(ctx.http(), ctx.data(), ctx.author().id, guild_id, user_ids).await?;
 
-    let mut message = String::new();
+    let mut lines = vec![];
 
     if !unpunished_users.is_empty() {
-        let mentions = user_ids_to_mentions(unpunished_users).join(", ");
-
-        let res = format!(
-            ":white_check_mark: **Successfully unbanned members:** {}\n",
-            mentions
-        );
-
-        message.push_str(&res);
+        lines.push(tr!(
+            ctx,
+            "unban-success",
+            count: unpunished_users.len(),
+            users: user_ids_to_mentions(unpunished_users).join(", ")
+        ));
     }
 
     if !not_unpunished_users.is_empty() {
-        let mentions = user_ids_to_mentions(not_unpunished_users).join(", ");
-        let res = format!(":warning: **Failed to unban members:** {}\n", mentions);
-        message.push_str(&res);
+        lines.push(tr!(
+            ctx,
+            "unban-failed",
+            count: not_unpunished_users.len(),
+            users: user_ids_to_mentions(not_unpunished_users).join(", ")
+        ));
     }
 
-    if message.is_empty() {
-        message.push_str("Failed to execute unban command!");
+    if lines.is_empty() {
+        lines.push(tr!(ctx, "unban-error"));
     }
 
-    ctx.reply(message).await?;
+    ctx.reply(lines.join("\n")).await?;
     Ok(())
 }
 
@@ -366,55 +444,82 @@ pub async fn strike(ctx: Context<'_>, users: String, reason: String) -> Result<(
     let user_ids: Vec<UserId> = user_ids_from(&users);
 
     if user_ids.is_empty() {
-        ctx.reply("You must provide at least 1 valid user mention or user ID.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-no-users")).await?;
 
         return Ok(());
     }
 
     if let None = check_greater_hierarchy(&ctx, ctx.author().id, &user_ids) {
-        ctx.reply("One of the users have a role higher than yours.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-higher-role")).await?;
 
         return Ok(());
     }
 
-    let (punished_users, not_punished_users) = strike_users(ctx, user_ids, &reason, None).await?;
+    let guild_id = ctx.guild_id().unwrap();
 
-    let mut message = String::new();
+    let (punished_users, not_punished_users, not_notified_users) = strike_users(
+        ctx.http(),
+        ctx.data(),
+        ctx.author().id,
+        guild_id,
+        user_ids,
+        &reason,
+        None,
+    )
+    .await?;
 
-    if !punished_users.is_empty() {
-        let punished_mentions = user_ids_to_mentions(punished_users).join(", ");
+    let locale = translation::locale(ctx);
+    let escalations = escalate_users(
+        ctx.http(),
+        ctx.data(),
+        ctx.author().id,
+        guild_id,
+        &punished_users,
+        Severity::Low,
+        locale.as_deref(),
+    )
+    .await?;
 
-        let response = format!(
-            ":white_check_mark: **Successfully striked members:** {}\n",
-            punished_mentions
-        );
+    let mut lines = vec![];
 
-        message.push_str(&response);
+    if !punished_users.is_empty() {
+        lines.push(tr!(
+            ctx,
+            "strike-success",
+            count: punished_users.len(),
+            users: user_ids_to_mentions(punished_users).join(", ")
+        ));
     }
 
     if !not_punished_users.is_empty() {
-        let not_punished_mentions = user_ids_to_mentions(not_punished_users).join(", ");
-
-        let response = format!(
-            ":warning: **Failed to strike members:** {}\n",
-            not_punished_mentions
-        );
+        lines.push(tr!(
+            ctx,
+            "strike-failed",
+            count: not_punished_users.len(),
+            users: user_ids_to_mentions(not_punished_users).join(", ")
+        ));
+    }
 
-        message.push_str(&response);
+    if !not_notified_users.is_empty() {
+        lines.push(tr!(
+            ctx,
+            "moderation-dm-failed",
+            count: not_notified_users.len(),
+            users: user_ids_to_mentions(not_notified_users).join(", ")
+        ));
     }
 
     if !reason.is_empty() {
-        let response = format!(":information: **Strike reason:** {}", reason);
-        message.push_str(&response);
+        lines.push(tr!(ctx, "strike-reason", reason: reason));
     }
 
-    if message.is_empty() {
-        message.push_str("Failed to execute strike command!");
+    lines.extend(escalations);
+
+    if lines.is_empty() {
+        lines.push(tr!(ctx, "strike-error"));
     }
 
-    ctx.reply(message).await?;
+    ctx.reply(lines.join("\n")).await?;
     Ok(())
 }
 
@@ -431,92 +536,150 @@ pub async fn punish(ctx: Context<'_>, id: i32, users: String, reason: String) ->
     let user_ids: Vec<UserId> = user_ids_from(&users);
 
     if user_ids.is_empty() {
-        ctx.reply("You must provide at least 1 valid user mention or user ID.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-no-users")).await?;
 
         return Ok(());
     }
 
     let guild_id = ctx.guild_id().unwrap();
 
-    let infraction = ctx.data().database.get_infraction(id).await;
+    let infraction = ctx.data().database.get_infraction(guild_id, id).await;
 
     if let Err(_) = infraction {
-        ctx.reply("This infraction ID doesn't exists!").await?;
+        ctx.reply(tr!(ctx, "punish-unknown-infraction")).await?;
         return Ok(());
     }
 
     let infraction = infraction.unwrap();
 
     if let None = check_greater_hierarchy(&ctx, ctx.author().id, &user_ids) {
-        ctx.reply("One of the users have a role higher than yours.")
-            .await?;
+        ctx.reply(tr!(ctx, "moderation-higher-role")).await?;
 
         return Ok(());
     }
 
-    let (punished_users, not_punished_users) = match infraction.punishment {
-        Punishment::Ban => ban_users(ctx, guild_id, user_ids, &reason, Some(infraction.id)).await?,
+    let (punished_users, not_punished_users, not_notified_users) = match infraction.punishment {
+        Punishment::Ban => {
+            let duration = Some(infraction.duration).filter(|d| *d > 0);
+            ban_users(
+                ctx.http(),
+                ctx.data(),
+                ctx.author().id,
+                guild_id,
+                user_ids,
+                &reason,
+                duration,
+                Some(infraction.id),
+            )
+            .await?
+        }
         Punishment::Timeout => {
             timeout_users(
-                ctx,
+                ctx.http(),
+                ctx.data(),
+                ctx.author().id,
+                guild_id,
+                user_ids,
+                infraction.duration,
+                &reason,
+                Some(infraction.id),
+            )
+            .await?
+        }
+        Punishment::Strike => {
+            strike_users(
+                ctx.http(),
+                ctx.data(),
+                ctx.author().id,
                 guild_id,
                 user_ids,
-                to_iso8601(infraction.duration),
+                &reason,
                 Some(infraction.id),
             )
             .await?
         }
-        Punishment::Strike => strike_users(ctx, user_ids, &reason, Some(infraction.id)).await?,
         Punishment::Kick => {
-            kick_users(ctx, guild_id, user_ids, &reason, Some(infraction.id)).await?
+            kick_users(
+                ctx.http(),
+                ctx.data(),
+                ctx.author().id,
+                guild_id,
+                user_ids,
+                &reason,
+                Some(infraction.id),
+            )
+            .await?
+        }
+        Punishment::Quarantine => {
+            quarantine_users(
+                ctx.http(),
+                ctx.data(),
+                ctx.author().id,
+                guild_id,
+                user_ids,
+                &reason,
+                Some(infraction.id),
+            )
+            .await?
         }
     };
 
-    let mut message = String::new();
+    let locale = translation::locale(ctx);
+    let escalations = escalate_users(
+        ctx.http(),
+        ctx.data(),
+        ctx.author().id,
+        guild_id,
+        &punished_users,
+        infraction.severity.clone(),
+        locale.as_deref(),
+    )
+    .await?;
 
-    if !punished_users.is_empty() {
-        let punished_mentions = user_ids_to_mentions(punished_users).join(", ");
+    let mut lines = vec![];
 
-        let response = format!(
-            ":white_check_mark: **Successfully punished members:** {}\n",
-            punished_mentions
-        );
-
-        message.push_str(&response);
+    if !punished_users.is_empty() {
+        lines.push(tr!(
+            ctx,
+            "punish-success",
+            count: punished_users.len(),
+            users: user_ids_to_mentions(punished_users).join(", ")
+        ));
     }
 
     if !not_punished_users.is_empty() {
-        let not_punished_mentions = user_ids_to_mentions(not_punished_users).join(", ");
-
-        let response = format!(
-            ":warning: **Failed to punish members:** {}\n",
-            not_punished_mentions
-        );
+        lines.push(tr!(
+            ctx,
+            "punish-failed",
+            count: not_punished_users.len(),
+            users: user_ids_to_mentions(not_punished_users).join(", ")
+        ));
+    }
 
-        message.push_str(&response);
+    if !not_notified_users.is_empty() {
+        lines.push(tr!(
+            ctx,
+            "moderation-dm-failed",
+            count: not_notified_users.len(),
+            users: user_ids_to_mentions(not_notified_users).join(", ")
+        ));
     }
 
-    let punishment_type = format!("{:?}", infraction.punishment).to_lowercase();
-    let response = format!(":information: **Punishment type:** {:?}\n", punishment_type);
-    message.push_str(&response);
+    let punishment = punishment_name(ctx, &infraction.punishment);
+    lines.push(tr!(ctx, "punish-type", punishment: punishment));
 
     if infraction.duration > 0 {
-        let duration = format!("{}", infraction.duration).to_lowercase();
-        let response = format!(":information: **Punishment duration:** {:?}\n", duration);
-        message.push_str(&response);
+        let duration = format_duration(infraction.duration);
+        lines.push(tr!(ctx, "punish-duration", duration: duration));
     }
 
     if !reason.is_empty() {
-        let response = format!(":information: **Punishment reason:** {}", reason);
-        message.push_str(&response);
+        lines.push(tr!(ctx, "punish-reason", reason: reason));
     }
 
-    if message.is_empty() {
-        message.push_str("Failed to execute punish command!");
-    }
+    lines.extend(escalations);
 
-    ctx.reply(message).await?;
+    ctx.reply(lines.join("\n")).await?;
     Ok(())
 }
 
@@ -536,38 +699,177 @@ pub async fn slowmode(
     duration: Option<u64>,
 ) -> Result<(), Error> {
     let mut channel = channel.unwrap_or(ctx.guild_channel().await.unwrap());
+
+    // Prefix commands resolve channels of any server the bot is in
+    if Some(channel.guild_id) != ctx.guild_id() {
+        let channel = channel.to_string();
+        ctx.reply(tr!(ctx, "moderation-foreign-channel", channel: channel))
+            .await?;
+        return Ok(());
+    }
+    let previous_seconds = channel.rate_limit_per_user.unwrap_or(0);
     let builder = EditChannel::new().rate_limit_per_user(seconds);
 
-    let res = match channel.edit(&ctx, builder).await {
-        Ok(_) => format!(":white_check_mark: Slowmode of `{seconds}` seconds enabled with success on channel {channel}!"),
-        Err(_) => format!(":x: Failed to enable slowmode on channel {channel}!")
-    };
+    if channel.edit(&ctx, builder).await.is_err() {
+        ctx.reply(tr!(ctx, "slowmode-failed", channel: channel.to_string()))
+            .await?;
+        return Ok(());
+    }
 
-    ctx.reply(res).await?;
+    let mut res = tr!(
+        ctx,
+        "slowmode-success",
+        seconds: seconds,
+        channel: channel.to_string()
+    );
 
     if let Some(duration) = duration {
-        tokio::spawn(remove_slowmode_after(channel.id, duration));
+        let run_at = from_now(duration as i64).ok_or("duration out of range")?;
+
+        match ctx
+            .data()
+            .scheduler
+            .schedule(
+                channel.guild_id,
+                ScheduledAction::Slowmode,
+                channel.id.get(),
+                Some(previous_seconds.to_string()),
+                ctx.author().id,
+                run_at,
+            )
+            .await
+        {
+            Ok(_) => {
+                let time = format!("<t:{}:R>", run_at.timestamp());
+                res.push('\n');
+                res.push_str(&tr!(ctx, "slowmode-reverts", time: time));
+            }
+            Err(_) => {
+                res.push('\n');
+                res.push_str(&tr!(ctx, "slowmode-schedule-failed"));
+            }
+        };
     }
 
+    let translations = &ctx.data().translations;
+    let locale = translations.resolve_locale(None, Some(channel.guild_id), None);
+    let entry = ModLogEntry::channel(ModLogAction::Slowmode, channel.id, ctx.author().id)
+        .duration(duration.map(|d| d as i64))
+        .details(tr_in!(
+            translations,
+            locale.as_deref(),
+            "modlog-slowmode",
+            seconds: seconds
+        ));
+    modlog::send(
+        ctx.http(),
+        &ctx.data().settings,
+        &ctx.data().translations,
+        channel.guild_id,
+        entry,
+    )
+    .await;
+
+    ctx.reply(res).await?;
     Ok(())
 }
 
-async fn remove_slowmode_after(channel_id: ChannelId, duration: u64) -> Result<(), Error> {
-    #[derive(serde::Serialize)]
-    struct SlowModeChannel {
-        rate_limit_per_user: u16,
+#[poise::command(
+    slash_command,
+    prefix_command,
+    guild_only,
+    required_permissions = "MANAGE_CHANNELS",
+    required_bot_permissions = "MANAGE_CHANNELS",
+    category = "Moderation"
+)]
+pub async fn lock(
+    ctx: Context<'_>,
+    channel: Option<GuildChannel>,
+    #[max = 86400] // max = 24 hours
+    duration: Option<u64>,
+) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let channel = channel.unwrap_or(ctx.guild_channel().await.unwrap());
+
+    // Prefix commands resolve channels of any server the bot is in
+    if Some(channel.guild_id) != ctx.guild_id() {
+        let channel = channel.to_string();
+        ctx.reply(tr!(ctx, "moderation-foreign-channel", channel: channel))
+            .await?;
+        return Ok(());
     }
 
-    tokio::time::sleep(Duration::from_secs(duration)).await;
+    let mod_roles = ctx
+        .data()
+        .settings
+        .roles(channel.guild_id, Setting::ModRoles)
+        .await;
+
+    match lock::lock_channel(
+        ctx.http(),
+        &ctx.data().database,
+        &channel,
+        ctx.author().id,
+        &mod_roles,
+    )
+    .await
+    {
+        Ok(true) => (),
+        Ok(false) => {
+            ctx.reply(tr!(ctx, "lock-already", channel: channel.to_string()))
+                .await?;
+            return Ok(());
+        }
+        Err(_) => {
+            ctx.reply(tr!(ctx, "lock-failed", channel: channel.to_string()))
+                .await?;
+            return Ok(());
+        }
+    }
 
-    let token = std::env::var("DISCORD_TOKEN").unwrap();
-    let http = Http::new(&token);
+    let mut res = tr!(ctx, "lock-success", channel: channel.to_string());
 
-    let map = SlowModeChannel {
-        rate_limit_per_user: 0,
-    };
+    if let Some(duration) = duration {
+        let run_at = from_now(duration as i64).ok_or("duration out of range")?;
+
+        match ctx
+            .data()
+            .scheduler
+            .schedule(
+                channel.guild_id,
+                ScheduledAction::Unlock,
+                channel.id.get(),
+                None,
+                ctx.author().id,
+                run_at,
+            )
+            .await
+        {
+            Ok(_) => {
+                let time = format!("<t:{}:R>", run_at.timestamp());
+                res.push('\n');
+                res.push_str(&tr!(ctx, "lock-unlocks", time: time));
+            }
+            Err(_) => {
+                res.push('\n');
+                res.push_str(&tr!(ctx, "lock-schedule-failed"));
+            }
+        };
+    }
 
-    http.edit_channel(channel_id, &map, None).await?;
+    let entry = ModLogEntry::channel(ModLogAction::Lock, channel.id, ctx.author().id)
+        .duration(duration.map(|d| d as i64));
+    modlog::send(
+        ctx.http(),
+        &ctx.data().settings,
+        &ctx.data().translations,
+        channel.guild_id,
+        entry,
+    )
+    .await;
+
+    ctx.reply(res).await?;
     Ok(())
 }
 
@@ -579,46 +881,56 @@ async fn remove_slowmode_after(channel_id: ChannelId, duration: u64) -> Result<(
     required_bot_permissions = "MANAGE_CHANNELS",
     category = "Moderation"
 )]
-pub async fn lock(ctx: Context<'_>, channel: Option<GuildChannel>) -> Result<(), Error> {
+pub async fn unlock(ctx: Context<'_>, channel: Option<GuildChannel>) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
     let channel = channel.unwrap_or(ctx.guild_channel().await.unwrap());
 
-    let permission_types = channel
-        .permission_overwrites
-        .iter()
-        .map(|ov| ov.kind)
-        .collect::<Vec<_>>();
-
-    for permission_type in permission_types.into_iter() {
-        channel
-            .delete_permission(ctx.http(), permission_type)
+    // Prefix commands resolve channels of any server the bot is in
+    if Some(channel.guild_id) != ctx.guild_id() {
+        let channel = channel.to_string();
+        ctx.reply(tr!(ctx, "moderation-foreign-channel", channel: channel))
             .await?;
+        return Ok(());
     }
 
-    let allow = Permissions::empty();
-
-    let deny = Permissions::SEND_MESSAGES
-        | Permissions::SEND_TTS_MESSAGES
-        | Permissions::ADD_REACTIONS
-        | Permissions::SEND_MESSAGES_IN_THREADS
-        | Permissions::CREATE_PUBLIC_THREADS
-        | Permissions::CREATE_PRIVATE_THREADS;
-
-    let kind = {
-        let guild_id = ctx.guild_id().unwrap();
-        let role_id = RoleId::new(guild_id.get());
-        PermissionOverwriteType::Role(role_id)
+    let unlocked = match lock::unlock_channel(ctx.http(), &ctx.data().database, &channel).await {
+        Ok(unlocked) => unlocked,
+        Err(_) => {
+            ctx.reply(tr!(ctx, "unlock-failed", channel: channel.to_string()))
+                .await?;
+            return Ok(());
+        }
     };
 
-    let permissions = PermissionOverwrite { allow, deny, kind };
-
-    let res = match channel.create_permission(ctx.http(), permissions).await {
-        Ok(()) => format!(":white_check_mark: Channel {channel} locked with success!"),
-        Err(_) => format!(":x: Failed to lock channel {channel}!"),
-    };
+    // The channel is unlocked either way, so a pending timed unlock has nothing left to do
+    ctx.data()
+        .database
+        .remove_target_scheduled_actions(
+            channel.guild_id,
+            ScheduledAction::Unlock,
+            channel.id.to_string(),
+        )
+        .await?;
+
+    if !unlocked {
+        ctx.reply(tr!(ctx, "unlock-not-locked", channel: channel.to_string()))
+            .await?;
+        return Ok(());
+    }
 
-    ctx.reply(res).await?;
+    let entry = ModLogEntry::channel(ModLogAction::Unlock, channel.id, ctx.author().id);
+    modlog::send(
+        ctx.http(),
+        &ctx.data().settings,
+        &ctx.data().translations,
+        channel.guild_id,
+        entry,
+    )
+    .await;
+
+    ctx.reply(tr!(ctx, "unlock-success", channel: channel.to_string()))
+        .await?;
     Ok(())
 }
 
@@ -629,136 +941,395 @@ pub async fn lock(ctx: Context<'_>, channel: Option<GuildChannel>) -> Result<(),
     required_bot_permissions = "MANAGE_MESSAGES",
     category = "Moderation"
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn clear(
     ctx: Context<'_>,
-    #[max = 100]
+    #[max = 1000]
     #[min = 1]
-    amount: u8,
+    amount: u16,
     channel: Option<GuildChannel>,
+    users: Option<String>,
+    bots: Option<bool>,
+    pattern: Option<String>,
+    links: Option<bool>,
+    attachments: Option<bool>,
+    before: Option<MessageId>,
+    after: Option<MessageId>,
 ) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
     let channel = channel.unwrap_or(ctx.guild_channel().await.unwrap());
-    let builder = GetMessages::new().limit(amount);
-    let messages = channel.messages(&ctx, builder).await;
 
-    if let Err(_) = messages {
-        ctx.reply(":warning: No messages found!").await?;
+    // Prefix commands resolve channels of any server the bot is in
+    if Some(channel.guild_id) != ctx.guild_id() {
+        let channel = channel.to_string();
+        ctx.reply(tr!(ctx, "moderation-foreign-channel", channel: channel))
+            .await?;
         return Ok(());
     }
 
-    let messages = messages.unwrap();
-    let amount = messages.len();
+    let pattern = match pattern.map(|p| Regex::new(&p)).transpose() {
+        Ok(pattern) => pattern,
+        Err(_) => {
+            ctx.reply(tr!(ctx, "clear-invalid-pattern")).await?;
+            return Ok(());
+        }
+    };
+
+    let filter = PurgeFilter {
+        users: users.as_deref().map(user_ids_from).unwrap_or_default(),
+        bots,
+        pattern,
+        links: links.unwrap_or(false),
+        attachments: attachments.unwrap_or(false),
+        before,
+        after,
+    };
+
+    let summary = match purge::purge(
+        ctx.http(),
+        &ctx.data().database,
+        channel.guild_id,
+        channel.id,
+        ctx.author().id,
+        amount as usize,
+        &filter,
+    )
+    .await
+    {
+        Ok(summary) => summary,
+        Err(_) => {
+            ctx.reply(tr!(ctx, "clear-failed")).await?;
+            return Ok(());
+        }
+    };
+
+    if summary.deleted == 0 {
+        let mut res = tr!(ctx, "clear-not-found");
+
+        if summary.reached_age_limit {
+            res.push('\n');
+            res.push_str(&tr!(ctx, "clear-too-old"));
+        }
 
-    if let Err(_) = channel.delete_messages(&ctx.http(), messages).await {
-        ctx.reply(":x: No messages deleted!").await?;
+        ctx.reply(res).await?;
         return Ok(());
     }
 
-    ctx.reply(format!(
-        ":white_check_mark: `{amount}` messages were deleted from the channel {channel}!"
-    ))
-    .await?;
-    Ok(())
-}
+    let mut authors = summary.authors.into_iter().collect::<Vec<_>>();
+    authors.sort_by_key(|(_, count)| Reverse(*count));
 
-fn to_iso8601(duration: i64) -> String {
-    let now = SystemTime::now();
-    let datetime_now: DateTime<Utc> = now.into();
-    let timestamp_now = datetime_now.timestamp();
+    // Only the most frequent authors, to keep the summary within message limits
+    let authors = authors
+        .iter()
+        .take(10)
+        .map(|(user_id, count)| format!("<@{user_id}>: `{count}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut lines = vec![
+        tr!(
+            ctx,
+            "clear-success",
+            count: summary.deleted,
+            channel: channel.to_string()
+        ),
+        tr!(ctx, "clear-authors", authors: authors.as_str()),
+    ];
+
+    if summary.reached_age_limit {
+        lines.push(tr!(ctx, "clear-stopped"));
+    }
 
-    let timestamp = timestamp_now + duration;
-    let datetime: DateTime<Utc> = DateTime::from_timestamp(timestamp, 0).unwrap();
+    if summary.interrupted {
+        lines.push(tr!(ctx, "clear-interrupted"));
+    }
 
-    datetime.to_rfc3339()
-}
+    // Deleted messages are always archived first
+    let archive_id = summary
+        .archive_id
+        .ok_or("deleted messages weren't archived")?;
+
+    lines.push(tr!(ctx, "clear-archived", archive: archive_id.to_string()));
+
+    // The mod-log follows the server's language, not the moderator's
+    let translations = &ctx.data().translations;
+    let locale = translations.resolve_locale(None, Some(channel.guild_id), None);
+    let deleted = tr_in!(
+        translations,
+        locale.as_deref(),
+        "modlog-clear",
+        count: summary.deleted,
+        archive: archive_id.to_string()
+    );
+
+    let mut entry = ModLogEntry::channel(ModLogAction::Clear, channel.id, ctx.author().id)
+        .details(format!("{deleted}\n{authors}"));
+
+    if ctx
+        .data()
+        .settings
+        .flag(channel.guild_id, Setting::PurgeTranscripts)
+        .await
+    {
+        let file = CreateAttachment::bytes(
+            summary.transcript.into_bytes(),
+            format!("transcript-{archive_id}.txt"),
+        );
+        entry = entry.attachment(file);
+    }
 
-fn from_iso8601(duration: String) -> i64 {
-    let now = SystemTime::now();
-    let datetime_now: DateTime<Utc> = now.into();
-    let timestamp_now = datetime_now.timestamp();
+    modlog::send(
+        ctx.http(),
+        &ctx.data().settings,
+        &ctx.data().translations,
+        channel.guild_id,
+        entry,
+    )
+    .await;
 
-    let datetime: DateTime<FixedOffset> = DateTime::parse_from_rfc3339(duration.as_str()).unwrap();
+    ctx.reply(lines.join("\n")).await?;
+    Ok(())
+}
+
+/// Converts the time to seconds, or `None` if it ends beyond the representable dates
+fn to_seconds(time: i64, unit: &TimeUnit) -> Option<i64> {
+    let seconds = match unit {
+        TimeUnit::Seconds => Some(time),
+        TimeUnit::Minutes => time.checked_mul(60),
+        TimeUnit::Hours => time.checked_mul(60 * 60),
+        TimeUnit::Days => time.checked_mul(60 * 60 * 24),
+    }?;
 
-    datetime.timestamp() - timestamp_now
+    from_now(seconds).map(|_| seconds)
 }
 
-async fn kick_users(
-    ctx: Context<'_>,
+/// Kicks the users, recording a case for each. `moderator_id` is the author of the command, or
+/// the bot for automatic moderation.
+pub async fn kick_users(
+    http: &Http,
+    data: &Data,
+    moderator_id: UserId,
     guild_id: GuildId,
     user_ids: impl IntoIterator<Item = UserId>,
     reason: &str,
     infraction: Option<i32>,
-) -> Result<(Vec<UserId>, Vec<UserId>), Error> {
+) -> Result<Punished, Error> {
     let mut kicked = vec![];
     let mut not_kicked = vec![];
+    let mut not_notified = vec![];
 
     for user_id in user_ids.into_iter() {
-        match guild_id.kick_with_reason(&ctx, &user_id, reason).await {
+        let case = match infraction {
+            Some(id) => {
+                data.database
+                    .log_user_infraction(guild_id, &user_id, moderator_id, id, reason, None)
+                    .await?
+            }
+            None => {
+                data.database
+                    .log_user_punishment(
+                        guild_id,
+                        &user_id,
+                        moderator_id,
+                        Punishment::Kick,
+                        reason,
+                        0,
+                        None,
+                    )
+                    .await?
+            }
+        };
+
+        // Members can only be DMed while they share a server with the bot
+        let kind = NoticeKind::new(&Punishment::Kick, infraction);
+        let notice = notify_user(http, data, guild_id, user_id, &case, kind, None).await;
+
+        match guild_id.kick_with_reason(http, user_id, reason).await {
             Ok(_) => {
-                match infraction {
-                    Some(id) => {
-                        ctx.data()
-                            .database
-                            .log_user_infraction(&user_id, id)
-                            .await?;
-                    }
-                    None => {
-                        ctx.data()
-                            .database
-                            .log_user_punishment(&user_id, Punishment::Kick, 0)
-                            .await?;
-                    }
-                };
+                send_case_log(http, data, guild_id, &case, None, infraction).await;
+
+                if notice.is_none() {
+                    not_notified.push(user_id);
+                }
 
                 kicked.push(user_id);
             }
-            Err(_) => not_kicked.push(user_id),
+            Err(_) => {
+                retract_case(http, data, guild_id, &case, notice).await?;
+                not_kicked.push(user_id);
+            }
         };
     }
 
-    Ok((kicked, not_kicked))
+    Ok((kicked, not_kicked, not_notified))
 }
 
-async fn ban_users(
-    ctx: Context<'_>,
+/// Gives the users the guild's quarantine role, recording a case for each
+pub async fn quarantine_users(
+    http: &Http,
+    data: &Data,
+    moderator_id: UserId,
     guild_id: GuildId,
     user_ids: impl IntoIterator<Item = UserId>,
     reason: &str,
     infraction: Option<i32>,
-) -> Result<(Vec<UserId>, Vec<UserId>), Error> {
+) -> Result<Punished, Error> {
+    let mut quarantined = vec![];
+    let mut not_quarantined = vec![];
+    let mut not_notified = vec![];
+
+    let role_id = data.settings.role(guild_id, Setting::QuarantineRole).await;
+
+    for user_id in user_ids.into_iter() {
+        // Without a quarantine role configured there is nothing to assign
+        let role_id = match role_id {
+            Some(role_id) => role_id,
+            None => {
+                not_quarantined.push(user_id);
+                continue;
+            }
+        };
+
+        let case = match infraction {
+            Some(id) => {
+                data.database
+                    .log_user_infraction(guild_id, &user_id, moderator_id, id, reason, None)
+                    .await?
+            }
+            None => {
+                data.database
+                    .log_user_punishment(
+                        guild_id,
+                        &user_id,
+                        moderator_id,
+                        Punishment::Quarantine,
+                        reason,
+                        0,
+                        None,
+                    )
+                    .await?
+            }
+        };
+
+        let kind = NoticeKind::new(&Punishment::Quarantine, infraction);
+        let notice = notify_user(http, data, guild_id, user_id, &case, kind, None).await;
+
+        match http
+            .add_member_role(guild_id, user_id, role_id, Some(reason))
+            .await
+        {
+            Ok(_) => {
+                send_case_log(http, data, guild_id, &case, None, infraction).await;
+
+                if notice.is_none() {
+                    not_notified.push(user_id);
+                }
+
+                quarantined.push(user_id);
+            }
+            Err(_) => {
+                retract_case(http, data, guild_id, &case, notice).await?;
+                not_quarantined.push(user_id);
+            }
+        };
+    }
+
+    Ok((quarantined, not_quarantined, not_notified))
+}
+
+/// Bans the users, recording a case for each and scheduling the unban when `duration` (in
+/// seconds) is given
+#[allow(clippy::too_many_arguments)]
+pub async fn ban_users(
+    http: &Http,
+    data: &Data,
+    moderator_id: UserId,
+    guild_id: GuildId,
+    user_ids: impl IntoIterator<Item = UserId>,
+    reason: &str,
+    duration: Option<i64>,
+    infraction: Option<i32>,
+) -> Result<Punished, Error> {
     let mut banned = vec![];
     let mut not_banned = vec![];
+    let mut not_notified = vec![];
+    let expires_at = duration
+        .map(|duration| from_now(duration).ok_or("duration out of range"))
+        .transpose()?;
 
     for user_id in user_ids.into_iter() {
-        match guild_id.ban_with_reason(&ctx, &user_id, 0, reason).await {
+        let case = match infraction {
+            Some(id) => {
+                data.database
+                    .log_user_infraction(guild_id, &user_id, moderator_id, id, reason, expires_at)
+                    .await?
+            }
+            None => {
+                data.database
+                    .log_user_punishment(
+                        guild_id,
+                        &user_id,
+                        moderator_id,
+                        Punishment::Ban,
+                        reason,
+                        duration.unwrap_or(0),
+                        expires_at,
+                    )
+                    .await?
+            }
+        };
+
+        // Members can only be DMed while they share a server with the bot
+        let kind = NoticeKind::new(&Punishment::Ban, infraction);
+        let notice = notify_user(http, data, guild_id, user_id, &case, kind, duration).await;
+
+        match guild_id.ban_with_reason(http, user_id, 0, reason).await {
             Ok(_) => {
-                match infraction {
-                    Some(id) => {
-                        ctx.data()
-                            .database
-                            .log_user_infraction(&user_id, id)
-                            .await?;
-                    }
-                    None => {
-                        ctx.data()
-                            .database
-                            .log_user_punishment(&user_id, Punishment::Ban, 0)
-                            .await?;
-                    }
-                };
+                send_case_log(http, data, guild_id, &case, duration, infraction).await;
+
+                // A new ban replaces whatever expiry a previous ban had
+                data.database
+                    .remove_target_scheduled_actions(
+                        guild_id,
+                        ScheduledAction::Unban,
+                        user_id.to_string(),
+                    )
+                    .await?;
+
+                if let Some(run_at) = expires_at {
+                    data.scheduler
+                        .schedule(
+                            guild_id,
+                            ScheduledAction::Unban,
+                            user_id.get(),
+                            None,
+                            moderator_id,
+                            run_at,
+                        )
+                        .await?;
+                }
+
+                if notice.is_none() {
+                    not_notified.push(user_id);
+                }
 
                 banned.push(user_id);
             }
-            Err(_) => not_banned.push(user_id),
+            Err(_) => {
+                retract_case(http, data, guild_id, &case, notice).await?;
+                not_banned.push(user_id);
+            }
         };
     }
 
-    Ok((banned, not_banned))
+    Ok((banned, not_banned, not_notified))
 }
 
 async fn unban_users(
-    ctx: Context<'_>,
+    http: &Http,
+    data: &Data,
+    moderator_id: UserId,
     guild_id: GuildId,
     user_ids: impl IntoIterator<Item = UserId>,
 ) -> Result<(Vec<UserId>, Vec<UserId>), Error> {
@@ -766,8 +1337,25 @@ async fn unban_users(
     let mut not_unbanned = Vec::new();
 
     for user_id in user_ids.into_iter() {
-        match guild_id.unban(&ctx, user_id).await {
-            Ok(_) => unbanned.push(user_id),
+        match guild_id.unban(http, user_id).await {
+            Ok(_) => {
+                let case = data
+                    .database
+                    .log_case(guild_id, CaseAction::Unban, &user_id, moderator_id, "")
+                    .await?;
+                send_case_log(http, data, guild_id, &case, None, None).await;
+
+                data.database.end_user_bans(guild_id, user_id).await?;
+                data.database
+                    .remove_target_scheduled_actions(
+                        guild_id,
+                        ScheduledAction::Unban,
+                        user_id.to_string(),
+                    )
+                    .await?;
+
+                unbanned.push(user_id);
+            }
             Err(_) => not_unbanned.push(user_id),
         };
     }
@@ -776,7 +1364,9 @@ async fn unban_users(
 }
 
 async fn untimeout_users(
-    ctx: Context<'_>,
+    http: &Http,
+    data: &Data,
+    moderator_id: UserId,
     guild_id: GuildId,
     user_ids: impl IntoIterator<Item = UserId>,
 ) -> Result<(Vec<UserId>, Vec<UserId>), Error> {
@@ -786,8 +1376,27 @@ async fn untimeout_users(
     for user_id in user_ids.into_iter() {
         let builder = EditMember::new().enable_communication();
 
-        match guild_id.edit_member(&ctx, user_id, builder).await {
-            Ok(_) => untimedout.push(user_id),
+        match guild_id.edit_member(http, user_id, builder).await {
+            Ok(_) => {
+                // Timeouts beyond Discord's limit are applied through the mute role instead
+                if mute::unmute_member(http, &data.database, guild_id, user_id).await? {
+                    data.database
+                        .remove_target_scheduled_actions(
+                            guild_id,
+                            ScheduledAction::Unmute,
+                            user_id.to_string(),
+                        )
+                        .await?;
+                }
+
+                let case = data
+                    .database
+                    .log_case(guild_id, CaseAction::Untimeout, &user_id, moderator_id, "")
+                    .await?;
+                send_case_log(http, data, guild_id, &case, None, None).await;
+
+                untimedout.push(user_id);
+            }
             Err(_) => not_untimedout.push(user_id),
         };
     }
@@ -795,85 +1404,331 @@ async fn untimeout_users(
     Ok((untimedout, not_untimedout))
 }
 
-async fn timeout_users(
-    ctx: Context<'_>,
+/// Times the users out for `duration` seconds, through the mute role when it's longer than
+/// Discord allows, recording a case for each
+#[allow(clippy::too_many_arguments)]
+pub async fn timeout_users(
+    http: &Http,
+    data: &Data,
+    moderator_id: UserId,
     guild_id: GuildId,
     user_ids: impl IntoIterator<Item = UserId>,
-    duration: String,
+    duration: i64,
+    reason: &str,
     infraction: Option<i32>,
-) -> Result<(Vec<UserId>, Vec<UserId>), Error> {
+) -> Result<Punished, Error> {
     let mut timedout = vec![];
     let mut not_timedout = vec![];
-    let duration_i64 = from_iso8601(duration.clone());
+    let mut not_notified = vec![];
+
+    let mute_role = mute_role_for(data, guild_id, duration).await;
+    let duration_i64 = match mute_role {
+        Some(_) => duration,
+        None => duration.min(MAX_TIMEOUT),
+    };
+    let expires_at = from_now(duration_i64).ok_or("duration out of range")?;
 
     for user_id in user_ids.into_iter() {
-        let builder = EditMember::new().disable_communication_until(duration.clone());
+        let case = match infraction {
+            Some(id) => {
+                data.database
+                    .log_user_infraction(
+                        guild_id,
+                        &user_id,
+                        moderator_id,
+                        id,
+                        reason,
+                        Some(expires_at),
+                    )
+                    .await?
+            }
+            None => {
+                data.database
+                    .log_user_punishment(
+                        guild_id,
+                        &user_id,
+                        moderator_id,
+                        Punishment::Timeout,
+                        reason,
+                        duration_i64,
+                        Some(expires_at),
+                    )
+                    .await?
+            }
+        };
+
+        let kind = NoticeKind::new(&Punishment::Timeout, infraction);
+        let notice = notify_user(
+            http,
+            data,
+            guild_id,
+            user_id,
+            &case,
+            kind,
+            Some(duration_i64),
+        )
+        .await;
+
+        let result = match mute_role {
+            Some(role_id) => {
+                mute::mute_member(
+                    http,
+                    &data.database,
+                    &data.scheduler,
+                    guild_id,
+                    user_id,
+                    role_id,
+                    moderator_id,
+                    reason,
+                    expires_at,
+                )
+                .await
+            }
+            None => {
+                let builder = EditMember::new()
+                    .disable_communication_until(expires_at.to_rfc3339())
+                    .audit_log_reason(reason);
+                guild_id
+                    .edit_member(http, user_id, builder)
+                    .await
+                    .map(|_| ())
+                    .map_err(Into::into)
+            }
+        };
 
-        match guild_id.edit_member(&ctx, user_id, builder).await {
+        match result {
             Ok(_) => {
-                match infraction {
-                    Some(id) => {
-                        ctx.data()
-                            .database
-                            .log_user_infraction(&user_id, id)
-                            .await?;
-                    }
-                    None => {
-                        ctx.data()
-                            .database
-                            .log_user_punishment(&user_id, Punishment::Timeout, duration_i64)
-                            .await?;
-                    }
-                };
+                send_case_log(http, data, guild_id, &case, Some(duration_i64), infraction).await;
+
+                if notice.is_none() {
+                    not_notified.push(user_id);
+                }
 
                 timedout.push(user_id);
             }
-            Err(_) => not_timedout.push(user_id),
+            Err(_) => {
+                retract_case(http, data, guild_id, &case, notice).await?;
+                not_timedout.push(user_id);
+            }
         };
     }
 
-    Ok((timedout, not_timedout))
+    Ok((timedout, not_timedout, not_notified))
 }
 
-async fn strike_users(
-    ctx: Context<'_>,
+/// Mute role to use for a timeout of `duration` seconds, if it's longer than Discord allows and
+/// the guild has one configured
+async fn mute_role_for(data: &Data, guild_id: GuildId, duration: i64) -> Option<RoleId> {
+    if duration <= MAX_TIMEOUT {
+        return None;
+    }
+
+    data.settings.role(guild_id, Setting::MuteRole).await
+}
+
+/// Records a strike for each user and lets them know about it
+pub async fn strike_users(
+    http: &Http,
+    data: &Data,
+    moderator_id: UserId,
+    guild_id: GuildId,
     user_ids: impl IntoIterator<Item = UserId>,
     reason: &str,
     infraction: Option<i32>,
-) -> Result<(Vec<UserId>, Vec<UserId>), Error> {
+) -> Result<Punished, Error> {
     let mut striked = vec![];
+    let mut not_notified = vec![];
 
     for user_id in user_ids.into_iter() {
-        match user_id.create_dm_channel(&ctx).await {
-            Ok(channel) => {
-                let res = format!("You received a strike:\n{}", reason);
-                match channel.say(&ctx, res).await {
-                    Ok(_) => (),
-                    Err(_) => (),
-                }
-            }
-            Err(_) => (),
-        };
-
-        match infraction {
+        let case = match infraction {
             Some(id) => {
-                ctx.data()
-                    .database
-                    .log_user_infraction(&user_id, id)
-                    .await?;
+                data.database
+                    .log_user_infraction(guild_id, &user_id, moderator_id, id, reason, None)
+                    .await?
             }
             None => {
-                ctx.data()
-                    .database
-                    .log_user_punishment(&user_id, Punishment::Strike, 0)
-                    .await?;
+                data.database
+                    .log_user_punishment(
+                        guild_id,
+                        &user_id,
+                        moderator_id,
+                        Punishment::Strike,
+                        reason,
+                        0,
+                        None,
+                    )
+                    .await?
             }
         };
 
+        let kind = NoticeKind::new(&Punishment::Strike, infraction);
+        if notify_user(http, data, guild_id, user_id, &case, kind, None)
+            .await
+            .is_none()
+        {
+            not_notified.push(user_id);
+        }
+
+        send_case_log(http, data, guild_id, &case, None, infraction).await;
+
         striked.push(user_id);
     }
 
-    Ok((striked, vec![]))
+    Ok((striked, vec![], not_notified))
+}
+
+/// DMs the member about their case, before the punishment is applied
+async fn notify_user(
+    http: &Http,
+    data: &Data,
+    guild_id: GuildId,
+    user_id: UserId,
+    case: &CaseModel,
+    kind: NoticeKind,
+    duration: Option<i64>,
+) -> Option<Message> {
+    let notice = Notice::new(kind, case).duration(duration);
+
+    notify::send(
+        http,
+        &data.settings,
+        &data.translations,
+        guild_id,
+        user_id,
+        &notice,
+    )
+    .await
+}
+
+/// Removes the case of a punishment that couldn't be applied, along with the DM announcing it
+async fn retract_case(
+    http: &Http,
+    data: &Data,
+    guild_id: GuildId,
+    case: &CaseModel,
+    notice: Option<Message>,
+) -> Result<(), Error> {
+    if let Some(message) = notice {
+        let _ = message.delete(http).await;
+    }
+
+    data.database
+        .remove_case(guild_id, case.case_number)
+        .await?;
+    Ok(())
+}
+
+async fn send_case_log(
+    http: &Http,
+    data: &Data,
+    guild_id: GuildId,
+    case: &CaseModel,
+    duration: Option<i64>,
+    infraction: Option<i32>,
+) {
+    let mut entry = ModLogEntry::case(case).duration(duration);
+
+    if let Some(id) = infraction {
+        let translations = &data.translations;
+        let locale = translations.resolve_locale(None, Some(guild_id), None);
+        let id = id.to_string();
+        entry = entry.details(tr_in!(translations, locale.as_deref(), "modlog-infraction", id: id));
+    }
+
+    modlog::send(http, &data.settings, &data.translations, guild_id, entry).await;
+}
+
+/// Applies the guild's escalation ladder after new records of `severity` were logged for the
+/// given users, returning a description of every escalated punishment in `locale`
+pub async fn escalate_users(
+    http: &Http,
+    data: &Data,
+    moderator_id: UserId,
+    guild_id: GuildId,
+    user_ids: &[UserId],
+    severity: Severity,
+    locale: Option<&str>,
+) -> Result<Vec<String>, Error> {
+    let translations = &data.translations;
+
+    let mut lines = vec![];
+
+    for user_id in user_ids.iter() {
+        if let Some((escalation, count)) = data
+            .database
+            .get_due_escalation(guild_id, *user_id, severity.clone())
+            .await?
+        {
+            let severity_name = format!("{:?}", severity).to_lowercase();
+            let reason = tr_in!(
+                translations,
+                locale,
+                "escalation-reason",
+                count: count,
+                severity: tr_in!(translations, locale, "severity-name", severity: severity_name),
+                days: escalation.days
+            );
+            let user = vec![*user_id];
+
+            let (escalated, _, _) = match escalation.punishment {
+                Punishment::Ban => {
+                    let duration = Some(escalation.duration).filter(|d| *d > 0);
+                    ban_users(
+                        http,
+                        data,
+                        moderator_id,
+                        guild_id,
+                        user,
+                        &reason,
+                        duration,
+                        None,
+                    )
+                    .await?
+                }
+                Punishment::Timeout => {
+                    let duration = escalation.duration;
+                    timeout_users(
+                        http,
+                        data,
+                        moderator_id,
+                        guild_id,
+                        user,
+                        duration,
+                        &reason,
+                        None,
+                    )
+                    .await?
+                }
+                Punishment::Strike => {
+                    strike_users(http, data, moderator_id, guild_id, user, &reason, None).await?
+                }
+                Punishment::Kick => {
+                    kick_users(http, data, moderator_id, guild_id, user, &reason, None).await?
+                }
+                Punishment::Quarantine => {
+                    quarantine_users(http, data, moderator_id, guild_id, user, &reason, None)
+                        .await?
+                }
+            };
+
+            let id = match escalated.is_empty() {
+                true => "escalation-failed",
+                false => "escalation-success",
+            };
+            let punishment = format!("{:?}", escalation.punishment).to_lowercase();
+
+            lines.push(tr_in!(
+                translations,
+                locale,
+                id,
+                user: format!("<@{user_id}>"),
+                punishment: tr_in!(translations, locale, "punishment-name", punishment: punishment),
+                reason: reason
+            ));
+        }
+    }
+
+    Ok(lines)
 }
 
 fn check_greater_hierarchy(ctx: &Context<'_>, caller: UserId, users: &[UserId]) -> Option<UserId> {
@@ -0,0 +1,104 @@
+use crate::models::{ScheduledAction, ScheduledActionModel};
+use crate::translation::tr;
+use crate::{Context, Error};
+
+#[poise::command(
+    slash_command,
+    prefix_command,
+    subcommands("list", "cancel"),
+    subcommand_required,
+    required_permissions = "KICK_MEMBERS | BAN_MEMBERS | MODERATE_MEMBERS",
+    category = "Moderation"
+)]
+pub async fn schedule(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "KICK_MEMBERS | BAN_MEMBERS | MODERATE_MEMBERS",
+    guild_only
+)]
+pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(actions) = ctx
+        .data()
+        .database
+        .get_guild_scheduled_actions(guild_id)
+        .await
+    {
+        let res = if actions.is_empty() {
+            tr!(ctx, "schedule-empty")
+        } else {
+            actions
+                .iter()
+                .map(|action| format_scheduled_action(ctx, action))
+                .collect::<Vec<String>>()
+                .join("\n")
+        };
+
+        ctx.reply(res).await?;
+        return Ok(());
+    }
+
+    ctx.reply(tr!(ctx, "schedule-list-failed")).await?;
+    Ok(())
+}
+
+#[poise::command(
+    ephemeral,
+    slash_command,
+    prefix_command,
+    required_permissions = "KICK_MEMBERS | BAN_MEMBERS | MODERATE_MEMBERS",
+    guild_only
+)]
+pub async fn cancel(ctx: Context<'_>, id: i32) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(result) = ctx
+        .data()
+        .database
+        .remove_guild_scheduled_action(guild_id, id)
+        .await
+    {
+        let res = match result.rows_affected() {
+            0 => tr!(ctx, "schedule-not-found", id: id),
+            _ => tr!(ctx, "schedule-cancelled", id: id),
+        };
+
+        ctx.reply(res).await?;
+        return Ok(());
+    }
+
+    ctx.reply(tr!(ctx, "schedule-cancel-failed", id: id))
+        .await?;
+    Ok(())
+}
+
+fn format_scheduled_action(ctx: Context<'_>, action: &ScheduledActionModel) -> String {
+    let target = match action.action {
+        ScheduledAction::Unban | ScheduledAction::Unmute => format!("<@{}>", action.target_id),
+        ScheduledAction::Slowmode | ScheduledAction::Unlock => format!("<#{}>", action.target_id),
+    };
+
+    tr!(
+        ctx,
+        "schedule-line",
+        id: action.id,
+        action: tr!(
+            ctx,
+            "schedule-action-name",
+            action: format!("{:?}", action.action).to_lowercase()
+        ),
+        target: target,
+        moderator: format!("<@{}>", action.moderator_id),
+        time: format!("<t:{}:R>", action.run_at.timestamp())
+    )
+}
@@ -1,5 +1,6 @@
 use serenity::all::{Attachment, CreateAttachment, CreateSticker, Message, Sticker};
 
+use crate::translation::tr;
 use crate::{Context, Error};
 
 #[poise::command(
@@ -40,11 +41,8 @@ pub async fn add(
     let guild_id = ctx.guild_id().unwrap();
 
     let res = match guild_id.create_sticker(&ctx, builder).await {
-        Err(_) => format!(":x: Failed to create sticker `{name}`!"),
-        Ok(sticker) => format!(
-            ":white_check_mark: Sticker `{}` created with success!",
-            sticker.name
-        ),
+        Err(_) => tr!(ctx, "sticker-create-failed", name: name),
+        Ok(sticker) => tr!(ctx, "sticker-created", name: sticker.name),
     };
 
     ctx.reply(res).await?;
@@ -61,8 +59,7 @@ pub async fn show(
     let tags = tags.unwrap_or("".to_owned());
 
     if name.is_empty() && tags.is_empty() || !name.is_empty() && !tags.is_empty() {
-        ctx.reply(":warning: Must provide `name` or `tags`!")
-            .await?;
+        ctx.reply(tr!(ctx, "sticker-name-or-tags")).await?;
         return Ok(());
     }
 
@@ -70,7 +67,7 @@ pub async fn show(
     let stickers = guild_id.stickers(&ctx.http()).await?;
 
     if stickers.is_empty() {
-        ctx.reply(":x: Guild has no stickers!").await?;
+        ctx.reply(tr!(ctx, "sticker-empty")).await?;
         return Ok(());
     }
 
@@ -83,7 +80,7 @@ pub async fn show(
     let sticker = filtered.first();
 
     if let None = sticker {
-        ctx.reply(":x: No sticker found!").await?;
+        ctx.reply(tr!(ctx, "sticker-not-found")).await?;
         return Ok(());
     }
 
@@ -108,7 +105,7 @@ pub async fn remove(ctx: Context<'_>, name: String) -> Result<(), Error> {
     let stickers = guild_id.stickers(&ctx.http()).await?;
 
     if stickers.is_empty() {
-        ctx.reply(":warning: Guild has no stickers!").await?;
+        ctx.reply(tr!(ctx, "sticker-empty")).await?;
         return Ok(());
     }
 
@@ -116,18 +113,15 @@ pub async fn remove(ctx: Context<'_>, name: String) -> Result<(), Error> {
     let sticker = filtered.first();
 
     if let None = sticker {
-        ctx.reply(":warning: No sticker found!").await?;
+        ctx.reply(tr!(ctx, "sticker-not-found")).await?;
         return Ok(());
     }
 
     let sticker = sticker.unwrap();
 
     let res = match guild_id.delete_sticker(&ctx.http(), sticker.id).await {
-        Err(_) => format!(":x: Failed to remove sticker `{}`!", sticker.name),
-        Ok(_) => format!(
-            ":white_check_mark: Sticker `{}` removed successfully!",
-            sticker.name
-        ),
+        Err(_) => tr!(ctx, "sticker-remove-failed", name: sticker.name.as_str()),
+        Ok(_) => tr!(ctx, "sticker-removed", name: sticker.name.as_str()),
     };
 
     ctx.reply(res).await?;
@@ -139,7 +133,7 @@ pub async fn context_get_sticker(ctx: Context<'_>, message: Message) -> Result<(
     ctx.defer_ephemeral().await?;
 
     let res = match message.sticker_items.first() {
-        None => ":x: No sticker found in the message!".to_owned(),
+        None => tr!(ctx, "sticker-message-empty"),
         Some(sticker_item) => format!("{}?size=2048", sticker_item.image_url().unwrap()),
     };
 
@@ -162,7 +156,7 @@ pub async fn context_clone_sticker(ctx: Context<'_>, message: Message) -> Result
     };
 
     if let None = sticker_item {
-        ctx.reply(":x: No sticker found in the message!").await?;
+        ctx.reply(tr!(ctx, "sticker-message-empty")).await?;
         return Ok(());
     }
 
@@ -170,8 +164,7 @@ pub async fn context_clone_sticker(ctx: Context<'_>, message: Message) -> Result
     let image_url = sticker_item.image_url();
 
     if let None = image_url {
-        ctx.reply(":x: Failed to retrieve sticker image format!")
-            .await?;
+        ctx.reply(tr!(ctx, "sticker-image-failed")).await?;
         return Ok(());
     }
 
@@ -189,11 +182,8 @@ pub async fn context_clone_sticker(ctx: Context<'_>, message: Message) -> Result
     let guild_id = ctx.guild_id().unwrap();
 
     let res = match guild_id.create_sticker(&ctx, builder).await {
-        Err(_) => format!(":x: Failed to create sticker `{name}`!"),
-        Ok(sticker) => format!(
-            ":white_check_mark: Sticker `{}` created with success!",
-            sticker.name
-        ),
+        Err(_) => tr!(ctx, "sticker-create-failed", name: name),
+        Ok(sticker) => tr!(ctx, "sticker-created", name: sticker.name),
     };
 
     ctx.reply(res).await?;
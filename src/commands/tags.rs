@@ -1,4 +1,5 @@
 use crate::models::TagModel;
+use crate::translation::tr;
 use crate::{Context, Error};
 use serenity::model::user::User;
 
@@ -17,8 +18,10 @@ pub async fn tag(_: Context<'_>) -> Result<(), Error> {
 pub async fn add(ctx: Context<'_>, name: String, content: String) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
-    if let Ok(_) = ctx.data().database.get_tag(&name).await {
-        ctx.reply(format!(":warning: Tag `{name}` already exists!"))
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Ok(_) = ctx.data().database.get_tag(guild_id, &name).await {
+        ctx.reply(tr!(ctx, "tag-exists", name: name.as_str()))
             .await?;
         return Ok(());
     }
@@ -26,18 +29,14 @@ pub async fn add(ctx: Context<'_>, name: String, content: String) -> Result<(),
     if let Ok(tag) = ctx
         .data()
         .database
-        .add_tag(&name, &content, ctx.author().id)
+        .add_tag(guild_id, &name, &content, ctx.author().id)
         .await
     {
-        ctx.reply(format!(
-            ":white_check_mark: Tag `{}` created with success!",
-            tag.name
-        ))
-        .await?;
+        ctx.reply(tr!(ctx, "tag-created", name: tag.name)).await?;
         return Ok(());
     }
 
-    ctx.reply(format!(":x: Cannot create tag {name}!")).await?;
+    ctx.reply(tr!(ctx, "tag-create-failed", name: name)).await?;
     Ok(())
 }
 
@@ -45,17 +44,16 @@ pub async fn add(ctx: Context<'_>, name: String, content: String) -> Result<(),
 pub async fn edit(ctx: Context<'_>, name: String, content: String) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
+    let guild_id = ctx.guild_id().unwrap();
+
     let res = match ctx
         .data()
         .database
-        .update_tag(&name, &content, ctx.author().id)
+        .update_tag(guild_id, &name, &content, ctx.author().id)
         .await
     {
-        Err(_) => format!(":x: Tag `{name}` doesn't exist or you're not the owner of it!"),
-        Ok(tag) => format!(
-            ":white_check_mark: Content of the tag `{}` updated successfully!",
-            tag.name
-        ),
+        Err(_) => tr!(ctx, "tag-edit-failed", name: name),
+        Ok(tag) => tr!(ctx, "tag-updated", name: tag.name),
     };
 
     ctx.reply(res).await?;
@@ -66,8 +64,10 @@ pub async fn edit(ctx: Context<'_>, name: String, content: String) -> Result<(),
 pub async fn see(ctx: Context<'_>, name: String) -> Result<(), Error> {
     ctx.defer().await?;
 
-    let res = match ctx.data().database.get_tag(&name).await {
-        Err(_) => format!(":x: Tag `{name}` doesn't exists!"),
+    let guild_id = ctx.guild_id().unwrap();
+
+    let res = match ctx.data().database.get_tag(guild_id, &name).await {
+        Err(_) => tr!(ctx, "tag-not-found", name: name),
         Ok(tag) => tag.content,
     };
 
@@ -79,18 +79,20 @@ pub async fn see(ctx: Context<'_>, name: String) -> Result<(), Error> {
 pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
-    let res = match ctx.data().database.get_all_tags().await {
-        Err(_) => format!(":x: Server has no tags!"),
-        Ok(tags) => parse_tag_names(&tags),
+    let guild_id = ctx.guild_id().unwrap();
+
+    let res = match ctx.data().database.get_all_tags(guild_id).await {
+        Err(_) => tr!(ctx, "tag-server-empty"),
+        Ok(tags) => parse_tag_names(ctx, &tags),
     };
 
     ctx.reply(res).await?;
     Ok(())
 }
 
-fn parse_tag_names(tags: &[TagModel]) -> String {
+fn parse_tag_names(ctx: Context<'_>, tags: &[TagModel]) -> String {
     if tags.is_empty() {
-        return ":x: No tags!".to_owned();
+        return tr!(ctx, "tag-empty");
     }
 
     let mut names = vec![];
@@ -105,9 +107,11 @@ fn parse_tag_names(tags: &[TagModel]) -> String {
 pub async fn user(ctx: Context<'_>, user: User) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
-    let res = match ctx.data().database.get_user_tags(user.id).await {
-        Err(_) => format!(":x: User has no tags!"),
-        Ok(tags) => parse_tag_names(&tags),
+    let guild_id = ctx.guild_id().unwrap();
+
+    let res = match ctx.data().database.get_user_tags(guild_id, user.id).await {
+        Err(_) => tr!(ctx, "tag-user-empty"),
+        Ok(tags) => parse_tag_names(ctx, &tags),
     };
 
     ctx.reply(res).await?;
@@ -118,22 +122,23 @@ pub async fn user(ctx: Context<'_>, user: User) -> Result<(), Error> {
 pub async fn remove(ctx: Context<'_>, name: String) -> Result<(), Error> {
     ctx.defer_ephemeral().await?;
 
-    if let Err(_) = ctx.data().database.get_tag(&name).await {
-        ctx.reply(format!(":x: Tag `{name}` doesn't exists!"))
-            .await?;
+    let guild_id = ctx.guild_id().unwrap();
+
+    if let Err(_) = ctx.data().database.get_tag(guild_id, &name).await {
+        ctx.reply(tr!(ctx, "tag-not-found", name: name)).await?;
         return Ok(());
     }
 
     let res = match ctx
         .data()
         .database
-        .remove_tag(&name, ctx.author().id)
+        .remove_tag(guild_id, &name, ctx.author().id)
         .await
         .unwrap()
         .rows_affected()
     {
-        1 => format!(":white_check_mark: Tag `{name}` deleted!"),
-        _ => format!(":x: You're not the owner of the tag `{name}`!"),
+        1 => tr!(ctx, "tag-deleted", name: name),
+        _ => tr!(ctx, "tag-not-owner", name: name),
     };
 
     ctx.reply(res).await?;
@@ -1,11 +1,20 @@
 use crate::models::{
-    AnimalModel, BangPointModel, InfractionModel, Punishment, PunishmentModel, Severity, TagModel,
-    UserInfractionModel,
+    AllowlistEntryModel, AllowlistKind, AnimalModel, AppealModel, AppealStatus, AutomodFilterModel,
+    BangPointModel, CaseAction, CaseModel, ChannelLockModel, ChannelLockOverwriteModel,
+    EscalationModel, FilterKind, GuildSettingModel, HistoryEntryModel, InfractionModel,
+    LockdownModel, MuteModel, OverwriteKind, Punishment, PunishmentModel, PurgeArchiveModel,
+    ScheduledAction, ScheduledActionModel, Severity, TagModel, UserInfractionModel,
+    UserLocaleModel,
+};
+use crate::settings::Setting;
+use crate::utils::from_now;
+use serenity::all::{
+    ChannelId, GuildId, Message, PermissionOverwrite, PermissionOverwriteType, RoleId, UserId,
 };
-use serenity::all::UserId;
 use sqlx::{
     postgres::{PgPoolOptions, PgQueryResult},
-    Error, Pool, Postgres,
+    types::chrono::{DateTime, Utc},
+    Error, Pool, Postgres, Transaction,
 };
 
 pub struct Database {
@@ -24,13 +33,15 @@ impl Database {
 
     pub async fn add_animal(
         &self,
+        guild_id: GuildId,
         animal: &str,
         emoji: &str,
         points: i32,
     ) -> Result<AnimalModel, Error> {
         sqlx::query_as!(
             AnimalModel,
-            r#"INSERT INTO animals (animal, emoji, points) VALUES ($1, $2, $3) RETURNING id, animal, emoji, points"#,
+            r#"INSERT INTO animals (guild_id, animal, emoji, points) VALUES ($1, $2, $3, $4) RETURNING id, guild_id, animal, emoji, points"#,
+            guild_id.to_string(),
             animal,
             emoji,
             points
@@ -39,41 +50,60 @@ impl Database {
             .await
     }
 
-    pub async fn remove_animal(&self, animal: &str) -> Result<PgQueryResult, Error> {
-        sqlx::query!("DELETE FROM animals WHERE animal = $1", animal)
-            .execute(&self.pool)
-            .await
+    pub async fn remove_animal(
+        &self,
+        guild_id: GuildId,
+        animal: &str,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "DELETE FROM animals WHERE guild_id = $1 AND animal = $2",
+            guild_id.to_string(),
+            animal
+        )
+        .execute(&self.pool)
+        .await
     }
 
-    pub async fn get_animal(&self, animal: &str) -> Result<AnimalModel, Error> {
+    pub async fn get_animal(&self, guild_id: GuildId, animal: &str) -> Result<AnimalModel, Error> {
         sqlx::query_as!(
             AnimalModel,
-            r#"SELECT * FROM animals WHERE animal = $1"#,
+            r#"SELECT * FROM animals WHERE guild_id = $1 AND animal = $2"#,
+            guild_id.to_string(),
             animal
         )
         .fetch_one(&self.pool)
         .await
     }
 
-    pub async fn get_animals(&self) -> Result<Vec<AnimalModel>, Error> {
-        sqlx::query_as!(AnimalModel, r#"SELECT * FROM animals"#)
-            .fetch_all(&self.pool)
-            .await
+    pub async fn get_animals(&self, guild_id: GuildId) -> Result<Vec<AnimalModel>, Error> {
+        sqlx::query_as!(
+            AnimalModel,
+            r#"SELECT * FROM animals WHERE guild_id = $1"#,
+            guild_id.to_string()
+        )
+        .fetch_all(&self.pool)
+        .await
     }
 
-    pub async fn get_bang_ranking(&self) -> Result<Vec<BangPointModel>, Error> {
+    pub async fn get_bang_ranking(&self, guild_id: GuildId) -> Result<Vec<BangPointModel>, Error> {
         sqlx::query_as!(
             BangPointModel,
-            r#"SELECT * FROM bang_points ORDER BY points DESC LIMIT 10"#
+            r#"SELECT * FROM bang_points WHERE guild_id = $1 ORDER BY points DESC LIMIT 10"#,
+            guild_id.to_string()
         )
         .fetch_all(&self.pool)
         .await
     }
 
-    pub async fn get_user_bang_points(&self, user_id: String) -> Result<BangPointModel, Error> {
+    pub async fn get_user_bang_points(
+        &self,
+        guild_id: GuildId,
+        user_id: String,
+    ) -> Result<BangPointModel, Error> {
         sqlx::query_as!(
             BangPointModel,
-            r#"SELECT * FROM bang_points WHERE user_id = $1"#,
+            r#"SELECT * FROM bang_points WHERE guild_id = $1 AND user_id = $2"#,
+            guild_id.to_string(),
             user_id
         )
         .fetch_one(&self.pool)
@@ -82,13 +112,15 @@ impl Database {
 
     pub async fn add_user_bang_points(
         &self,
+        guild_id: GuildId,
         user_id: String,
         points: i32,
     ) -> Result<BangPointModel, Error> {
         sqlx::query_as!(
             BangPointModel,
-            r#"UPDATE bang_points SET points = points + $1 WHERE user_id = $2 RETURNING id, user_id, points"#,
+            r#"UPDATE bang_points SET points = points + $1 WHERE guild_id = $2 AND user_id = $3 RETURNING id, guild_id, user_id, points"#,
             points,
+            guild_id.to_string(),
             user_id
         )
             .fetch_one(&self.pool)
@@ -97,12 +129,14 @@ impl Database {
 
     pub async fn create_user_bang_points(
         &self,
+        guild_id: GuildId,
         user_id: String,
         points: i32,
     ) -> Result<BangPointModel, Error> {
         sqlx::query_as!(
             BangPointModel,
-            r#"INSERT INTO bang_points (user_id, points) VALUES ($1, $2) RETURNING id, user_id, points"#,
+            r#"INSERT INTO bang_points (guild_id, user_id, points) VALUES ($1, $2, $3) RETURNING id, guild_id, user_id, points"#,
+            guild_id.to_string(),
             user_id,
             points
         )
@@ -112,62 +146,323 @@ impl Database {
 
     pub async fn create_or_add_user_bang_points(
         &self,
+        guild_id: GuildId,
         user_id: String,
         points: i32,
     ) -> Result<BangPointModel, Error> {
-        if let Ok(_) = self.get_user_bang_points(user_id.clone()).await {
-            return self.add_user_bang_points(user_id, points).await;
+        if self
+            .get_user_bang_points(guild_id, user_id.clone())
+            .await
+            .is_ok()
+        {
+            return self.add_user_bang_points(guild_id, user_id, points).await;
         }
 
-        self.create_user_bang_points(user_id, points).await
+        self.create_user_bang_points(guild_id, user_id, points)
+            .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn log_user_punishment(
         &self,
+        guild_id: GuildId,
         user_id: &UserId,
+        moderator_id: UserId,
         punishment: Punishment,
+        reason: &str,
         duration: i64,
-    ) -> Result<PunishmentModel, Error> {
-        sqlx::query_as!(
-            PunishmentModel,
-            r#"INSERT INTO punishments (user_id, punishment, duration) VALUES ($1, $2, $3) RETURNING id, user_id, punishment AS "punishment!: Punishment", duration"#,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<CaseModel, Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        let case = Self::open_case(
+            &mut transaction,
+            guild_id,
+            punishment.clone().into(),
+            user_id,
+            moderator_id,
+            reason,
+        )
+        .await?;
+
+        sqlx::query!(
+            r#"INSERT INTO punishments (guild_id, user_id, moderator_id, punishment, reason, duration, expires_at, case_id) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"#,
+            guild_id.to_string(),
             user_id.get().to_string(),
+            moderator_id.to_string(),
             punishment as Punishment,
-            duration
+            reason,
+            duration,
+            expires_at,
+            case.id
         )
-            .fetch_one(&self.pool)
-            .await
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        Ok(case)
     }
 
     pub async fn log_user_infraction(
         &self,
+        guild_id: GuildId,
         user_id: &UserId,
+        moderator_id: UserId,
         infraction_id: i32,
-    ) -> Result<UserInfractionModel, Error> {
+        reason: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<CaseModel, Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        let punishment = sqlx::query_scalar!(
+            r#"SELECT punishment AS "punishment!: Punishment" FROM infractions WHERE guild_id = $1 AND id = $2"#,
+            guild_id.to_string(),
+            infraction_id
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        let case = Self::open_case(
+            &mut transaction,
+            guild_id,
+            punishment.into(),
+            user_id,
+            moderator_id,
+            reason,
+        )
+        .await?;
+
+        sqlx::query!(
+            r#"INSERT INTO user_infractions (guild_id, user_id, moderator_id, infraction_id, reason, expires_at, case_id) VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+            guild_id.to_string(),
+            user_id.get().to_string(),
+            moderator_id.to_string(),
+            infraction_id,
+            reason,
+            expires_at,
+            case.id
+        )
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        Ok(case)
+    }
+
+    /// Logs an action that doesn't punish anyone by itself, like an unban
+    pub async fn log_case(
+        &self,
+        guild_id: GuildId,
+        action: CaseAction,
+        user_id: &UserId,
+        moderator_id: UserId,
+        reason: &str,
+    ) -> Result<CaseModel, Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        let case = Self::open_case(
+            &mut transaction,
+            guild_id,
+            action,
+            user_id,
+            moderator_id,
+            reason,
+        )
+        .await?;
+
+        transaction.commit().await?;
+        Ok(case)
+    }
+
+    async fn open_case(
+        transaction: &mut Transaction<'_, Postgres>,
+        guild_id: GuildId,
+        action: CaseAction,
+        user_id: &UserId,
+        moderator_id: UserId,
+        reason: &str,
+    ) -> Result<CaseModel, Error> {
+        let case_number = sqlx::query_scalar!(
+            r#"INSERT INTO case_counters (guild_id, last_case) VALUES ($1, 1) ON CONFLICT (guild_id) DO UPDATE SET last_case = case_counters.last_case + 1 RETURNING last_case"#,
+            guild_id.to_string()
+        )
+            .fetch_one(&mut **transaction)
+            .await?;
+
         sqlx::query_as!(
-            UserInfractionModel,
-            r#"INSERT INTO user_infractions (user_id, infraction_id) VALUES ($1, $2) RETURNING id, user_id, infraction_id, created_at"#,
+            CaseModel,
+            r#"INSERT INTO cases (guild_id, case_number, action, user_id, moderator_id, reason) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id, guild_id, case_number, action AS "action!: CaseAction", user_id, moderator_id, reason, created_at"#,
+            guild_id.to_string(),
+            case_number,
+            action as CaseAction,
             user_id.get().to_string(),
-            infraction_id
+            moderator_id.to_string(),
+            reason
+        )
+            .fetch_one(&mut **transaction)
+            .await
+    }
+
+    pub async fn get_case(&self, guild_id: GuildId, case_number: i32) -> Result<CaseModel, Error> {
+        sqlx::query_as!(
+            CaseModel,
+            r#"SELECT id, guild_id, case_number, action AS "action!: CaseAction", user_id, moderator_id, reason, created_at FROM cases WHERE guild_id = $1 AND case_number = $2"#,
+            guild_id.to_string(),
+            case_number
         )
             .fetch_one(&self.pool)
             .await
     }
 
-    pub async fn get_infraction(&self, id: i32) -> Result<InfractionModel, Error> {
+    pub async fn get_case_by_id(&self, case_id: i32) -> Result<Option<CaseModel>, Error> {
+        sqlx::query_as!(
+            CaseModel,
+            r#"SELECT id, guild_id, case_number, action AS "action!: CaseAction", user_id, moderator_id, reason, created_at FROM cases WHERE id = $1"#,
+            case_id
+        )
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn get_case_punishment(
+        &self,
+        case_id: i32,
+    ) -> Result<Option<PunishmentModel>, Error> {
+        sqlx::query_as!(
+            PunishmentModel,
+            r#"SELECT id, user_id, punishment AS "punishment!: Punishment", duration, expires_at, guild_id, moderator_id, reason, created_at, case_id FROM punishments WHERE case_id = $1"#,
+            case_id
+        )
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn get_case_infraction(
+        &self,
+        case_id: i32,
+    ) -> Result<Option<UserInfractionModel>, Error> {
+        sqlx::query_as!(
+            UserInfractionModel,
+            r#"SELECT * FROM user_infractions WHERE case_id = $1"#,
+            case_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn update_case_reason(
+        &self,
+        guild_id: GuildId,
+        case_number: i32,
+        reason: &str,
+    ) -> Result<CaseModel, Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        let case = sqlx::query_as!(
+            CaseModel,
+            r#"UPDATE cases SET reason = $1 WHERE guild_id = $2 AND case_number = $3 RETURNING id, guild_id, case_number, action AS "action!: CaseAction", user_id, moderator_id, reason, created_at"#,
+            reason,
+            guild_id.to_string(),
+            case_number
+        )
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        sqlx::query!(
+            "UPDATE punishments SET reason = $1 WHERE case_id = $2",
+            reason,
+            case.id
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE user_infractions SET reason = $1 WHERE case_id = $2",
+            reason,
+            case.id
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+        Ok(case)
+    }
+
+    /// Deleting a case also deletes the punishment or infraction record attached to it
+    pub async fn remove_case(
+        &self,
+        guild_id: GuildId,
+        case_number: i32,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "DELETE FROM cases WHERE guild_id = $1 AND case_number = $2",
+            guild_id.to_string(),
+            case_number
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    pub async fn get_user_ban_expiry(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<Option<DateTime<Utc>>, Error> {
+        sqlx::query_scalar!(
+            r#"SELECT MAX(expires_at) FROM (
+                SELECT expires_at FROM punishments WHERE guild_id = $1 AND user_id = $2 AND punishment = 'ban'
+                UNION ALL
+                SELECT ui.expires_at FROM user_infractions ui JOIN infractions i ON i.guild_id = ui.guild_id AND i.id = ui.infraction_id WHERE ui.guild_id = $1 AND ui.user_id = $2 AND i.punishment = 'ban'
+            ) bans WHERE expires_at > Now()"#,
+            guild_id.to_string(),
+            user_id.get().to_string()
+        )
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn end_user_bans(&self, guild_id: GuildId, user_id: UserId) -> Result<(), Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        sqlx::query!(
+            "UPDATE punishments SET expires_at = Now() WHERE guild_id = $1 AND user_id = $2 AND punishment = 'ban' AND expires_at > Now()",
+            guild_id.to_string(),
+            user_id.get().to_string()
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE user_infractions SET expires_at = Now() FROM infractions WHERE infractions.guild_id = user_infractions.guild_id AND infractions.id = user_infractions.infraction_id AND user_infractions.guild_id = $1 AND user_infractions.user_id = $2 AND infractions.punishment = 'ban' AND user_infractions.expires_at > Now()",
+            guild_id.to_string(),
+            user_id.get().to_string()
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await
+    }
+
+    pub async fn get_infraction(
+        &self,
+        guild_id: GuildId,
+        id: i32,
+    ) -> Result<InfractionModel, Error> {
         sqlx::query_as!(
             InfractionModel,
-            r#"SELECT id, severity AS "severity!: Severity", punishment AS "punishment!: Punishment", duration FROM infractions WHERE id = $1"#,
+            r#"SELECT id, guild_id, severity AS "severity!: Severity", punishment AS "punishment!: Punishment", duration, expires_after FROM infractions WHERE guild_id = $1 AND id = $2"#,
+            guild_id.to_string(),
             id
         )
             .fetch_one(&self.pool)
             .await
     }
 
-    pub async fn get_infractions(&self) -> Result<Vec<InfractionModel>, Error> {
+    pub async fn get_infractions(&self, guild_id: GuildId) -> Result<Vec<InfractionModel>, Error> {
         sqlx::query_as!(
             InfractionModel,
-            r#"SELECT id, severity AS "severity!: Severity", punishment AS "punishment!: Punishment", duration FROM infractions"#
+            r#"SELECT id, guild_id, severity AS "severity!: Severity", punishment AS "punishment!: Punishment", duration, expires_after FROM infractions WHERE guild_id = $1 ORDER BY id"#,
+            guild_id.to_string()
         )
             .fetch_all(&self.pool)
             .await
@@ -175,18 +470,22 @@ impl Database {
 
     pub async fn add_infraction(
         &self,
+        guild_id: GuildId,
         id: i32,
         severity: Severity,
         punishment: Punishment,
         duration: i64,
+        expires_after: Option<i32>,
     ) -> Result<InfractionModel, Error> {
         sqlx::query_as!(
             InfractionModel,
-            r#"INSERT INTO infractions (id, severity, punishment, duration) VALUES ($1, $2, $3, $4) RETURNING id, severity AS "severity!: Severity", punishment AS "punishment!: Punishment", duration"#,
+            r#"INSERT INTO infractions (guild_id, id, severity, punishment, duration, expires_after) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id, guild_id, severity AS "severity!: Severity", punishment AS "punishment!: Punishment", duration, expires_after"#,
+            guild_id.to_string(),
             id,
             severity as Severity,
             punishment as Punishment,
-            duration
+            duration,
+            expires_after
         )
             .fetch_one(&self.pool)
             .await
@@ -194,57 +493,263 @@ impl Database {
 
     pub async fn update_infraction(
         &self,
+        guild_id: GuildId,
         id: i32,
         severity: Severity,
         punishment: Punishment,
         duration: i64,
+        expires_after: Option<i32>,
     ) -> Result<InfractionModel, Error> {
         sqlx::query_as!(
             InfractionModel,
-            r#"UPDATE infractions SET severity = $1, punishment = $2, duration = $3 WHERE id = $4 RETURNING id, severity AS "severity!: Severity", punishment AS "punishment!: Punishment", duration"#,
+            r#"UPDATE infractions SET severity = $1, punishment = $2, duration = $3, expires_after = $4 WHERE guild_id = $5 AND id = $6 RETURNING id, guild_id, severity AS "severity!: Severity", punishment AS "punishment!: Punishment", duration, expires_after"#,
             severity as Severity,
             punishment as Punishment,
             duration,
+            expires_after,
+            guild_id.to_string(),
             id
         )
             .fetch_one(&self.pool)
             .await
     }
 
-    pub async fn remove_infraction(&self, id: i32) -> Result<PgQueryResult, Error> {
-        sqlx::query!("DELETE FROM infractions WHERE id = $1", id)
-            .execute(&self.pool)
-            .await
+    pub async fn remove_infraction(
+        &self,
+        guild_id: GuildId,
+        id: i32,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "DELETE FROM infractions WHERE guild_id = $1 AND id = $2",
+            guild_id.to_string(),
+            id
+        )
+        .execute(&self.pool)
+        .await
     }
 
     pub async fn get_user_infractions(
         &self,
+        guild_id: GuildId,
         user_id: UserId,
     ) -> Result<Vec<UserInfractionModel>, Error> {
         sqlx::query_as!(
             UserInfractionModel,
-            r#"SELECT * FROM user_infractions WHERE user_id = $1"#,
+            r#"SELECT * FROM user_infractions WHERE guild_id = $1 AND user_id = $2"#,
+            guild_id.to_string(),
             user_id.get().to_string()
         )
         .fetch_all(&self.pool)
         .await
     }
 
-    pub async fn get_tag(&self, name: &str) -> Result<TagModel, Error> {
-        sqlx::query_as!(TagModel, r#"SELECT * FROM tags WHERE name = $1"#, name)
+    pub async fn add_escalation(
+        &self,
+        guild_id: GuildId,
+        severity: Severity,
+        threshold: i32,
+        days: i32,
+        punishment: Punishment,
+        duration: i64,
+    ) -> Result<EscalationModel, Error> {
+        sqlx::query_as!(
+            EscalationModel,
+            r#"INSERT INTO escalations (guild_id, severity, threshold, days, punishment, duration) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id, guild_id, severity AS "severity!: Severity", threshold, days, punishment AS "punishment!: Punishment", duration"#,
+            guild_id.to_string(),
+            severity as Severity,
+            threshold,
+            days,
+            punishment as Punishment,
+            duration
+        )
             .fetch_one(&self.pool)
             .await
     }
 
+    pub async fn get_escalations(&self, guild_id: GuildId) -> Result<Vec<EscalationModel>, Error> {
+        sqlx::query_as!(
+            EscalationModel,
+            r#"SELECT id, guild_id, severity AS "severity!: Severity", threshold, days, punishment AS "punishment!: Punishment", duration FROM escalations WHERE guild_id = $1 ORDER BY severity, threshold"#,
+            guild_id.to_string()
+        )
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    /// Escalations for a severity, highest threshold first
+    pub async fn get_severity_escalations(
+        &self,
+        guild_id: GuildId,
+        severity: Severity,
+    ) -> Result<Vec<EscalationModel>, Error> {
+        sqlx::query_as!(
+            EscalationModel,
+            r#"SELECT id, guild_id, severity AS "severity!: Severity", threshold, days, punishment AS "punishment!: Punishment", duration FROM escalations WHERE guild_id = $1 AND severity = $2 ORDER BY threshold DESC"#,
+            guild_id.to_string(),
+            severity as Severity
+        )
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn remove_escalation(
+        &self,
+        guild_id: GuildId,
+        id: i32,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "DELETE FROM escalations WHERE guild_id = $1 AND id = $2",
+            guild_id.to_string(),
+            id
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    /// Pardons the punishment or infraction recorded by a case, like after an accepted appeal
+    pub async fn pardon_case(&self, case_id: i32, moderator_id: UserId) -> Result<(), Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        sqlx::query!(
+            "UPDATE punishments SET pardoned_at = Now(), pardoned_by = $1 WHERE case_id = $2 AND pardoned_at IS NULL",
+            moderator_id.to_string(),
+            case_id
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE user_infractions SET pardoned_at = Now(), pardoned_by = $1 WHERE case_id = $2 AND pardoned_at IS NULL",
+            moderator_id.to_string(),
+            case_id
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await
+    }
+
+    /// Counts infractions of a severity since a given date, ignoring expired and pardoned ones.
+    /// Direct strikes have no rule attached to them, so they count as `Low` infractions.
+    pub async fn count_user_infractions(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        severity: Severity,
+        since: DateTime<Utc>,
+    ) -> Result<i64, Error> {
+        sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM (
+                SELECT ui.id FROM user_infractions ui JOIN infractions i ON i.guild_id = ui.guild_id AND i.id = ui.infraction_id
+                    WHERE ui.guild_id = $1 AND ui.user_id = $2 AND i.severity = $3 AND ui.created_at >= $4
+                    AND ui.pardoned_at IS NULL
+                    AND (i.expires_after IS NULL OR ui.created_at + make_interval(days => i.expires_after) > Now())
+                UNION ALL
+                SELECT p.id FROM punishments p
+                    WHERE $3 = 'low'::severity AND p.guild_id = $1 AND p.user_id = $2 AND p.punishment = 'strike' AND p.created_at >= $4
+                    AND p.pardoned_at IS NULL
+            ) records"#,
+            guild_id.to_string(),
+            user_id.get().to_string(),
+            severity as Severity,
+            since
+        )
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    /// Escalation reached by the user's latest infraction of a severity, along with the number of
+    /// infractions counted for it
+    pub async fn get_due_escalation(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        severity: Severity,
+    ) -> Result<Option<(EscalationModel, i64)>, Error> {
+        let escalations = self
+            .get_severity_escalations(guild_id, severity.clone())
+            .await?;
+
+        for escalation in escalations.into_iter() {
+            // Periods reaching before the earliest date cover every infraction
+            let since = from_now(-(escalation.days as i64) * 60 * 60 * 24)
+                .unwrap_or(DateTime::<Utc>::MIN_UTC);
+            let count = self
+                .count_user_infractions(guild_id, user_id, severity.clone(), since)
+                .await?;
+
+            // Escalate every time the threshold is reached again within the period
+            if count > 0 && count % escalation.threshold as i64 == 0 {
+                return Ok(Some((escalation, count)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub async fn get_user_history(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<Vec<HistoryEntryModel>, Error> {
+        sqlx::query_as!(
+            HistoryEntryModel,
+            r#"SELECT case_number AS "case_number?", punishment AS "punishment?: Punishment", severity AS "severity?: Severity", user_infraction_id AS "user_infraction_id?", infraction_id AS "infraction_id?", reason AS "reason!", moderator_id AS "moderator_id?", duration AS "duration!", created_at AS "created_at?", expires_at AS "expires_at?", expired AS "expired!", pardoned AS "pardoned!" FROM (
+                SELECT c.case_number, p.punishment, NULL::severity AS severity, NULL::integer AS user_infraction_id, NULL::integer AS infraction_id, p.reason, p.moderator_id, p.duration, p.created_at, p.expires_at, false AS expired, p.pardoned_at IS NOT NULL AS pardoned
+                    FROM punishments p LEFT JOIN cases c ON c.id = p.case_id
+                    WHERE p.guild_id = $1 AND p.user_id = $2
+                UNION ALL
+                SELECT c.case_number, i.punishment, i.severity, ui.id, ui.infraction_id, ui.reason, ui.moderator_id, COALESCE(i.duration, 0), ui.created_at, ui.expires_at,
+                        COALESCE(ui.created_at + make_interval(days => i.expires_after) <= Now(), false),
+                        ui.pardoned_at IS NOT NULL
+                    FROM user_infractions ui LEFT JOIN infractions i ON i.guild_id = ui.guild_id AND i.id = ui.infraction_id LEFT JOIN cases c ON c.id = ui.case_id
+                    WHERE ui.guild_id = $1 AND ui.user_id = $2
+            ) history ORDER BY created_at DESC NULLS LAST"#,
+            guild_id.to_string(),
+            user_id.get().to_string()
+        )
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn pardon_user_infraction(
+        &self,
+        guild_id: GuildId,
+        id: i32,
+        moderator_id: UserId,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "UPDATE user_infractions SET pardoned_at = Now(), pardoned_by = $1 WHERE guild_id = $2 AND id = $3 AND pardoned_at IS NULL",
+            moderator_id.to_string(),
+            guild_id.to_string(),
+            id
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    pub async fn get_tag(&self, guild_id: GuildId, name: &str) -> Result<TagModel, Error> {
+        sqlx::query_as!(
+            TagModel,
+            r#"SELECT * FROM tags WHERE guild_id = $1 AND name = $2"#,
+            guild_id.to_string(),
+            name
+        )
+        .fetch_one(&self.pool)
+        .await
+    }
+
     pub async fn add_tag(
         &self,
+        guild_id: GuildId,
         name: &str,
         content: &str,
         user_id: UserId,
     ) -> Result<TagModel, Error> {
         sqlx::query_as!(
             TagModel,
-            r#"INSERT INTO tags (user_id, name, content) VALUES ($1, $2, $3) RETURNING id, user_id, name, content"#,
+            r#"INSERT INTO tags (guild_id, user_id, name, content) VALUES ($1, $2, $3, $4) RETURNING id, guild_id, user_id, name, content"#,
+            guild_id.to_string(),
             user_id.to_string(),
             name,
             content
@@ -255,14 +760,16 @@ impl Database {
 
     pub async fn update_tag(
         &self,
+        guild_id: GuildId,
         name: &str,
         content: &str,
         user_id: UserId,
     ) -> Result<TagModel, Error> {
         sqlx::query_as!(
             TagModel,
-            r#"UPDATE tags SET content = $1 WHERE user_id = $2 AND name = $3 RETURNING id, user_id, name, content"#,
+            r#"UPDATE tags SET content = $1 WHERE guild_id = $2 AND user_id = $3 AND name = $4 RETURNING id, guild_id, user_id, name, content"#,
             content,
+            guild_id.to_string(),
             user_id.to_string(),
             name
         )
@@ -270,29 +777,646 @@ impl Database {
             .await
     }
 
-    pub async fn get_all_tags(&self) -> Result<Vec<TagModel>, Error> {
-        sqlx::query_as!(TagModel, r#"SELECT * FROM tags"#)
-            .fetch_all(&self.pool)
-            .await
+    pub async fn get_all_tags(&self, guild_id: GuildId) -> Result<Vec<TagModel>, Error> {
+        sqlx::query_as!(
+            TagModel,
+            r#"SELECT * FROM tags WHERE guild_id = $1"#,
+            guild_id.to_string()
+        )
+        .fetch_all(&self.pool)
+        .await
     }
 
-    pub async fn get_user_tags(&self, user_id: UserId) -> Result<Vec<TagModel>, Error> {
+    pub async fn get_user_tags(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<Vec<TagModel>, Error> {
         sqlx::query_as!(
             TagModel,
-            r#"SELECT * FROM tags WHERE user_id = $1"#,
+            r#"SELECT * FROM tags WHERE guild_id = $1 AND user_id = $2"#,
+            guild_id.to_string(),
             user_id.to_string()
         )
         .fetch_all(&self.pool)
         .await
     }
 
-    pub async fn remove_tag(&self, name: &str, user_id: UserId) -> Result<PgQueryResult, Error> {
+    pub async fn remove_tag(
+        &self,
+        guild_id: GuildId,
+        name: &str,
+        user_id: UserId,
+    ) -> Result<PgQueryResult, Error> {
         sqlx::query!(
-            "DELETE FROM tags WHERE name = $1 AND user_id = $2",
+            "DELETE FROM tags WHERE guild_id = $1 AND name = $2 AND user_id = $3",
+            guild_id.to_string(),
             name,
             user_id.to_string()
         )
         .execute(&self.pool)
         .await
     }
+
+    pub async fn add_scheduled_action(
+        &self,
+        guild_id: GuildId,
+        action: ScheduledAction,
+        target_id: String,
+        payload: Option<String>,
+        moderator_id: UserId,
+        run_at: DateTime<Utc>,
+    ) -> Result<ScheduledActionModel, Error> {
+        sqlx::query_as!(
+            ScheduledActionModel,
+            r#"INSERT INTO scheduled_actions (guild_id, action, target_id, payload, moderator_id, run_at) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id, guild_id, action AS "action!: ScheduledAction", target_id, payload, moderator_id, run_at, attempts, created_at"#,
+            guild_id.to_string(),
+            action as ScheduledAction,
+            target_id,
+            payload,
+            moderator_id.to_string(),
+            run_at
+        )
+            .fetch_one(&self.pool)
+            .await
+    }
+
+    pub async fn get_due_scheduled_actions(&self) -> Result<Vec<ScheduledActionModel>, Error> {
+        sqlx::query_as!(
+            ScheduledActionModel,
+            r#"SELECT id, guild_id, action AS "action!: ScheduledAction", target_id, payload, moderator_id, run_at, attempts, created_at FROM scheduled_actions WHERE run_at <= Now() ORDER BY run_at"#
+        )
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn get_next_scheduled_run(&self) -> Result<Option<DateTime<Utc>>, Error> {
+        sqlx::query_scalar!(r#"SELECT MIN(run_at) FROM scheduled_actions"#)
+            .fetch_one(&self.pool)
+            .await
+    }
+
+    pub async fn get_guild_scheduled_actions(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<Vec<ScheduledActionModel>, Error> {
+        sqlx::query_as!(
+            ScheduledActionModel,
+            r#"SELECT id, guild_id, action AS "action!: ScheduledAction", target_id, payload, moderator_id, run_at, attempts, created_at FROM scheduled_actions WHERE guild_id = $1 ORDER BY run_at"#,
+            guild_id.to_string()
+        )
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn remove_scheduled_action(&self, id: i32) -> Result<PgQueryResult, Error> {
+        sqlx::query!("DELETE FROM scheduled_actions WHERE id = $1", id)
+            .execute(&self.pool)
+            .await
+    }
+
+    pub async fn retry_scheduled_action(
+        &self,
+        id: i32,
+        run_at: DateTime<Utc>,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "UPDATE scheduled_actions SET run_at = $2, attempts = attempts + 1 WHERE id = $1",
+            id,
+            run_at
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    pub async fn remove_target_scheduled_actions(
+        &self,
+        guild_id: GuildId,
+        action: ScheduledAction,
+        target_id: String,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "DELETE FROM scheduled_actions WHERE guild_id = $1 AND action = $2 AND target_id = $3",
+            guild_id.to_string(),
+            action as ScheduledAction,
+            target_id
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    pub async fn remove_guild_scheduled_action(
+        &self,
+        guild_id: GuildId,
+        id: i32,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "DELETE FROM scheduled_actions WHERE guild_id = $1 AND id = $2",
+            guild_id.to_string(),
+            id
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    pub async fn get_guild_settings(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<Vec<GuildSettingModel>, Error> {
+        sqlx::query_as!(
+            GuildSettingModel,
+            "SELECT * FROM guild_settings WHERE guild_id = $1 ORDER BY key",
+            guild_id.to_string()
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn set_guild_setting(
+        &self,
+        guild_id: GuildId,
+        setting: Setting,
+        value: &str,
+    ) -> Result<GuildSettingModel, Error> {
+        sqlx::query_as!(
+            GuildSettingModel,
+            r#"INSERT INTO guild_settings (guild_id, key, value) VALUES ($1, $2, $3)
+               ON CONFLICT (guild_id, key) DO UPDATE SET value = $3, updated_at = now()
+               RETURNING *"#,
+            guild_id.to_string(),
+            setting.key(),
+            value
+        )
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    /// Every guild's value of a setting, for settings that are needed outside of a guild lookup
+    pub async fn get_setting_values(
+        &self,
+        setting: Setting,
+    ) -> Result<Vec<GuildSettingModel>, Error> {
+        sqlx::query_as!(
+            GuildSettingModel,
+            "SELECT * FROM guild_settings WHERE key = $1",
+            setting.key()
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn remove_guild_setting(
+        &self,
+        guild_id: GuildId,
+        setting: Setting,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "DELETE FROM guild_settings WHERE guild_id = $1 AND key = $2",
+            guild_id.to_string(),
+            setting.key()
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    /// Stores the channel's current overwrites. Fails if the channel already has a snapshot.
+    pub async fn add_channel_lock(
+        &self,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+        moderator_id: UserId,
+        overwrites: &[PermissionOverwrite],
+        lockdown: bool,
+    ) -> Result<ChannelLockModel, Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        let lock = sqlx::query_as!(
+            ChannelLockModel,
+            r#"INSERT INTO channel_locks (guild_id, channel_id, moderator_id, lockdown) VALUES ($1, $2, $3, $4) RETURNING *"#,
+            guild_id.to_string(),
+            channel_id.to_string(),
+            moderator_id.to_string(),
+            lockdown
+        )
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        for overwrite in overwrites.iter() {
+            let (kind, target_id) = match overwrite.kind {
+                PermissionOverwriteType::Role(role_id) => {
+                    (OverwriteKind::Role, role_id.to_string())
+                }
+                PermissionOverwriteType::Member(user_id) => {
+                    (OverwriteKind::Member, user_id.to_string())
+                }
+                _ => continue,
+            };
+
+            sqlx::query!(
+                r#"INSERT INTO channel_lock_overwrites (lock_id, kind, target_id, allow, deny) VALUES ($1, $2, $3, $4, $5)"#,
+                lock.id,
+                kind as OverwriteKind,
+                target_id,
+                overwrite.allow.bits() as i64,
+                overwrite.deny.bits() as i64
+            )
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+        Ok(lock)
+    }
+
+    pub async fn get_channel_lock(
+        &self,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+    ) -> Result<Option<ChannelLockModel>, Error> {
+        sqlx::query_as!(
+            ChannelLockModel,
+            r#"SELECT * FROM channel_locks WHERE guild_id = $1 AND channel_id = $2"#,
+            guild_id.to_string(),
+            channel_id.to_string()
+        )
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn get_channel_lock_overwrites(
+        &self,
+        lock_id: i32,
+    ) -> Result<Vec<ChannelLockOverwriteModel>, Error> {
+        sqlx::query_as!(
+            ChannelLockOverwriteModel,
+            r#"SELECT id, lock_id, kind AS "kind!: OverwriteKind", target_id, allow, deny FROM channel_lock_overwrites WHERE lock_id = $1"#,
+            lock_id
+        )
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn remove_channel_lock(&self, id: i32) -> Result<PgQueryResult, Error> {
+        sqlx::query!("DELETE FROM channel_locks WHERE id = $1", id)
+            .execute(&self.pool)
+            .await
+    }
+
+    pub async fn get_lockdown_channel_locks(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<Vec<ChannelLockModel>, Error> {
+        sqlx::query_as!(
+            ChannelLockModel,
+            r#"SELECT * FROM channel_locks WHERE guild_id = $1 AND lockdown"#,
+            guild_id.to_string()
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// Remembers the rate limit a channel had before its lock raised it
+    pub async fn set_channel_lock_slowmode(
+        &self,
+        id: i32,
+        slowmode: i32,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "UPDATE channel_locks SET slowmode = $1 WHERE id = $2",
+            slowmode,
+            id
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    pub async fn add_lockdown(
+        &self,
+        guild_id: GuildId,
+        moderator_id: UserId,
+        reason: &str,
+    ) -> Result<LockdownModel, Error> {
+        sqlx::query_as!(
+            LockdownModel,
+            r#"INSERT INTO lockdowns (guild_id, moderator_id, reason) VALUES ($1, $2, $3) RETURNING *"#,
+            guild_id.to_string(),
+            moderator_id.to_string(),
+            reason
+        )
+            .fetch_one(&self.pool)
+            .await
+    }
+
+    pub async fn get_lockdown(&self, guild_id: GuildId) -> Result<Option<LockdownModel>, Error> {
+        sqlx::query_as!(
+            LockdownModel,
+            r#"SELECT * FROM lockdowns WHERE guild_id = $1"#,
+            guild_id.to_string()
+        )
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn set_lockdown_invites_paused(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "UPDATE lockdowns SET invites_paused = true WHERE guild_id = $1",
+            guild_id.to_string()
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    pub async fn remove_lockdown(&self, guild_id: GuildId) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "DELETE FROM lockdowns WHERE guild_id = $1",
+            guild_id.to_string()
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    /// Archives messages right before a purge deletes them
+    pub async fn add_purge_archive(
+        &self,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+        moderator_id: UserId,
+        messages: &[Message],
+    ) -> Result<PurgeArchiveModel, Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        let archive = sqlx::query_as!(
+            PurgeArchiveModel,
+            r#"INSERT INTO purge_archives (guild_id, channel_id, moderator_id) VALUES ($1, $2, $3) RETURNING *"#,
+            guild_id.to_string(),
+            channel_id.to_string(),
+            moderator_id.to_string()
+        )
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        for message in messages.iter() {
+            let attachments = message
+                .attachments
+                .iter()
+                .map(|attachment| attachment.url.clone())
+                .collect::<Vec<_>>();
+
+            sqlx::query!(
+                r#"INSERT INTO purged_messages (archive_id, message_id, author_id, author_name, content, attachments, sent_at) VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+                archive.id,
+                message.id.to_string(),
+                message.author.id.to_string(),
+                message.author.name,
+                message.content,
+                &attachments,
+                DateTime::from_timestamp(message.timestamp.unix_timestamp(), 0)
+            )
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+        Ok(archive)
+    }
+
+    pub async fn add_automod_filter(
+        &self,
+        guild_id: GuildId,
+        kind: FilterKind,
+        pattern: &str,
+        created_by: UserId,
+    ) -> Result<AutomodFilterModel, Error> {
+        sqlx::query_as!(
+            AutomodFilterModel,
+            r#"INSERT INTO automod_filters (guild_id, kind, pattern, created_by) VALUES ($1, $2, $3, $4) RETURNING id, guild_id, kind AS "kind!: FilterKind", pattern, created_by, created_at"#,
+            guild_id.to_string(),
+            kind as FilterKind,
+            pattern,
+            created_by.to_string()
+        )
+            .fetch_one(&self.pool)
+            .await
+    }
+
+    pub async fn get_automod_filters(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<Vec<AutomodFilterModel>, Error> {
+        sqlx::query_as!(
+            AutomodFilterModel,
+            r#"SELECT id, guild_id, kind AS "kind!: FilterKind", pattern, created_by, created_at FROM automod_filters WHERE guild_id = $1 ORDER BY id"#,
+            guild_id.to_string()
+        )
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn remove_automod_filter(
+        &self,
+        guild_id: GuildId,
+        id: i32,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "DELETE FROM automod_filters WHERE guild_id = $1 AND id = $2",
+            guild_id.to_string(),
+            id
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    pub async fn add_allowlist_entry(
+        &self,
+        guild_id: GuildId,
+        kind: AllowlistKind,
+        value: &str,
+        created_by: UserId,
+    ) -> Result<AllowlistEntryModel, Error> {
+        sqlx::query_as!(
+            AllowlistEntryModel,
+            r#"INSERT INTO link_allowlist (guild_id, kind, value, created_by) VALUES ($1, $2, $3, $4) RETURNING id, guild_id, kind AS "kind!: AllowlistKind", value, created_by, created_at"#,
+            guild_id.to_string(),
+            kind as AllowlistKind,
+            value,
+            created_by.to_string()
+        )
+            .fetch_one(&self.pool)
+            .await
+    }
+
+    pub async fn get_allowlist(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<Vec<AllowlistEntryModel>, Error> {
+        sqlx::query_as!(
+            AllowlistEntryModel,
+            r#"SELECT id, guild_id, kind AS "kind!: AllowlistKind", value, created_by, created_at FROM link_allowlist WHERE guild_id = $1 ORDER BY kind, value"#,
+            guild_id.to_string()
+        )
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn remove_allowlist_entry(
+        &self,
+        guild_id: GuildId,
+        id: i32,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "DELETE FROM link_allowlist WHERE guild_id = $1 AND id = $2",
+            guild_id.to_string(),
+            id
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    /// Stores a mute, replacing the member's previous one
+    pub async fn add_mute(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        role_id: RoleId,
+        moderator_id: UserId,
+        expires_at: DateTime<Utc>,
+    ) -> Result<MuteModel, Error> {
+        sqlx::query_as!(
+            MuteModel,
+            r#"INSERT INTO mutes (guild_id, user_id, role_id, moderator_id, expires_at) VALUES ($1, $2, $3, $4, $5)
+               ON CONFLICT (guild_id, user_id) DO UPDATE SET role_id = $3, moderator_id = $4, expires_at = $5, created_at = Now()
+               RETURNING *"#,
+            guild_id.to_string(),
+            user_id.to_string(),
+            role_id.to_string(),
+            moderator_id.to_string(),
+            expires_at
+        )
+            .fetch_one(&self.pool)
+            .await
+    }
+
+    pub async fn get_mute(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<Option<MuteModel>, Error> {
+        sqlx::query_as!(
+            MuteModel,
+            r#"SELECT * FROM mutes WHERE guild_id = $1 AND user_id = $2"#,
+            guild_id.to_string(),
+            user_id.to_string()
+        )
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn remove_mute(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "DELETE FROM mutes WHERE guild_id = $1 AND user_id = $2",
+            guild_id.to_string(),
+            user_id.to_string()
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    pub async fn get_user_locales(&self) -> Result<Vec<UserLocaleModel>, Error> {
+        sqlx::query_as!(UserLocaleModel, "SELECT * FROM user_locales")
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn set_user_locale(
+        &self,
+        user_id: UserId,
+        locale: &str,
+    ) -> Result<UserLocaleModel, Error> {
+        sqlx::query_as!(
+            UserLocaleModel,
+            r#"INSERT INTO user_locales (user_id, locale) VALUES ($1, $2)
+               ON CONFLICT (user_id) DO UPDATE SET locale = $2, updated_at = now()
+               RETURNING *"#,
+            user_id.to_string(),
+            locale
+        )
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn remove_user_locale(&self, user_id: UserId) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "DELETE FROM user_locales WHERE user_id = $1",
+            user_id.to_string()
+        )
+        .execute(&self.pool)
+        .await
+    }
+
+    /// Stores an appeal of the case, or returns `None` if the case was already appealed
+    pub async fn add_appeal(
+        &self,
+        guild_id: GuildId,
+        case_id: i32,
+        user_id: UserId,
+        content: &str,
+    ) -> Result<Option<AppealModel>, Error> {
+        sqlx::query_as!(
+            AppealModel,
+            r#"INSERT INTO appeals (guild_id, case_id, user_id, content) VALUES ($1, $2, $3, $4)
+               ON CONFLICT (case_id) DO NOTHING
+               RETURNING id, guild_id, case_id, user_id, content, status AS "status!: AppealStatus", reviewer_id, created_at, reviewed_at"#,
+            guild_id.to_string(),
+            case_id,
+            user_id.to_string(),
+            content
+        )
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    /// Accepts or denies a pending appeal, returning `None` if it was already reviewed
+    pub async fn review_appeal(
+        &self,
+        guild_id: GuildId,
+        id: i32,
+        status: AppealStatus,
+        reviewer_id: UserId,
+    ) -> Result<Option<AppealModel>, Error> {
+        sqlx::query_as!(
+            AppealModel,
+            r#"UPDATE appeals SET status = $1, reviewer_id = $2, reviewed_at = Now()
+               WHERE guild_id = $3 AND id = $4 AND status = 'pending'
+               RETURNING id, guild_id, case_id, user_id, content, status AS "status!: AppealStatus", reviewer_id, created_at, reviewed_at"#,
+            status as AppealStatus,
+            reviewer_id.to_string(),
+            guild_id.to_string(),
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn remove_appeal(&self, id: i32) -> Result<PgQueryResult, Error> {
+        sqlx::query!("DELETE FROM appeals WHERE id = $1", id)
+            .execute(&self.pool)
+            .await
+    }
+
+    /// Puts a reviewed appeal back up for review, like after failing to lift the punishment
+    pub async fn reopen_appeal(&self, id: i32) -> Result<PgQueryResult, Error> {
+        sqlx::query!(
+            "UPDATE appeals SET status = 'pending', reviewer_id = NULL, reviewed_at = NULL WHERE id = $1",
+            id
+        )
+        .execute(&self.pool)
+        .await
+    }
 }
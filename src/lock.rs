@@ -0,0 +1,348 @@
+//! Locks channels by snapshotting their permission overwrites before touching them, so that
+//! unlocking restores exactly what the staff had configured
+
+use serenity::all::{
+    ChannelId, ChannelType, EditChannel, EditGuild, GuildChannel, GuildId, Http,
+    PermissionOverwrite, PermissionOverwriteType, Permissions, RoleId, UserId,
+};
+
+use crate::database::Database;
+use crate::models::{ChannelLockModel, ChannelLockOverwriteModel, OverwriteKind};
+use crate::settings::{Setting, Settings};
+use crate::translation::{tr_in, Translations};
+use crate::Error;
+
+/// Permissions denied to @everyone and removed from the other overwrites while locked
+pub const LOCKED_PERMISSIONS: Permissions = Permissions::SEND_MESSAGES
+    .union(Permissions::SEND_TTS_MESSAGES)
+    .union(Permissions::ADD_REACTIONS)
+    .union(Permissions::SEND_MESSAGES_IN_THREADS)
+    .union(Permissions::CREATE_PUBLIC_THREADS)
+    .union(Permissions::CREATE_PRIVATE_THREADS);
+
+/// Locks the channel, leaving the overwrites of `exempt_roles` untouched. Returns `false`
+/// without changing anything if the channel is already locked.
+pub async fn lock_channel(
+    http: &Http,
+    database: &Database,
+    channel: &GuildChannel,
+    moderator_id: UserId,
+    exempt_roles: &[RoleId],
+) -> Result<bool, Error> {
+    let lock =
+        snapshot_and_lock(http, database, channel, moderator_id, exempt_roles, false).await?;
+    Ok(lock.is_some())
+}
+
+async fn snapshot_and_lock(
+    http: &Http,
+    database: &Database,
+    channel: &GuildChannel,
+    moderator_id: UserId,
+    exempt_roles: &[RoleId],
+    lockdown: bool,
+) -> Result<Option<ChannelLockModel>, Error> {
+    if database
+        .get_channel_lock(channel.guild_id, channel.id)
+        .await?
+        .is_some()
+    {
+        return Ok(None);
+    }
+
+    let lock = database
+        .add_channel_lock(
+            channel.guild_id,
+            channel.id,
+            moderator_id,
+            &channel.permission_overwrites,
+            lockdown,
+        )
+        .await?;
+
+    let everyone = PermissionOverwriteType::Role(RoleId::new(channel.guild_id.get()));
+
+    let mut overwrites = channel.permission_overwrites.clone();
+
+    if !overwrites.iter().any(|ov| ov.kind == everyone) {
+        overwrites.push(PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::empty(),
+            kind: everyone,
+        });
+    }
+
+    // Overwrites as they were before being edited, to put back if locking fails halfway
+    let mut edited = vec![];
+
+    for overwrite in overwrites.into_iter() {
+        if let PermissionOverwriteType::Role(role_id) = overwrite.kind {
+            if exempt_roles.contains(&role_id) {
+                continue;
+            }
+        }
+
+        let mut locked = overwrite.clone();
+        locked.allow.remove(LOCKED_PERMISSIONS);
+
+        if locked.kind == everyone {
+            locked.deny.insert(LOCKED_PERMISSIONS);
+        }
+
+        if locked == overwrite {
+            continue;
+        }
+
+        if let Err(e) = channel.create_permission(http, locked).await {
+            for overwrite in edited.into_iter() {
+                // Only the @everyone overwrite can be missing from the original ones
+                let _ = match channel.permission_overwrites.contains(&overwrite) {
+                    true => channel.create_permission(http, overwrite).await,
+                    false => channel.delete_permission(http, everyone).await,
+                };
+            }
+
+            database.remove_channel_lock(lock.id).await?;
+            return Err(e.into());
+        }
+
+        edited.push(overwrite);
+    }
+
+    Ok(Some(lock))
+}
+
+/// Restores the overwrites the channel had before it was locked and forgets the snapshot.
+/// Returns `false` if the channel isn't locked.
+pub async fn unlock_channel(
+    http: &Http,
+    database: &Database,
+    channel: &GuildChannel,
+) -> Result<bool, Error> {
+    let lock = match database
+        .get_channel_lock(channel.guild_id, channel.id)
+        .await?
+    {
+        Some(lock) => lock,
+        None => return Ok(false),
+    };
+
+    restore_channel(http, database, channel, &lock).await?;
+    Ok(true)
+}
+
+async fn restore_channel(
+    http: &Http,
+    database: &Database,
+    channel: &GuildChannel,
+    lock: &ChannelLockModel,
+) -> Result<(), Error> {
+    let snapshot = database
+        .get_channel_lock_overwrites(lock.id)
+        .await?
+        .iter()
+        .map(to_overwrite)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for overwrite in channel.permission_overwrites.iter() {
+        if !snapshot.iter().any(|ov| ov.kind == overwrite.kind) {
+            channel.delete_permission(http, overwrite.kind).await?;
+        }
+    }
+
+    for overwrite in snapshot.into_iter() {
+        if !channel.permission_overwrites.contains(&overwrite) {
+            channel.create_permission(http, overwrite).await?;
+        }
+    }
+
+    if let Some(slowmode) = lock.slowmode {
+        let builder = EditChannel::new().rate_limit_per_user(slowmode as u16);
+        channel.id.edit(http, builder).await?;
+    }
+
+    database.remove_channel_lock(lock.id).await?;
+    Ok(())
+}
+
+/// Locks the configured lockdown channels, or every text channel if there are none, and
+/// pauses invites. Returns the channels that were locked and the ones that failed.
+#[allow(clippy::too_many_arguments)]
+pub async fn start_lockdown(
+    http: &Http,
+    database: &Database,
+    settings: &Settings,
+    translations: &Translations,
+    guild_id: GuildId,
+    moderator_id: UserId,
+    reason: &str,
+    slowmode: Option<u16>,
+) -> Result<(Vec<ChannelId>, Vec<ChannelId>), Error> {
+    database
+        .add_lockdown(guild_id, moderator_id, reason)
+        .await?;
+
+    let exempt_roles = settings.roles(guild_id, Setting::ModRoles).await;
+    let configured = settings.channels(guild_id, Setting::LockdownChannels).await;
+
+    let mut channels = guild_id
+        .channels(http)
+        .await?
+        .into_values()
+        .filter(|channel| match configured.is_empty() {
+            true => channel.kind == ChannelType::Text,
+            false => configured.contains(&channel.id),
+        })
+        .collect::<Vec<_>>();
+
+    channels.sort_by_key(|channel| channel.position);
+
+    // Announced in every locked channel, so it follows the server's language
+    let locale = translations.resolve_locale(None, Some(guild_id), None);
+
+    let mut locked = vec![];
+    let mut not_locked = vec![];
+
+    for channel in channels.iter() {
+        let lock =
+            match snapshot_and_lock(http, database, channel, moderator_id, &exempt_roles, true)
+                .await
+            {
+                Ok(Some(lock)) => lock,
+                // Channels locked on their own before the lockdown stay out of it
+                Ok(None) => continue,
+                Err(_) => {
+                    not_locked.push(channel.id);
+                    continue;
+                }
+            };
+
+        if let Some(slowmode) = slowmode {
+            let previous = channel.rate_limit_per_user.unwrap_or_default();
+
+            if previous < slowmode
+                && database
+                    .set_channel_lock_slowmode(lock.id, previous as i32)
+                    .await
+                    .is_ok()
+            {
+                let builder = EditChannel::new().rate_limit_per_user(slowmode);
+                let _ = channel.id.edit(http, builder).await;
+            }
+        }
+
+        let notice = match reason.is_empty() {
+            true => tr_in!(translations, locale.as_deref(), "lockdown-notice-start"),
+            false => tr_in!(
+                translations,
+                locale.as_deref(),
+                "lockdown-notice-start-reason",
+                reason: reason
+            ),
+        };
+        let _ = channel.say(http, notice).await;
+
+        locked.push(channel.id);
+    }
+
+    if set_invites_disabled(http, guild_id, true)
+        .await
+        .unwrap_or(false)
+    {
+        database.set_lockdown_invites_paused(guild_id).await?;
+    }
+
+    Ok((locked, not_locked))
+}
+
+/// Restores every channel locked by the lockdown and resumes invites if the lockdown paused
+/// them. Returns the channels that were restored and the ones that failed.
+pub async fn end_lockdown(
+    http: &Http,
+    database: &Database,
+    translations: &Translations,
+    guild_id: GuildId,
+) -> Result<(Vec<ChannelId>, Vec<ChannelId>), Error> {
+    let lockdown = database
+        .get_lockdown(guild_id)
+        .await?
+        .ok_or("guild is not in lockdown")?;
+
+    let mut channels = guild_id.channels(http).await?;
+
+    let locale = translations.resolve_locale(None, Some(guild_id), None);
+    let notice = tr_in!(translations, locale.as_deref(), "lockdown-notice-end");
+
+    let mut unlocked = vec![];
+    let mut not_unlocked = vec![];
+
+    for lock in database.get_lockdown_channel_locks(guild_id).await? {
+        let channel_id = ChannelId::new(lock.channel_id.parse()?);
+
+        let channel = match channels.remove(&channel_id) {
+            Some(channel) => channel,
+            None => {
+                // The channel was deleted during the lockdown, there is nothing to restore
+                database.remove_channel_lock(lock.id).await?;
+                not_unlocked.push(channel_id);
+                continue;
+            }
+        };
+
+        match restore_channel(http, database, &channel, &lock).await {
+            Ok(_) => {
+                let _ = channel.say(http, notice.as_str()).await;
+                unlocked.push(channel_id);
+            }
+            Err(_) => not_unlocked.push(channel_id),
+        }
+    }
+
+    if lockdown.invites_paused {
+        set_invites_disabled(http, guild_id, false).await?;
+    }
+
+    database.remove_lockdown(guild_id).await?;
+
+    Ok((unlocked, not_unlocked))
+}
+
+/// Toggles the guild's `INVITES_DISABLED` feature, returning whether it changed
+async fn set_invites_disabled(
+    http: &Http,
+    guild_id: GuildId,
+    disabled: bool,
+) -> Result<bool, Error> {
+    const FEATURE: &str = "INVITES_DISABLED";
+
+    let mut features = guild_id.to_partial_guild(http).await?.features;
+
+    if features.iter().any(|f| f == FEATURE) == disabled {
+        return Ok(false);
+    }
+
+    match disabled {
+        true => features.push(FEATURE.to_owned()),
+        false => features.retain(|f| f != FEATURE),
+    }
+
+    guild_id
+        .edit(http, EditGuild::new().features(features))
+        .await?;
+    Ok(true)
+}
+
+fn to_overwrite(overwrite: &ChannelLockOverwriteModel) -> Result<PermissionOverwrite, Error> {
+    let target_id = overwrite.target_id.parse()?;
+
+    let kind = match overwrite.kind {
+        OverwriteKind::Role => PermissionOverwriteType::Role(RoleId::new(target_id)),
+        OverwriteKind::Member => PermissionOverwriteType::Member(UserId::new(target_id)),
+    };
+
+    Ok(PermissionOverwrite {
+        allow: Permissions::from_bits_retain(overwrite.allow as u64),
+        deny: Permissions::from_bits_retain(overwrite.deny as u64),
+        kind,
+    })
+}
@@ -1,30 +1,47 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use dotenv::dotenv;
-use models::AnimalModel;
 use poise::serenity_prelude as serenity;
 
+pub mod appeals;
+pub mod automod;
 pub mod commands;
 pub mod database;
+pub mod lock;
 pub mod models;
+pub mod modlog;
+pub mod mute;
+pub mod notify;
+pub mod purge;
+pub mod scheduler;
+pub mod settings;
 pub mod translation;
 pub mod utils;
 
+use automod::antiraid::RaidMonitor;
+use automod::filter::FilterCache;
+use automod::links::AllowlistCache;
+use automod::spam::SpamTracker;
 use database::Database;
+use scheduler::Scheduler;
+use settings::Settings;
 use tokio::sync::Mutex;
-use tokio::task::JoinHandle;
 
 type Error = Box<dyn std::error::Error + Send + Sync>;
 type Context<'a> = poise::Context<'a, Data, Error>;
 
 pub struct Data {
-    translations: translation::Translations,
+    translations: Arc<translation::Translations>,
     database: Arc<Database>,
-    bang_channel: Arc<Mutex<u64>>,
-    bang_available: Arc<Mutex<bool>>,
-    bang_handles: Arc<Mutex<Vec<JoinHandle<Result<(), Error>>>>>,
-    last_animal: Arc<Mutex<AnimalModel>>,
+    scheduler: Arc<Scheduler>,
+    settings: Arc<Settings>,
+    raid_monitor: RaidMonitor,
+    filters: FilterCache,
+    allowlist: AllowlistCache,
+    spam: SpamTracker,
+    bang_games: Arc<Mutex<HashMap<serenity::GuildId, commands::bang::BangGame>>>,
 }
 
 async fn on_error(error: poise::FrameworkError<'_, Data, Error>) {
@@ -41,23 +58,58 @@ async fn on_error(error: poise::FrameworkError<'_, Data, Error>) {
     }
 }
 
-#[tokio::main]
-async fn main() {
-    dotenv().ok();
-
-    let token = std::env::var("DISCORD_TOKEN").expect("missing DISCORD_TOKEN");
-    let db_url = std::env::var("DATABASE_URL").expect("missing DATABASE_URL");
-
-    let database = Database::new(db_url).await.unwrap();
+async fn event_handler(
+    ctx: &serenity::Context,
+    event: &serenity::FullEvent,
+    data: &Data,
+) -> Result<(), Error> {
+    println!(
+        "Got an event in event handler: {:?}",
+        event.snake_case_name()
+    );
+
+    match event {
+        serenity::FullEvent::GuildMemberAddition { new_member } => {
+            // All of them run even if one fails, a broken raid check shouldn't open the gate
+            let mute = mute::on_member_join(ctx, data, new_member).await;
+            let raid = automod::antiraid::on_member_join(ctx, data, new_member).await;
+            let gate = automod::gate::on_member_join(ctx, data, new_member).await;
+            mute.and(raid).and(gate)?;
+        }
+        serenity::FullEvent::Message { new_message } => {
+            automod::on_message(ctx, data, new_message).await?;
+        }
+        // Only edits that changed the content need to be checked again
+        serenity::FullEvent::MessageUpdate { new, event, .. } if event.content.is_some() => {
+            let mut message = match new {
+                Some(message) => message.clone(),
+                None => event.channel_id.message(ctx, event.id).await?,
+            };
+            // Messages fetched through HTTP don't carry their guild
+            message.guild_id = message.guild_id.or(event.guild_id);
+
+            automod::on_message_edit(ctx, data, &message).await?;
+        }
+        serenity::FullEvent::InteractionCreate { interaction } => {
+            appeals::on_interaction(ctx, data, interaction).await?;
+        }
+        _ => (),
+    }
 
-    let intents =
-        serenity::GatewayIntents::non_privileged() | serenity::GatewayIntents::MESSAGE_CONTENT;
+    Ok(())
+}
 
-    let mut commands = vec![
+/// Every command the bot registers, before translations are applied
+fn commands() -> Vec<poise::Command<Data, Error>> {
+    vec![
         commands::misc::ping(),
         commands::utility::help(),
         commands::misc::database(),
+        commands::misc::reloadtranslations(),
         commands::infractions::infractions(),
+        commands::escalation::escalation(),
+        commands::config::config(),
+        commands::language::language(),
         commands::moderation::punish(),
         commands::moderation::kick(),
         commands::moderation::timeout(),
@@ -65,6 +117,8 @@ async fn main() {
         commands::moderation::unban(),
         commands::moderation::untimeout(),
         commands::moderation::strike(),
+        commands::case::case(),
+        commands::history::history(),
         commands::tags::tag(),
         commands::emoji::emoji(),
         commands::bang::startbang(),
@@ -74,27 +128,70 @@ async fn main() {
         commands::animal::animal(),
         commands::moderation::slowmode(),
         commands::moderation::lock(),
+        commands::moderation::unlock(),
+        commands::lockdown::lockdown(),
+        commands::filter::filter(),
+        commands::allowlist::allowlist(),
         commands::moderation::clear(),
+        commands::schedule::schedule(),
         commands::sticker::sticker(),
         commands::sticker::context_get_sticker(),
         commands::sticker::context_clone_sticker(),
-    ];
+    ]
+}
+
+#[tokio::main]
+async fn main() {
+    dotenv().ok();
+
+    // Validate the translation files against the command tree without connecting to anything
+    if std::env::args().any(|arg| arg == "--check-translations") {
+        let translations = translation::read_ftl().expect("failed to read translation files");
+        let problems = translation::check_translations(&translations, &commands());
+
+        for problem in problems.iter() {
+            println!("{}", problem);
+        }
+
+        std::process::exit(if problems.is_empty() { 0 } else { 1 });
+    }
+
+    let token = std::env::var("DISCORD_TOKEN").expect("missing DISCORD_TOKEN");
+    let db_url = std::env::var("DATABASE_URL").expect("missing DATABASE_URL");
+
+    let database = Arc::new(Database::new(db_url).await.unwrap());
+    let settings = Arc::new(Settings::new(Arc::clone(&database)));
 
     let translations = translation::read_ftl().expect("failed to read translation files");
-    translation::apply_translations(&translations, &mut commands);
+    translations
+        .load_locales(&database)
+        .await
+        .expect("failed to load locale preferences");
+    let translations = Arc::new(translations);
+
+    let scheduler = Arc::new(Scheduler::new(
+        Arc::clone(&database),
+        Arc::clone(&settings),
+        Arc::clone(&translations),
+    ));
+    let filters = FilterCache::new(Arc::clone(&database));
+    let allowlist = AllowlistCache::new(Arc::clone(&database));
+
+    let intents = serenity::GatewayIntents::non_privileged()
+        | serenity::GatewayIntents::MESSAGE_CONTENT
+        | serenity::GatewayIntents::GUILD_MEMBERS;
+
+    let mut commands = commands();
 
-    let last_animal = AnimalModel {
-        id: 0,
-        emoji: "".to_owned(),
-        animal: "".to_owned(),
-        points: 0,
-    };
+    translation::apply_translations(&translations, &mut commands);
 
     let framework = poise::Framework::builder()
         .options(poise::FrameworkOptions {
             commands,
             prefix_options: poise::PrefixFrameworkOptions {
-                prefix: Some("k!".into()),
+                dynamic_prefix: Some(|ctx| {
+                    Box::pin(async move { Ok(Some(ctx.data.settings.prefix(ctx.guild_id).await)) })
+                }),
                 edit_tracker: Some(Arc::new(poise::EditTracker::for_timespan(
                     Duration::from_secs(3600),
                 ))),
@@ -113,28 +210,24 @@ async fn main() {
             },
             command_check: Some(|ctx| Box::pin(async move { Ok(!ctx.author().bot) })),
             skip_checks_for_owners: false,
-            event_handler: |_ctx, event, _framework, _data| {
-                Box::pin(async move {
-                    println!(
-                        "Got an event in event handler: {:?}",
-                        event.snake_case_name()
-                    );
-                    Ok(())
-                })
-            },
+            event_handler: |ctx, event, _framework, data| Box::pin(event_handler(ctx, event, data)),
             ..Default::default()
         })
         .setup(|ctx, ready, framework| {
             Box::pin(async move {
                 println!("Logged in as {}", ready.user.name);
                 poise::builtins::register_globally(ctx, &framework.options().commands).await?;
+                tokio::spawn(Arc::clone(&scheduler).run(Arc::clone(&ctx.http)));
                 Ok(Data {
                     translations,
-                    database: Arc::new(database),
-                    bang_channel: Arc::new(Mutex::new(0)),
-                    bang_available: Arc::new(Mutex::new(false)),
-                    bang_handles: Arc::new(Mutex::new(Vec::new())),
-                    last_animal: Arc::new(Mutex::new(last_animal)),
+                    database,
+                    scheduler,
+                    settings,
+                    raid_monitor: RaidMonitor::default(),
+                    filters,
+                    allowlist,
+                    spam: SpamTracker::default(),
+                    bang_games: Arc::new(Mutex::new(HashMap::new())),
                 })
             })
         })
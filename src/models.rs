@@ -15,12 +15,88 @@ pub enum Punishment {
     Timeout,
     Ban,
     Kick,
+    Quarantine,
+}
+
+#[derive(Clone, Debug, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "case_action", rename_all = "lowercase")]
+pub enum CaseAction {
+    Kick,
+    Ban,
+    Unban,
+    Timeout,
+    Untimeout,
+    Strike,
+    Quarantine,
+}
+
+impl From<Punishment> for CaseAction {
+    fn from(punishment: Punishment) -> Self {
+        match punishment {
+            Punishment::Strike => CaseAction::Strike,
+            Punishment::Timeout => CaseAction::Timeout,
+            Punishment::Ban => CaseAction::Ban,
+            Punishment::Kick => CaseAction::Kick,
+            Punishment::Quarantine => CaseAction::Quarantine,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "scheduled_action", rename_all = "lowercase")]
+pub enum ScheduledAction {
+    Unban,
+    Slowmode,
+    Unlock,
+    Unmute,
+}
+
+#[derive(Clone, Debug, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "overwrite_kind", rename_all = "lowercase")]
+pub enum OverwriteKind {
+    Role,
+    Member,
+}
+
+#[derive(Clone, Debug, PartialEq, sqlx::Type, poise::ChoiceParameter)]
+#[sqlx(type_name = "filter_kind", rename_all = "lowercase")]
+pub enum FilterKind {
+    Word,
+    Regex,
+}
+
+#[derive(Clone, Debug, PartialEq, sqlx::Type, poise::ChoiceParameter)]
+#[sqlx(type_name = "allowlist_kind", rename_all = "lowercase")]
+pub enum AllowlistKind {
+    Domain,
+    Invite,
+}
+
+#[derive(Clone, Debug, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "appeal_status", rename_all = "lowercase")]
+pub enum AppealStatus {
+    Pending,
+    Accepted,
+    Denied,
 }
 
 #[derive(Debug, sqlx::FromRow)]
 pub struct InfractionModel {
     pub id: i32,
+    pub guild_id: String,
+    pub severity: Severity,
+    pub punishment: Punishment,
+    pub duration: i64,
+    pub expires_after: Option<i32>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct EscalationModel {
+    pub id: i32,
+    pub guild_id: String,
     pub severity: Severity,
+    pub threshold: i32,
+    pub days: i32,
     pub punishment: Punishment,
     pub duration: i64,
 }
@@ -31,6 +107,12 @@ pub struct PunishmentModel {
     pub user_id: String,
     pub punishment: Punishment,
     pub duration: i64,
+    pub expires_at: Option<chrono::DateTime<Utc>>,
+    pub guild_id: String,
+    pub moderator_id: Option<String>,
+    pub reason: String,
+    pub created_at: Option<chrono::DateTime<Utc>>,
+    pub case_id: Option<i32>,
 }
 
 #[derive(Debug, sqlx::FromRow)]
@@ -39,19 +121,57 @@ pub struct UserInfractionModel {
     pub user_id: String,
     pub infraction_id: i32,
     pub created_at: Option<chrono::DateTime<Utc>>,
+    pub expires_at: Option<chrono::DateTime<Utc>>,
+    pub guild_id: String,
+    pub moderator_id: Option<String>,
+    pub reason: String,
+    pub case_id: Option<i32>,
+    pub pardoned_at: Option<chrono::DateTime<Utc>>,
+    pub pardoned_by: Option<String>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct CaseModel {
+    pub id: i32,
+    pub guild_id: String,
+    pub case_number: i32,
+    pub action: CaseAction,
+    pub user_id: String,
+    pub moderator_id: String,
+    pub reason: String,
+    pub created_at: Option<chrono::DateTime<Utc>>,
+}
+
+/// A punishment or rule infraction from a user's moderation history
+#[derive(Debug, sqlx::FromRow)]
+pub struct HistoryEntryModel {
+    pub case_number: Option<i32>,
+    pub punishment: Option<Punishment>,
+    pub severity: Option<Severity>,
+    pub user_infraction_id: Option<i32>,
+    pub infraction_id: Option<i32>,
+    pub reason: String,
+    pub moderator_id: Option<String>,
+    pub duration: i64,
+    pub created_at: Option<chrono::DateTime<Utc>>,
+    pub expires_at: Option<chrono::DateTime<Utc>>,
+    pub expired: bool,
+    pub pardoned: bool,
 }
 
 #[derive(Debug, sqlx::FromRow)]
 pub struct TagModel {
     pub id: i32,
+    pub guild_id: String,
     pub user_id: String,
     pub name: String,
     pub content: String,
 }
 
-#[derive(Debug, sqlx::FromRow)]
+#[derive(Clone, Debug, sqlx::FromRow)]
 pub struct AnimalModel {
     pub id: i32,
+    pub guild_id: String,
     pub animal: String,
     pub emoji: String,
     pub points: i32,
@@ -60,6 +180,118 @@ pub struct AnimalModel {
 #[derive(Debug, sqlx::FromRow)]
 pub struct BangPointModel {
     pub id: i32,
+    pub guild_id: String,
     pub user_id: String,
     pub points: i32,
 }
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ScheduledActionModel {
+    pub id: i32,
+    pub guild_id: String,
+    pub action: ScheduledAction,
+    pub target_id: String,
+    pub payload: Option<String>,
+    pub moderator_id: String,
+    pub run_at: chrono::DateTime<Utc>,
+    /// Failed runs so far, each one delays the next retry further
+    pub attempts: i32,
+    pub created_at: Option<chrono::DateTime<Utc>>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct GuildSettingModel {
+    pub guild_id: String,
+    pub key: String,
+    pub value: String,
+    pub updated_at: chrono::DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ChannelLockModel {
+    pub id: i32,
+    pub guild_id: String,
+    pub channel_id: String,
+    pub moderator_id: String,
+    pub created_at: chrono::DateTime<Utc>,
+    pub lockdown: bool,
+    pub slowmode: Option<i32>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ChannelLockOverwriteModel {
+    pub id: i32,
+    pub lock_id: i32,
+    pub kind: OverwriteKind,
+    pub target_id: String,
+    pub allow: i64,
+    pub deny: i64,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct LockdownModel {
+    pub guild_id: String,
+    pub moderator_id: String,
+    pub reason: String,
+    pub invites_paused: bool,
+    pub created_at: chrono::DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct PurgeArchiveModel {
+    pub id: i32,
+    pub guild_id: String,
+    pub channel_id: String,
+    pub moderator_id: String,
+    pub created_at: chrono::DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct AutomodFilterModel {
+    pub id: i32,
+    pub guild_id: String,
+    pub kind: FilterKind,
+    pub pattern: String,
+    pub created_by: String,
+    pub created_at: chrono::DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct AllowlistEntryModel {
+    pub id: i32,
+    pub guild_id: String,
+    pub kind: AllowlistKind,
+    pub value: String,
+    pub created_by: String,
+    pub created_at: chrono::DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct MuteModel {
+    pub guild_id: String,
+    pub user_id: String,
+    pub role_id: String,
+    pub moderator_id: String,
+    pub expires_at: chrono::DateTime<Utc>,
+    pub created_at: chrono::DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct UserLocaleModel {
+    pub user_id: String,
+    pub locale: String,
+    pub updated_at: chrono::DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct AppealModel {
+    pub id: i32,
+    pub guild_id: String,
+    pub case_id: i32,
+    pub user_id: String,
+    pub content: String,
+    pub status: AppealStatus,
+    pub reviewer_id: Option<String>,
+    pub created_at: chrono::DateTime<Utc>,
+    pub reviewed_at: Option<chrono::DateTime<Utc>>,
+}
@@ -0,0 +1,263 @@
+//! Posts moderation events as embeds to the guild's mod-log channel, if one is configured
+
+use serenity::all::{
+    CacheHttp, ChannelId, Colour, CreateAllowedMentions, CreateAttachment, CreateEmbed,
+    CreateMessage, GuildId, RoleId, Timestamp, UserId,
+};
+
+use crate::models::{CaseAction, CaseModel};
+use crate::settings::{Setting, Settings};
+use crate::translation::{tr_in, Translations};
+use crate::utils::format_duration;
+
+#[derive(Debug)]
+pub enum ModLogAction {
+    Kick,
+    Ban,
+    Unban,
+    Timeout,
+    Untimeout,
+    Strike,
+    Quarantine,
+    Lock,
+    Unlock,
+    Slowmode,
+    Clear,
+    LockdownStart,
+    LockdownEnd,
+    Raid,
+    Automod,
+}
+
+impl From<CaseAction> for ModLogAction {
+    fn from(action: CaseAction) -> Self {
+        match action {
+            CaseAction::Kick => ModLogAction::Kick,
+            CaseAction::Ban => ModLogAction::Ban,
+            CaseAction::Unban => ModLogAction::Unban,
+            CaseAction::Timeout => ModLogAction::Timeout,
+            CaseAction::Untimeout => ModLogAction::Untimeout,
+            CaseAction::Strike => ModLogAction::Strike,
+            CaseAction::Quarantine => ModLogAction::Quarantine,
+        }
+    }
+}
+
+impl ModLogAction {
+    fn colour(&self) -> Colour {
+        match self {
+            ModLogAction::Ban => Colour::RED,
+            ModLogAction::Kick => Colour::ORANGE,
+            ModLogAction::Timeout | ModLogAction::Strike | ModLogAction::Quarantine => Colour::GOLD,
+            ModLogAction::Unban
+            | ModLogAction::Untimeout
+            | ModLogAction::Unlock
+            | ModLogAction::LockdownEnd => Colour::DARK_GREEN,
+            ModLogAction::LockdownStart | ModLogAction::Raid => Colour::DARK_RED,
+            ModLogAction::Automod => Colour::DARK_ORANGE,
+            ModLogAction::Lock | ModLogAction::Slowmode | ModLogAction::Clear => Colour::BLUE,
+        }
+    }
+}
+
+pub struct ModLogEntry {
+    pub action: ModLogAction,
+    /// `None` for actions against the entire server
+    pub target: Option<String>,
+    pub moderator: String,
+    pub reason: String,
+    pub duration: Option<i64>,
+    pub case_number: Option<i32>,
+    pub details: Option<String>,
+    /// File uploaded along with the embed, like a purge transcript
+    pub attachment: Option<CreateAttachment>,
+    /// Roles pinged along with the entry, for events that need the staff's attention
+    pub mentions: Vec<RoleId>,
+}
+
+impl ModLogEntry {
+    /// Entry for an action taken against a channel, like a lock or a purge
+    pub fn channel(action: ModLogAction, channel_id: ChannelId, moderator_id: UserId) -> Self {
+        Self {
+            action,
+            target: Some(format!("<#{channel_id}>")),
+            moderator: format!("<@{moderator_id}>"),
+            reason: String::new(),
+            duration: None,
+            case_number: None,
+            details: None,
+            attachment: None,
+            mentions: vec![],
+        }
+    }
+
+    /// Entry for an action taken against the whole server, like a lockdown
+    pub fn guild(action: ModLogAction, moderator_id: UserId) -> Self {
+        Self {
+            action,
+            target: None,
+            moderator: format!("<@{moderator_id}>"),
+            reason: String::new(),
+            duration: None,
+            case_number: None,
+            details: None,
+            attachment: None,
+            mentions: vec![],
+        }
+    }
+
+    /// Entry for an action against a member that doesn't open a case, like a deleted message
+    pub fn member(action: ModLogAction, user_id: UserId, moderator_id: UserId) -> Self {
+        Self {
+            action,
+            target: Some(format!("<@{user_id}> (`{user_id}`)")),
+            moderator: format!("<@{moderator_id}>"),
+            reason: String::new(),
+            duration: None,
+            case_number: None,
+            details: None,
+            attachment: None,
+            mentions: vec![],
+        }
+    }
+
+    /// Entry for a moderation case opened against a member
+    pub fn case(case: &CaseModel) -> Self {
+        Self {
+            action: case.action.clone().into(),
+            target: Some(format!("<@{}> (`{}`)", case.user_id, case.user_id)),
+            moderator: format!("<@{}>", case.moderator_id),
+            reason: case.reason.clone(),
+            duration: None,
+            case_number: Some(case.case_number),
+            details: None,
+            attachment: None,
+            mentions: vec![],
+        }
+    }
+
+    pub fn duration(mut self, duration: Option<i64>) -> Self {
+        self.duration = duration.filter(|d| *d > 0);
+        self
+    }
+
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = reason.into();
+        self
+    }
+
+    pub fn details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    pub fn attachment(mut self, attachment: CreateAttachment) -> Self {
+        self.attachment = Some(attachment);
+        self
+    }
+
+    pub fn mentions(mut self, roles: Vec<RoleId>) -> Self {
+        self.mentions = roles;
+        self
+    }
+
+    /// Builds the embed in the guild's language
+    fn to_embed(&self, translations: &Translations, locale: Option<&str>) -> CreateEmbed {
+        let action = format!("{:?}", self.action).to_lowercase();
+        let action = tr_in!(translations, locale, "modlog-action", action: action);
+
+        let title = match self.case_number {
+            Some(number) => tr_in!(
+                translations,
+                locale,
+                "modlog-case-title",
+                action: action,
+                case: number.to_string()
+            ),
+            None => action,
+        };
+
+        let target = match &self.target {
+            Some(target) => target.clone(),
+            None => tr_in!(translations, locale, "modlog-entire-server"),
+        };
+
+        let reason = if self.reason.is_empty() {
+            tr_in!(translations, locale, "modlog-no-reason")
+        } else {
+            self.reason.clone()
+        };
+
+        let mut embed = CreateEmbed::new()
+            .title(title)
+            .colour(self.action.colour())
+            .field(tr_in!(translations, locale, "modlog-target"), target, true)
+            .field(
+                tr_in!(translations, locale, "modlog-moderator"),
+                &self.moderator,
+                true,
+            )
+            .field(tr_in!(translations, locale, "modlog-reason"), reason, false)
+            .timestamp(Timestamp::now());
+
+        if let Some(duration) = self.duration {
+            embed = embed.field(
+                tr_in!(translations, locale, "modlog-duration"),
+                format_duration(duration),
+                true,
+            );
+        }
+
+        if let Some(details) = &self.details {
+            embed = embed.field(
+                tr_in!(translations, locale, "modlog-details"),
+                details,
+                false,
+            );
+        }
+
+        embed
+    }
+}
+
+/// Sends the entry to the mod-log channel. Failures are only printed, since a missing log
+/// message should never interrupt the moderation action itself.
+pub async fn send(
+    http: impl CacheHttp,
+    settings: &Settings,
+    translations: &Translations,
+    guild_id: GuildId,
+    entry: ModLogEntry,
+) {
+    let channel_id = match settings.channel(guild_id, Setting::ModLogChannel).await {
+        Some(channel_id) => channel_id,
+        None => return,
+    };
+
+    let locale = translations.resolve_locale(None, Some(guild_id), None);
+    let mut message = CreateMessage::new().embed(entry.to_embed(translations, locale.as_deref()));
+
+    if !entry.mentions.is_empty() {
+        let content = entry
+            .mentions
+            .iter()
+            .map(|role_id| format!("<@&{role_id}>"))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        message = message
+            .content(content)
+            .allowed_mentions(CreateAllowedMentions::new().roles(entry.mentions));
+    }
+
+    if let Some(attachment) = entry.attachment {
+        message = message.add_file(attachment);
+    }
+
+    if let Err(e) = channel_id.send_message(http, message).await {
+        println!(
+            "Failed to send mod-log message to channel {}: {}",
+            channel_id, e
+        );
+    }
+}
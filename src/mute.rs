@@ -0,0 +1,109 @@
+//! Mutes members through a role for durations beyond Discord's 28 day timeout limit. Active
+//! mutes are stored, so the role is given back if the member rejoins before the mute ends.
+
+use serenity::all::{Context as SerenityContext, GuildId, Http, Member, RoleId, UserId};
+use sqlx::types::chrono::{DateTime, Utc};
+
+use crate::database::Database;
+use crate::models::ScheduledAction;
+use crate::scheduler::Scheduler;
+use crate::utils::{is_discord_error, UNKNOWN_MEMBER};
+use crate::{Data, Error};
+
+/// Discord doesn't allow timeouts longer than 28 days
+pub const MAX_TIMEOUT: i64 = 28 * 60 * 60 * 24;
+
+/// Assigns the mute role and schedules its removal, replacing any previous mute
+#[allow(clippy::too_many_arguments)]
+pub async fn mute_member(
+    http: &Http,
+    database: &Database,
+    scheduler: &Scheduler,
+    guild_id: GuildId,
+    user_id: UserId,
+    role_id: RoleId,
+    moderator_id: UserId,
+    reason: &str,
+    expires_at: DateTime<Utc>,
+) -> Result<(), Error> {
+    http.add_member_role(guild_id, user_id, role_id, Some(reason))
+        .await?;
+
+    database
+        .add_mute(guild_id, user_id, role_id, moderator_id, expires_at)
+        .await?;
+    database
+        .remove_target_scheduled_actions(guild_id, ScheduledAction::Unmute, user_id.to_string())
+        .await?;
+
+    scheduler
+        .schedule(
+            guild_id,
+            ScheduledAction::Unmute,
+            user_id.get(),
+            None,
+            moderator_id,
+            expires_at,
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Removes the mute role and forgets the mute. Returns `false` if the member isn't muted.
+pub async fn unmute_member(
+    http: &Http,
+    database: &Database,
+    guild_id: GuildId,
+    user_id: UserId,
+) -> Result<bool, Error> {
+    let mute = match database.get_mute(guild_id, user_id).await? {
+        Some(mute) => mute,
+        None => return Ok(false),
+    };
+
+    let role_id = RoleId::new(mute.role_id.parse()?);
+
+    // The member may have left the server, the mute is forgotten either way. Any other failure
+    // keeps the mute, so it can be retried.
+    if let Err(e) = http
+        .remove_member_role(guild_id, user_id, role_id, Some("Mute ended"))
+        .await
+    {
+        if !is_discord_error(&e, &[UNKNOWN_MEMBER]) {
+            return Err(e.into());
+        }
+    }
+
+    database.remove_mute(guild_id, user_id).await?;
+    Ok(true)
+}
+
+/// Gives the mute role back to members who left and rejoined while muted
+pub async fn on_member_join(
+    ctx: &SerenityContext,
+    data: &Data,
+    member: &Member,
+) -> Result<(), Error> {
+    let mute = match data
+        .database
+        .get_mute(member.guild_id, member.user.id)
+        .await?
+    {
+        Some(mute) if mute.expires_at > Utc::now() => mute,
+        _ => return Ok(()),
+    };
+
+    let role_id = RoleId::new(mute.role_id.parse()?);
+
+    ctx.http
+        .add_member_role(
+            member.guild_id,
+            member.user.id,
+            role_id,
+            Some("Rejoined while muted"),
+        )
+        .await?;
+
+    Ok(())
+}
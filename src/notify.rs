@@ -0,0 +1,191 @@
+//! Tells members about their punishments by DM. Notices are sent before the punishment is
+//! applied, so kicked and banned members still receive them. Guilds can replace the translated
+//! messages with their own templates.
+
+use serenity::all::{
+    ButtonStyle, CacheHttp, CreateActionRow, CreateButton, CreateMessage, GuildId, Message, UserId,
+};
+
+use crate::appeals;
+use crate::models::{CaseAction, CaseModel, Punishment};
+use crate::settings::{Setting, Settings};
+use crate::translation::{tr_in, Translations};
+use crate::utils::format_duration;
+
+/// Placeholders available in DM templates, written like `{reason}`
+pub const PLACEHOLDERS: &[&str] = &["guild", "reason", "duration", "case", "appeal"];
+
+#[derive(Debug, Clone, Copy)]
+pub enum NoticeKind {
+    Kick,
+    Ban,
+    Timeout,
+    Strike,
+    Quarantine,
+    /// Any punishment given for one of the guild's infractions
+    Infraction,
+}
+
+impl NoticeKind {
+    pub fn new(punishment: &Punishment, infraction: Option<i32>) -> Self {
+        match (punishment, infraction) {
+            (_, Some(_)) => NoticeKind::Infraction,
+            (Punishment::Kick, None) => NoticeKind::Kick,
+            (Punishment::Ban, None) => NoticeKind::Ban,
+            (Punishment::Timeout, None) => NoticeKind::Timeout,
+            (Punishment::Strike, None) => NoticeKind::Strike,
+            (Punishment::Quarantine, None) => NoticeKind::Quarantine,
+        }
+    }
+
+    /// Setting holding the guild's template for this kind of notice
+    fn setting(&self) -> Setting {
+        match self {
+            NoticeKind::Kick => Setting::KickMessage,
+            NoticeKind::Ban => Setting::BanMessage,
+            NoticeKind::Timeout => Setting::TimeoutMessage,
+            NoticeKind::Strike => Setting::StrikeMessage,
+            NoticeKind::Quarantine => Setting::QuarantineMessage,
+            NoticeKind::Infraction => Setting::InfractionMessage,
+        }
+    }
+
+    /// Translated message used when the guild has no template
+    fn message_id(&self) -> &'static str {
+        match self {
+            NoticeKind::Kick => "dm-kick",
+            NoticeKind::Ban => "dm-ban",
+            NoticeKind::Timeout => "dm-timeout",
+            NoticeKind::Strike => "dm-strike",
+            NoticeKind::Quarantine => "dm-quarantine",
+            NoticeKind::Infraction => "dm-infraction",
+        }
+    }
+}
+
+pub struct Notice {
+    pub kind: NoticeKind,
+    pub reason: String,
+    /// Length of the punishment in seconds, permanent or not applicable if unset
+    pub duration: Option<i64>,
+    pub case_number: i32,
+    pub case_id: i32,
+    /// Kicks can't be undone, so there is nothing to appeal
+    pub appealable: bool,
+}
+
+impl Notice {
+    pub fn new(kind: NoticeKind, case: &CaseModel) -> Self {
+        Self {
+            kind,
+            reason: case.reason.clone(),
+            duration: None,
+            case_number: case.case_number,
+            case_id: case.id,
+            appealable: case.action != CaseAction::Kick,
+        }
+    }
+
+    pub fn duration(mut self, duration: Option<i64>) -> Self {
+        self.duration = duration.filter(|d| *d > 0);
+        self
+    }
+}
+
+/// DMs the notice to the member, in the guild's template or the member's language, with a button
+/// to appeal if the guild reviews appeals. Returns the message so it can be deleted if the
+/// punishment fails, or `None` if the member can't be DMed.
+pub async fn send(
+    http: impl CacheHttp,
+    settings: &Settings,
+    translations: &Translations,
+    guild_id: GuildId,
+    user_id: UserId,
+    notice: &Notice,
+) -> Option<Message> {
+    let guild = guild_name(&http, guild_id).await;
+    let appeal = settings.get(guild_id, Setting::AppealLink).await;
+    let locale = translations.resolve_locale(Some(user_id), Some(guild_id), None);
+    let locale = locale.as_deref();
+
+    let content = match settings.get(guild_id, notice.kind.setting()).await {
+        Some(template) => {
+            let duration = match notice.duration {
+                Some(duration) => format_duration(duration),
+                None => tr_in!(translations, locale, "dm-permanent"),
+            };
+
+            let mut content = template
+                .replace("{guild}", &guild)
+                .replace("{duration}", &duration)
+                .replace("{case}", &notice.case_number.to_string())
+                .replace("{appeal}", appeal.as_deref().unwrap_or_default());
+
+            // Older strike messages were sent with the reason below them
+            match template.contains("{reason}") {
+                true => content = content.replace("{reason}", &notice.reason),
+                false if !notice.reason.is_empty() => {
+                    content = format!("{}\n{}", content, notice.reason)
+                }
+                false => (),
+            }
+
+            content
+        }
+        None => {
+            let mut lines = vec![tr_in!(
+                translations,
+                locale,
+                notice.kind.message_id(),
+                guild: guild
+            )];
+
+            if let Some(duration) = notice.duration {
+                let duration = format_duration(duration);
+                lines.push(tr_in!(translations, locale, "dm-duration", duration: duration));
+            }
+
+            if !notice.reason.is_empty() {
+                let reason = notice.reason.clone();
+                lines.push(tr_in!(translations, locale, "dm-reason", reason: reason));
+            }
+
+            let case = notice.case_number.to_string();
+            lines.push(tr_in!(translations, locale, "dm-case", case: case));
+
+            if let Some(appeal) = appeal {
+                lines.push(tr_in!(translations, locale, "dm-appeal", appeal: appeal));
+            }
+
+            lines.join("\n")
+        }
+    };
+
+    let mut message = CreateMessage::new().content(content);
+
+    if notice.appealable
+        && settings
+            .channel(guild_id, Setting::AppealChannel)
+            .await
+            .is_some()
+    {
+        let button = CreateButton::new(appeals::button_id(notice.case_id))
+            .label(tr_in!(translations, locale, "dm-appeal-button"))
+            .style(ButtonStyle::Secondary);
+        message = message.components(vec![CreateActionRow::Buttons(vec![button])]);
+    }
+
+    let channel = user_id.create_dm_channel(&http).await.ok()?;
+    channel.send_message(&http, message).await.ok()
+}
+
+pub async fn guild_name(http: impl CacheHttp, guild_id: GuildId) -> String {
+    if let Some(name) = http.cache().and_then(|cache| guild_id.name(cache)) {
+        return name;
+    }
+
+    match guild_id.to_partial_guild(http.http()).await {
+        Ok(guild) => guild.name,
+        Err(_) => guild_id.to_string(),
+    }
+}
@@ -0,0 +1,349 @@
+//! Collects the messages matched by a purge, paging through the channel history past the
+//! 100 message limit of a single request. Messages are archived to the database before
+//! they're deleted, so their content is still available for appeals.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serenity::all::{ChannelId, GetMessages, GuildId, Http, Message, MessageId, UserId};
+use sqlx::types::chrono::Utc;
+
+use crate::database::Database;
+use crate::Error;
+
+/// Discord refuses to bulk delete messages older than 14 days
+const BULK_DELETE_MAX_AGE: i64 = 14 * 60 * 60 * 24;
+
+/// Upper bound of messages inspected by a single purge
+const MAX_SCANNED: usize = 5000;
+
+#[derive(Default)]
+pub struct PurgeFilter {
+    pub users: Vec<UserId>,
+    pub bots: Option<bool>,
+    pub pattern: Option<Regex>,
+    pub links: bool,
+    pub attachments: bool,
+    pub before: Option<MessageId>,
+    pub after: Option<MessageId>,
+}
+
+impl PurgeFilter {
+    fn matches(&self, message: &Message) -> bool {
+        if !self.users.is_empty() && !self.users.contains(&message.author.id) {
+            return false;
+        }
+
+        if let Some(bots) = self.bots {
+            if message.author.bot != bots {
+                return false;
+            }
+        }
+
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(&message.content) {
+                return false;
+            }
+        }
+
+        if self.links && !contains_link(&message.content) {
+            return false;
+        }
+
+        if self.attachments && message.attachments.is_empty() {
+            return false;
+        }
+
+        true
+    }
+
+    /// Whether the message is at or before the `after` cutoff, where the search stops
+    fn is_past_cutoff(&self, message: &Message) -> bool {
+        self.after.is_some_and(|after| message.id <= after)
+    }
+}
+
+fn contains_link(content: &str) -> bool {
+    static LINK: OnceLock<Regex> = OnceLock::new();
+
+    LINK.get_or_init(|| Regex::new(r"https?://\S+").unwrap())
+        .is_match(content)
+}
+
+pub struct PurgeSummary {
+    pub deleted: usize,
+    pub authors: HashMap<UserId, usize>,
+    /// Whether the search stopped at messages too old to be bulk deleted
+    pub reached_age_limit: bool,
+    /// Whether deleting failed partway, leaving the remaining messages in place
+    pub interrupted: bool,
+    /// Archive holding the deleted messages, if anything was deleted
+    pub archive_id: Option<i32>,
+    /// Plain text transcript of the deleted messages, oldest first
+    pub transcript: String,
+}
+
+/// Archives and deletes up to `amount` messages matching the filter, newest first. Nothing is
+/// deleted if the messages can't be archived.
+pub async fn purge(
+    http: &Http,
+    database: &Database,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    moderator_id: UserId,
+    amount: usize,
+    filter: &PurgeFilter,
+) -> Result<PurgeSummary, Error> {
+    let (messages, reached_age_limit) = collect(http, channel_id, amount, filter).await?;
+
+    let mut summary = PurgeSummary {
+        deleted: 0,
+        authors: HashMap::new(),
+        reached_age_limit,
+        interrupted: false,
+        archive_id: None,
+        transcript: String::new(),
+    };
+
+    if messages.is_empty() {
+        return Ok(summary);
+    }
+
+    let archive = database
+        .add_purge_archive(guild_id, channel_id, moderator_id, &messages)
+        .await?;
+
+    summary.archive_id = Some(archive.id);
+    summary.transcript = transcript(&messages);
+
+    for chunk in messages.chunks(100) {
+        let result = channel_id
+            .delete_messages(http, chunk.iter().map(|message| message.id))
+            .await;
+
+        // Once some messages are gone, the moderator still needs to know what was deleted
+        if let Err(e) = result {
+            if summary.deleted == 0 {
+                return Err(e.into());
+            }
+
+            println!(
+                "Stopped purging channel {} after {} messages: {}",
+                channel_id, summary.deleted, e
+            );
+            summary.interrupted = true;
+            break;
+        }
+
+        summary.deleted += chunk.len();
+
+        for message in chunk.iter() {
+            *summary.authors.entry(message.author.id).or_default() += 1;
+        }
+    }
+
+    Ok(summary)
+}
+
+fn transcript(messages: &[Message]) -> String {
+    let mut transcript = String::new();
+
+    for message in messages.iter().rev() {
+        let _ = writeln!(
+            transcript,
+            "[{}] {} ({}): {}",
+            message.timestamp.to_rfc3339().unwrap_or_default(),
+            message.author.name,
+            message.author.id,
+            message.content
+        );
+
+        for attachment in message.attachments.iter() {
+            let _ = writeln!(transcript, "    Attachment: {}", attachment.url);
+        }
+    }
+
+    transcript
+}
+
+/// Pages backwards through the channel, returning the matching messages and whether the
+/// search stopped at a message older than the bulk delete limit
+async fn collect(
+    http: &Http,
+    channel_id: ChannelId,
+    amount: usize,
+    filter: &PurgeFilter,
+) -> Result<(Vec<Message>, bool), Error> {
+    let oldest_allowed = Utc::now().timestamp() - BULK_DELETE_MAX_AGE;
+
+    let mut matched = vec![];
+    let mut scanned = 0;
+    let mut cursor = filter.before;
+
+    while matched.len() < amount && scanned < MAX_SCANNED {
+        let mut builder = GetMessages::new().limit(100);
+
+        if let Some(before) = cursor {
+            builder = builder.before(before);
+        }
+
+        let page = channel_id.messages(http, builder).await?;
+
+        let last = match page.last() {
+            Some(message) => message.id,
+            None => break,
+        };
+
+        scanned += page.len();
+
+        for message in page.into_iter() {
+            if filter.is_past_cutoff(&message) {
+                return Ok((matched, false));
+            }
+
+            // Older messages can't be bulk deleted, so there is no point paging further back
+            if message.timestamp.unix_timestamp() <= oldest_allowed {
+                return Ok((matched, true));
+            }
+
+            if filter.matches(&message) {
+                matched.push(message);
+
+                if matched.len() == amount {
+                    break;
+                }
+            }
+        }
+
+        cursor = Some(last);
+    }
+
+    Ok((matched, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(id: u64, author: u64, content: &str) -> Message {
+        let mut message = Message::default();
+        message.id = MessageId::new(id);
+        message.author.id = UserId::new(author);
+        message.content = content.to_owned();
+        message
+    }
+
+    fn attachment() -> serenity::all::Attachment {
+        serenity::json::from_value(serenity::json::json!({
+            "id": "1",
+            "filename": "image.png",
+            "size": 1,
+            "url": "https://cdn.discordapp.com/image.png",
+            "proxy_url": "https://media.discordapp.net/image.png",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        assert!(PurgeFilter::default().matches(&message(1, 1, "hello")));
+    }
+
+    #[test]
+    fn users_filter_matches_listed_authors_only() {
+        let filter = PurgeFilter {
+            users: vec![UserId::new(1), UserId::new(2)],
+            ..Default::default()
+        };
+
+        assert!(filter.matches(&message(1, 2, "hello")));
+        assert!(!filter.matches(&message(1, 3, "hello")));
+    }
+
+    #[test]
+    fn bots_filter_matches_either_kind_of_author() {
+        let mut bot = message(1, 1, "beep");
+        bot.author.bot = true;
+        let human = message(2, 2, "hi");
+
+        let bots = PurgeFilter {
+            bots: Some(true),
+            ..Default::default()
+        };
+        assert!(bots.matches(&bot));
+        assert!(!bots.matches(&human));
+
+        let humans = PurgeFilter {
+            bots: Some(false),
+            ..Default::default()
+        };
+        assert!(!humans.matches(&bot));
+        assert!(humans.matches(&human));
+    }
+
+    #[test]
+    fn pattern_filter_matches_content() {
+        let filter = PurgeFilter {
+            pattern: Some(Regex::new(r"^buy \w+$").unwrap()),
+            ..Default::default()
+        };
+
+        assert!(filter.matches(&message(1, 1, "buy gold")));
+        assert!(!filter.matches(&message(1, 1, "please buy gold")));
+    }
+
+    #[test]
+    fn links_filter_needs_a_scheme() {
+        let filter = PurgeFilter {
+            links: true,
+            ..Default::default()
+        };
+
+        assert!(filter.matches(&message(1, 1, "see https://example.com")));
+        assert!(filter.matches(&message(1, 1, "http://example.com")));
+        assert!(!filter.matches(&message(1, 1, "see example.com")));
+        assert!(!filter.matches(&message(1, 1, "https://")));
+    }
+
+    #[test]
+    fn attachments_filter_needs_an_attachment() {
+        let filter = PurgeFilter {
+            attachments: true,
+            ..Default::default()
+        };
+
+        let mut with_file = message(1, 1, "");
+        with_file.attachments.push(attachment());
+
+        assert!(filter.matches(&with_file));
+        assert!(!filter.matches(&message(1, 1, "")));
+    }
+
+    #[test]
+    fn filters_are_combined() {
+        let filter = PurgeFilter {
+            users: vec![UserId::new(1)],
+            links: true,
+            ..Default::default()
+        };
+
+        assert!(filter.matches(&message(1, 1, "https://example.com")));
+        assert!(!filter.matches(&message(1, 1, "no link")));
+        assert!(!filter.matches(&message(1, 2, "https://example.com")));
+    }
+
+    #[test]
+    fn cutoff_includes_the_after_message() {
+        let filter = PurgeFilter {
+            after: Some(MessageId::new(100)),
+            ..Default::default()
+        };
+
+        assert!(filter.is_past_cutoff(&message(99, 1, "")));
+        assert!(filter.is_past_cutoff(&message(100, 1, "")));
+        assert!(!filter.is_past_cutoff(&message(101, 1, "")));
+        assert!(!PurgeFilter::default().is_past_cutoff(&message(1, 1, "")));
+    }
+}
@@ -0,0 +1,247 @@
+//! Persists time-bound moderation actions in the database and executes them once they are due,
+//! so pending unbans, unmutes, slowmode reverts and unlocks survive bot restarts
+
+use std::num::ParseIntError;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serenity::all::{ChannelId, EditChannel, GuildId, Http, UserId};
+use sqlx::types::chrono::{DateTime, Utc};
+use tokio::sync::Notify;
+
+use crate::database::Database;
+use crate::lock;
+use crate::models::{CaseAction, ScheduledAction, ScheduledActionModel};
+use crate::modlog::{self, ModLogAction, ModLogEntry};
+use crate::mute;
+use crate::settings::Settings;
+use crate::translation::{tr_in, Translations};
+use crate::utils::{is_discord_error, UNKNOWN_BAN, UNKNOWN_MEMBER};
+use crate::Error;
+
+/// Upper bound for how long the worker sleeps without checking the database
+const IDLE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Delay before the first retry of a failed action
+const RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Failed runs after which an action is given up on
+const MAX_ATTEMPTS: i32 = 10;
+
+/// Discord errors meaning the target is gone, so retrying would never succeed
+const PERMANENT_ERRORS: &[isize] = &[
+    10003, // Unknown channel
+    10004, // Unknown guild
+    UNKNOWN_MEMBER,
+    10013, // Unknown user
+    UNKNOWN_BAN,
+];
+
+pub struct Scheduler {
+    database: Arc<Database>,
+    settings: Arc<Settings>,
+    translations: Arc<Translations>,
+    notify: Notify,
+}
+
+impl Scheduler {
+    pub fn new(
+        database: Arc<Database>,
+        settings: Arc<Settings>,
+        translations: Arc<Translations>,
+    ) -> Self {
+        Self {
+            database,
+            settings,
+            translations,
+            notify: Notify::new(),
+        }
+    }
+
+    /// Stores a new action and wakes the worker up so it can re-arm its timer
+    pub async fn schedule(
+        &self,
+        guild_id: GuildId,
+        action: ScheduledAction,
+        target_id: u64,
+        payload: Option<String>,
+        moderator_id: UserId,
+        run_at: DateTime<Utc>,
+    ) -> Result<ScheduledActionModel, Error> {
+        let scheduled = self
+            .database
+            .add_scheduled_action(
+                guild_id,
+                action,
+                target_id.to_string(),
+                payload,
+                moderator_id,
+                run_at,
+            )
+            .await?;
+
+        self.notify.notify_one();
+        Ok(scheduled)
+    }
+
+    /// Worker loop started once from `main`. Every pending action is read back from the
+    /// database, so anything scheduled before a restart is picked up on boot.
+    pub async fn run(self: Arc<Self>, http: Arc<Http>) {
+        loop {
+            match self.database.get_due_scheduled_actions().await {
+                Ok(actions) => {
+                    for action in actions.iter() {
+                        match self.execute(&http, action).await {
+                            Ok(()) => self.remove(action).await,
+                            Err(e) if is_permanent(&e) || action.attempts >= MAX_ATTEMPTS => {
+                                println!(
+                                    "Dropping scheduled action {} ({:?}): {}",
+                                    action.id, action.action, e
+                                );
+                                self.remove(action).await;
+                            }
+                            Err(e) => {
+                                println!(
+                                    "Failed to run scheduled action {} ({:?}), retrying: {}",
+                                    action.id, action.action, e
+                                );
+                                self.retry(action).await;
+                            }
+                        }
+                    }
+                }
+                Err(e) => println!("Failed to fetch scheduled actions: {}", e),
+            }
+
+            let sleep = match self.database.get_next_scheduled_run().await {
+                Ok(Some(run_at)) => (run_at - Utc::now())
+                    .to_std()
+                    .unwrap_or(Duration::ZERO)
+                    .min(IDLE_INTERVAL),
+                _ => IDLE_INTERVAL,
+            };
+
+            tokio::select! {
+                _ = tokio::time::sleep(sleep) => {}
+                _ = self.notify.notified() => {}
+            }
+        }
+    }
+
+    async fn remove(&self, action: &ScheduledActionModel) {
+        if let Err(e) = self.database.remove_scheduled_action(action.id).await {
+            println!("Failed to remove scheduled action {}: {}", action.id, e);
+        }
+    }
+
+    /// Pushes the action back, doubling the delay with every failed attempt
+    async fn retry(&self, action: &ScheduledActionModel) {
+        let delay = RETRY_DELAY
+            .saturating_mul(2u32.saturating_pow(action.attempts as u32))
+            .min(IDLE_INTERVAL);
+        let run_at = Utc::now() + delay;
+
+        if let Err(e) = self
+            .database
+            .retry_scheduled_action(action.id, run_at)
+            .await
+        {
+            println!("Failed to reschedule action {}: {}", action.id, e);
+        }
+    }
+
+    async fn execute(&self, http: &Http, action: &ScheduledActionModel) -> Result<(), Error> {
+        let guild_id = GuildId::new(action.guild_id.parse()?);
+        let target_id: u64 = action.target_id.parse()?;
+        let moderator_id = UserId::new(action.moderator_id.parse()?);
+
+        // Nobody invoked the action, so it's logged in the guild's language
+        let locale = self.translations.resolve_locale(None, Some(guild_id), None);
+        let locale = locale.as_deref();
+
+        let entry = match action.action {
+            ScheduledAction::Unban => {
+                let user_id = UserId::new(target_id);
+                guild_id.unban(http, user_id).await?;
+
+                let case = self
+                    .database
+                    .log_case(
+                        guild_id,
+                        CaseAction::Unban,
+                        &user_id,
+                        moderator_id,
+                        &tr_in!(self.translations, locale, "scheduler-ban-expired"),
+                    )
+                    .await?;
+
+                ModLogEntry::case(&case)
+            }
+            ScheduledAction::Unmute => {
+                let user_id = UserId::new(target_id);
+                mute::unmute_member(http, &self.database, guild_id, user_id).await?;
+
+                let case = self
+                    .database
+                    .log_case(
+                        guild_id,
+                        CaseAction::Untimeout,
+                        &user_id,
+                        moderator_id,
+                        &tr_in!(self.translations, locale, "scheduler-mute-expired"),
+                    )
+                    .await?;
+
+                ModLogEntry::case(&case)
+            }
+            ScheduledAction::Slowmode => {
+                let seconds = match &action.payload {
+                    Some(payload) => payload.parse()?,
+                    None => 0,
+                };
+
+                let builder = EditChannel::new().rate_limit_per_user(seconds);
+                ChannelId::new(target_id).edit(http, builder).await?;
+
+                ModLogEntry::channel(
+                    ModLogAction::Slowmode,
+                    ChannelId::new(target_id),
+                    moderator_id,
+                )
+                .details(tr_in!(
+                    self.translations,
+                    locale,
+                    "scheduler-slowmode-reverted",
+                    seconds: seconds
+                ))
+            }
+            ScheduledAction::Unlock => {
+                let channel = ChannelId::new(target_id)
+                    .to_channel(http)
+                    .await?
+                    .guild()
+                    .ok_or("scheduled unlock target isn't a guild channel")?;
+
+                // Already unlocked by hand, so there's nothing to log
+                if !lock::unlock_channel(http, &self.database, &channel).await? {
+                    return Ok(());
+                }
+
+                ModLogEntry::channel(ModLogAction::Unlock, channel.id, moderator_id)
+                    .details(tr_in!(self.translations, locale, "scheduler-lock-expired"))
+            }
+        };
+
+        modlog::send(http, &self.settings, &self.translations, guild_id, entry).await;
+        Ok(())
+    }
+}
+
+/// Whether a failed action can never succeed, like unbanning someone who was already unbanned
+fn is_permanent(error: &Error) -> bool {
+    match error.downcast_ref::<serenity::Error>() {
+        Some(error) => is_discord_error(error, PERMANENT_ERRORS),
+        // Malformed IDs or payloads stay malformed
+        None => error.downcast_ref::<ParseIntError>().is_some(),
+    }
+}
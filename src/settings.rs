@@ -0,0 +1,577 @@
+//! Typed per-guild settings stored in the `guild_settings` table. Values are cached in memory
+//! after the first lookup, so hot paths like prefix resolution don't query the database on
+//! every message.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use regex::Regex;
+use serenity::all::{ChannelId, GuildId, RoleId};
+use tokio::sync::RwLock;
+
+use crate::database::Database;
+use crate::notify::PLACEHOLDERS;
+use crate::Error;
+
+pub const DEFAULT_PREFIX: &str = "k!";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Setting {
+    Prefix,
+    Locale,
+    ModLogChannel,
+    ModRoles,
+    BangChannel,
+    StrikeMessage,
+    KickMessage,
+    BanMessage,
+    TimeoutMessage,
+    QuarantineMessage,
+    InfractionMessage,
+    AppealLink,
+    AppealChannel,
+    LockdownChannels,
+    PurgeTranscripts,
+    AntiRaidThreshold,
+    AntiRaidWindow,
+    AntiRaidAction,
+    AntiRaidAccountAge,
+    QuarantineRole,
+    GateAccountAge,
+    GateDefaultAvatar,
+    GateUsernamePattern,
+    GateAction,
+    AutomodAction,
+    AutomodTimeout,
+    AutomodExemptRoles,
+    AutomodExemptChannels,
+    SpamFilter,
+    SpamWindow,
+    SpamMessages,
+    SpamDuplicates,
+    SpamMentions,
+    SpamEmojis,
+    SpamInfraction,
+    LinkFilter,
+    MuteRole,
+}
+
+enum SettingKind {
+    Text {
+        max_length: usize,
+    },
+    /// Text with placeholders from [`crate::notify::PLACEHOLDERS`]
+    Template {
+        max_length: usize,
+    },
+    Link,
+    Locale,
+    Channel,
+    Channels,
+    Role,
+    Roles,
+    Flag,
+    Pattern {
+        max_length: usize,
+    },
+    Number {
+        min: u64,
+        max: u64,
+    },
+    Choice(&'static [&'static str]),
+}
+
+/// Why [`Setting::parse`] rejected a value, translated by the command showing it
+#[derive(Debug)]
+pub enum InvalidValue {
+    Empty,
+    TooLong(usize),
+    /// Prefixes with spaces could never be typed before a command
+    Spaces,
+    Placeholder,
+    Link,
+    Locale,
+    Channel,
+    Channels,
+    Role,
+    Roles,
+    Flag,
+    Pattern,
+    Number {
+        min: u64,
+        max: u64,
+    },
+    Choice(&'static [&'static str]),
+}
+
+impl Setting {
+    pub const ALL: &'static [Setting] = &[
+        Setting::Prefix,
+        Setting::Locale,
+        Setting::ModLogChannel,
+        Setting::ModRoles,
+        Setting::BangChannel,
+        Setting::StrikeMessage,
+        Setting::KickMessage,
+        Setting::BanMessage,
+        Setting::TimeoutMessage,
+        Setting::QuarantineMessage,
+        Setting::InfractionMessage,
+        Setting::AppealLink,
+        Setting::AppealChannel,
+        Setting::LockdownChannels,
+        Setting::PurgeTranscripts,
+        Setting::AntiRaidThreshold,
+        Setting::AntiRaidWindow,
+        Setting::AntiRaidAction,
+        Setting::AntiRaidAccountAge,
+        Setting::QuarantineRole,
+        Setting::GateAccountAge,
+        Setting::GateDefaultAvatar,
+        Setting::GateUsernamePattern,
+        Setting::GateAction,
+        Setting::AutomodAction,
+        Setting::AutomodTimeout,
+        Setting::AutomodExemptRoles,
+        Setting::AutomodExemptChannels,
+        Setting::SpamFilter,
+        Setting::SpamWindow,
+        Setting::SpamMessages,
+        Setting::SpamDuplicates,
+        Setting::SpamMentions,
+        Setting::SpamEmojis,
+        Setting::SpamInfraction,
+        Setting::LinkFilter,
+        Setting::MuteRole,
+    ];
+
+    /// Name used in the database and in the `/config` command
+    pub fn key(&self) -> &'static str {
+        match self {
+            Setting::Prefix => "prefix",
+            Setting::Locale => "locale",
+            Setting::ModLogChannel => "mod_log_channel",
+            Setting::ModRoles => "mod_roles",
+            Setting::BangChannel => "bang_channel",
+            Setting::StrikeMessage => "strike_message",
+            Setting::KickMessage => "kick_message",
+            Setting::BanMessage => "ban_message",
+            Setting::TimeoutMessage => "timeout_message",
+            Setting::QuarantineMessage => "quarantine_message",
+            Setting::InfractionMessage => "infraction_message",
+            Setting::AppealLink => "appeal_link",
+            Setting::AppealChannel => "appeal_channel",
+            Setting::LockdownChannels => "lockdown_channels",
+            Setting::PurgeTranscripts => "purge_transcripts",
+            Setting::AntiRaidThreshold => "antiraid_threshold",
+            Setting::AntiRaidWindow => "antiraid_window",
+            Setting::AntiRaidAction => "antiraid_action",
+            Setting::AntiRaidAccountAge => "antiraid_account_age",
+            Setting::QuarantineRole => "quarantine_role",
+            Setting::GateAccountAge => "gate_account_age",
+            Setting::GateDefaultAvatar => "gate_default_avatar",
+            Setting::GateUsernamePattern => "gate_username_pattern",
+            Setting::GateAction => "gate_action",
+            Setting::AutomodAction => "automod_action",
+            Setting::AutomodTimeout => "automod_timeout",
+            Setting::AutomodExemptRoles => "automod_exempt_roles",
+            Setting::AutomodExemptChannels => "automod_exempt_channels",
+            Setting::SpamFilter => "spam_filter",
+            Setting::SpamWindow => "spam_window",
+            Setting::SpamMessages => "spam_messages",
+            Setting::SpamDuplicates => "spam_duplicates",
+            Setting::SpamMentions => "spam_mentions",
+            Setting::SpamEmojis => "spam_emojis",
+            Setting::SpamInfraction => "spam_infraction",
+            Setting::LinkFilter => "link_filter",
+            Setting::MuteRole => "mute_role",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Setting> {
+        Setting::ALL.iter().copied().find(|s| s.key() == key)
+    }
+
+    pub fn default_value(&self) -> Option<&'static str> {
+        match self {
+            Setting::Prefix => Some(DEFAULT_PREFIX),
+            Setting::PurgeTranscripts => Some("false"),
+            Setting::AntiRaidWindow => Some("10"),
+            Setting::AntiRaidAction => Some("alert"),
+            Setting::AntiRaidAccountAge => Some("7"),
+            Setting::GateDefaultAvatar => Some("false"),
+            Setting::GateAction => Some("timeout"),
+            Setting::AutomodAction => Some("warn"),
+            Setting::AutomodTimeout => Some("600"),
+            Setting::SpamFilter => Some("false"),
+            Setting::SpamWindow => Some("5"),
+            Setting::SpamMessages => Some("6"),
+            Setting::SpamDuplicates => Some("3"),
+            Setting::SpamMentions => Some("5"),
+            Setting::SpamEmojis => Some("10"),
+            Setting::LinkFilter => Some("off"),
+            // Unset templates mean the translated DMs are sent
+            Setting::StrikeMessage
+            | Setting::KickMessage
+            | Setting::BanMessage
+            | Setting::TimeoutMessage
+            | Setting::QuarantineMessage
+            | Setting::InfractionMessage => None,
+            // Unset means every member gets their client's language
+            Setting::Locale
+            | Setting::ModLogChannel
+            | Setting::ModRoles
+            | Setting::BangChannel
+            | Setting::LockdownChannels
+            | Setting::AntiRaidThreshold
+            | Setting::QuarantineRole
+            | Setting::GateAccountAge
+            | Setting::GateUsernamePattern
+            | Setting::AutomodExemptRoles
+            | Setting::AutomodExemptChannels
+            | Setting::SpamInfraction
+            | Setting::AppealLink
+            | Setting::AppealChannel
+            | Setting::MuteRole => None,
+        }
+    }
+
+    fn kind(&self) -> SettingKind {
+        match self {
+            Setting::Prefix => SettingKind::Text { max_length: 10 },
+            Setting::Locale => SettingKind::Locale,
+            Setting::ModLogChannel | Setting::BangChannel | Setting::AppealChannel => {
+                SettingKind::Channel
+            }
+            Setting::ModRoles => SettingKind::Roles,
+            Setting::StrikeMessage
+            | Setting::KickMessage
+            | Setting::BanMessage
+            | Setting::TimeoutMessage
+            | Setting::QuarantineMessage
+            | Setting::InfractionMessage => SettingKind::Template { max_length: 1000 },
+            Setting::AppealLink => SettingKind::Link,
+            Setting::LockdownChannels => SettingKind::Channels,
+            Setting::PurgeTranscripts => SettingKind::Flag,
+            Setting::AntiRaidThreshold => SettingKind::Number { min: 2, max: 100 },
+            Setting::AntiRaidWindow => SettingKind::Number { min: 1, max: 600 },
+            Setting::AntiRaidAction => {
+                SettingKind::Choice(&["alert", "lockdown", "timeout", "kick"])
+            }
+            Setting::AntiRaidAccountAge => SettingKind::Number { min: 1, max: 365 },
+            Setting::QuarantineRole | Setting::MuteRole => SettingKind::Role,
+            Setting::GateAccountAge => SettingKind::Number { min: 1, max: 365 },
+            Setting::GateDefaultAvatar => SettingKind::Flag,
+            Setting::GateUsernamePattern => SettingKind::Pattern { max_length: 200 },
+            Setting::GateAction => SettingKind::Choice(&["kick", "timeout", "quarantine"]),
+            Setting::AutomodAction => SettingKind::Choice(&["delete", "warn", "strike", "timeout"]),
+            Setting::AutomodTimeout => SettingKind::Number {
+                min: 1,
+                max: 2419200,
+            },
+            Setting::AutomodExemptRoles => SettingKind::Roles,
+            Setting::AutomodExemptChannels => SettingKind::Channels,
+            Setting::SpamFilter => SettingKind::Flag,
+            Setting::SpamWindow => SettingKind::Number { min: 1, max: 60 },
+            Setting::SpamMessages => SettingKind::Number { min: 2, max: 50 },
+            Setting::SpamDuplicates => SettingKind::Number { min: 2, max: 20 },
+            Setting::SpamMentions => SettingKind::Number { min: 1, max: 50 },
+            Setting::SpamEmojis => SettingKind::Number { min: 1, max: 100 },
+            Setting::SpamInfraction => SettingKind::Number {
+                min: 1,
+                max: i32::MAX as u64,
+            },
+            Setting::LinkFilter => SettingKind::Choice(&["off", "invites", "all"]),
+        }
+    }
+
+    /// Validates user input and converts it into the representation stored in the database
+    pub fn parse(&self, input: &str) -> Result<String, InvalidValue> {
+        let input = input.trim();
+
+        if input.is_empty() {
+            return Err(InvalidValue::Empty);
+        }
+
+        match self.kind() {
+            SettingKind::Text { max_length } => {
+                if input.chars().count() > max_length {
+                    return Err(InvalidValue::TooLong(max_length));
+                }
+
+                if *self == Setting::Prefix && input.contains(char::is_whitespace) {
+                    return Err(InvalidValue::Spaces);
+                }
+
+                Ok(input.to_owned())
+            }
+            SettingKind::Template { max_length } => {
+                if input.chars().count() > max_length {
+                    return Err(InvalidValue::TooLong(max_length));
+                }
+
+                let regex = Regex::new(r"\{(\w*)\}").unwrap();
+
+                let known = regex
+                    .captures_iter(input)
+                    .all(|captures| PLACEHOLDERS.contains(&&captures[1]));
+
+                match known {
+                    true => Ok(input.to_owned()),
+                    false => Err(InvalidValue::Placeholder),
+                }
+            }
+            SettingKind::Link => {
+                let regex = Regex::new(r"^https?://\S+$").unwrap();
+
+                match input.chars().count() <= 200 && regex.is_match(input) {
+                    true => Ok(input.to_owned()),
+                    false => Err(InvalidValue::Link),
+                }
+            }
+            SettingKind::Locale => {
+                let regex = Regex::new(r"^[a-z]{2}(-[A-Z]{2})?$").unwrap();
+
+                match regex.is_match(input) {
+                    true => Ok(input.to_owned()),
+                    false => Err(InvalidValue::Locale),
+                }
+            }
+            SettingKind::Channel => {
+                let regex = Regex::new(r"^(?:<#)?(\d+)>?$").unwrap();
+
+                match regex.captures(input) {
+                    Some(captures) => Ok(captures[1].to_owned()),
+                    None => Err(InvalidValue::Channel),
+                }
+            }
+            SettingKind::Channels => {
+                parse_ids(input, r"^(?:<#)?(\d+)>?$").ok_or(InvalidValue::Channels)
+            }
+            SettingKind::Role => {
+                let regex = Regex::new(r"^(?:<@&)?(\d+)>?$").unwrap();
+
+                match regex.captures(input) {
+                    Some(captures) => Ok(captures[1].to_owned()),
+                    None => Err(InvalidValue::Role),
+                }
+            }
+            SettingKind::Roles => parse_ids(input, r"^(?:<@&)?(\d+)>?$").ok_or(InvalidValue::Roles),
+            SettingKind::Flag => match input.to_lowercase().as_str() {
+                "true" | "yes" | "on" | "1" => Ok("true".to_owned()),
+                "false" | "no" | "off" | "0" => Ok("false".to_owned()),
+                _ => Err(InvalidValue::Flag),
+            },
+            SettingKind::Pattern { max_length } => {
+                if input.chars().count() > max_length {
+                    return Err(InvalidValue::TooLong(max_length));
+                }
+
+                match Regex::new(input) {
+                    Ok(_) => Ok(input.to_owned()),
+                    Err(_) => Err(InvalidValue::Pattern),
+                }
+            }
+            SettingKind::Number { min, max } => match input.parse::<u64>() {
+                Ok(number) if (min..=max).contains(&number) => Ok(number.to_string()),
+                _ => Err(InvalidValue::Number { min, max }),
+            },
+            SettingKind::Choice(choices) => {
+                let input = input.to_lowercase();
+
+                match choices.contains(&input.as_str()) {
+                    true => Ok(input),
+                    false => Err(InvalidValue::Choice(choices)),
+                }
+            }
+        }
+    }
+
+    /// Channels referenced by a stored value, none for settings that aren't channels
+    pub fn channel_ids(&self, value: &str) -> Vec<ChannelId> {
+        match self.kind() {
+            SettingKind::Channel | SettingKind::Channels => parse_stored_ids(value)
+                .into_iter()
+                .map(ChannelId::new)
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Roles referenced by a stored value, none for settings that aren't roles
+    pub fn role_ids(&self, value: &str) -> Vec<RoleId> {
+        match self.kind() {
+            SettingKind::Role | SettingKind::Roles => parse_stored_ids(value)
+                .into_iter()
+                .map(RoleId::new)
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Formats a stored value to be shown in Discord
+    pub fn display(&self, value: &str) -> String {
+        match self.kind() {
+            SettingKind::Text { .. }
+            | SettingKind::Template { .. }
+            | SettingKind::Locale
+            | SettingKind::Flag
+            | SettingKind::Pattern { .. }
+            | SettingKind::Number { .. }
+            | SettingKind::Choice(_) => format!("`{value}`"),
+            SettingKind::Link => format!("<{value}>"),
+            SettingKind::Channel => format!("<#{value}>"),
+            SettingKind::Channels => value
+                .split(',')
+                .map(|id| format!("<#{id}>"))
+                .collect::<Vec<_>>()
+                .join(" "),
+            SettingKind::Role => format!("<@&{value}>"),
+            SettingKind::Roles => value
+                .split(',')
+                .map(|id| format!("<@&{id}>"))
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+/// Splits the comma separated IDs of a stored value
+fn parse_stored_ids(value: &str) -> Vec<u64> {
+    value.split(',').filter_map(|id| id.parse().ok()).collect()
+}
+
+/// Parses a list of mentions or IDs separated by commas or spaces into comma separated IDs
+fn parse_ids(input: &str, pattern: &str) -> Option<String> {
+    let regex = Regex::new(pattern).unwrap();
+
+    let mut ids = vec![];
+
+    for item in input.split(|c: char| c == ',' || c.is_whitespace()) {
+        if item.is_empty() {
+            continue;
+        }
+
+        ids.push(regex.captures(item)?[1].to_owned());
+    }
+
+    Some(ids.join(","))
+}
+
+pub struct Settings {
+    database: Arc<Database>,
+    cache: RwLock<HashMap<GuildId, HashMap<Setting, String>>>,
+}
+
+impl Settings {
+    pub fn new(database: Arc<Database>) -> Self {
+        Self {
+            database,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Returns every value explicitly set in the guild, loading them into the cache if needed
+    pub async fn get_all(&self, guild_id: GuildId) -> Result<HashMap<Setting, String>, Error> {
+        if let Some(settings) = self.cache.read().await.get(&guild_id) {
+            return Ok(settings.clone());
+        }
+
+        let settings = self
+            .database
+            .get_guild_settings(guild_id)
+            .await?
+            .into_iter()
+            .filter_map(|row| Some((Setting::from_key(&row.key)?, row.value)))
+            .collect::<HashMap<_, _>>();
+
+        self.cache.write().await.insert(guild_id, settings.clone());
+
+        Ok(settings)
+    }
+
+    /// Returns the value set in the guild or the setting's default. Lookup failures are only
+    /// printed and treated as the setting being unset.
+    pub async fn get(&self, guild_id: GuildId, setting: Setting) -> Option<String> {
+        match self.get_all(guild_id).await {
+            Ok(mut settings) => settings.remove(&setting),
+            Err(e) => {
+                println!("Failed to retrieve settings of guild {}: {}", guild_id, e);
+                None
+            }
+        }
+        .or_else(|| setting.default_value().map(str::to_owned))
+    }
+
+    pub async fn prefix(&self, guild_id: Option<GuildId>) -> String {
+        match guild_id {
+            Some(guild_id) => self.get(guild_id, Setting::Prefix).await,
+            None => None,
+        }
+        .unwrap_or_else(|| DEFAULT_PREFIX.to_owned())
+    }
+
+    pub async fn channel(&self, guild_id: GuildId, setting: Setting) -> Option<ChannelId> {
+        let value = self.get(guild_id, setting).await?;
+        value.parse().ok().map(ChannelId::new)
+    }
+
+    pub async fn channels(&self, guild_id: GuildId, setting: Setting) -> Vec<ChannelId> {
+        match self.get(guild_id, setting).await {
+            Some(value) => parse_stored_ids(&value)
+                .into_iter()
+                .map(ChannelId::new)
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    pub async fn role(&self, guild_id: GuildId, setting: Setting) -> Option<RoleId> {
+        let value = self.get(guild_id, setting).await?;
+        value.parse().ok().map(RoleId::new)
+    }
+
+    pub async fn roles(&self, guild_id: GuildId, setting: Setting) -> Vec<RoleId> {
+        match self.get(guild_id, setting).await {
+            Some(value) => parse_stored_ids(&value)
+                .into_iter()
+                .map(RoleId::new)
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    pub async fn flag(&self, guild_id: GuildId, setting: Setting) -> bool {
+        self.get(guild_id, setting).await.as_deref() == Some("true")
+    }
+
+    pub async fn number(&self, guild_id: GuildId, setting: Setting) -> Option<u64> {
+        self.get(guild_id, setting).await?.parse().ok()
+    }
+
+    pub async fn set(&self, guild_id: GuildId, setting: Setting, value: &str) -> Result<(), Error> {
+        self.database
+            .set_guild_setting(guild_id, setting, value)
+            .await?;
+
+        if let Some(settings) = self.cache.write().await.get_mut(&guild_id) {
+            settings.insert(setting, value.to_owned());
+        }
+
+        Ok(())
+    }
+
+    /// Removes the guild's value, returning whether there was one
+    pub async fn reset(&self, guild_id: GuildId, setting: Setting) -> Result<bool, Error> {
+        let result = self
+            .database
+            .remove_guild_setting(guild_id, setting)
+            .await?;
+
+        if let Some(settings) = self.cache.write().await.get_mut(&guild_id) {
+            settings.remove(&setting);
+        }
+
+        Ok(result.rows_affected() > 0)
+    }
+}
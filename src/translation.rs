@@ -1,5 +1,13 @@
 //! Wraps the fluent API and provides easy to use functions and macros for translation
 
+use std::collections::{BTreeSet, HashMap};
+use std::sync::RwLock;
+
+use serenity::all::{GuildId, UserId};
+
+use crate::database::Database;
+use crate::models::{CaseAction, Punishment, Severity};
+use crate::settings::Setting;
 use crate::{Context, Data, Error};
 
 type FluentBundle = fluent::bundle::FluentBundle<
@@ -7,9 +15,123 @@ type FluentBundle = fluent::bundle::FluentBundle<
     intl_memoizer::concurrent::IntlLangMemoizer,
 >;
 
-pub struct Translations {
+/// Locale of the main translation, used when a message is missing from the other ones
+const MAIN_LOCALE: &str = "en-US";
+
+/// Language files parsed from the `translations/` folder, replaced as a whole on reload
+struct Bundles {
     main: FluentBundle,
-    other: std::collections::HashMap<String, FluentBundle>,
+    other: HashMap<String, FluentBundle>,
+    /// Message identifiers of every language file, since bundles can't be iterated
+    ids: HashMap<String, BTreeSet<String>>,
+}
+
+pub struct Translations {
+    bundles: RwLock<Bundles>,
+    /// Languages chosen by users with `/language set`. Kept in memory, like the guild defaults,
+    /// because messages are translated synchronously.
+    users: RwLock<HashMap<UserId, String>>,
+    guilds: RwLock<HashMap<GuildId, String>>,
+}
+
+impl Translations {
+    /// Whether there is a translation file for the given locale
+    pub fn has_locale(&self, locale: &str) -> bool {
+        self.bundles.read().unwrap().other.contains_key(locale)
+    }
+
+    /// Every locale with a translation file
+    pub fn locales(&self) -> Vec<String> {
+        self.bundles.read().unwrap().other.keys().cloned().collect()
+    }
+
+    /// Reads the `translations/` folder again, keeping the current files if it fails. Command
+    /// names and descriptions are only registered on startup, so they aren't affected.
+    pub fn reload(&self) -> Result<(), Error> {
+        let bundles = read_bundles()?;
+        *self.bundles.write().unwrap() = bundles;
+        Ok(())
+    }
+
+    /// Loads the user preferences and guild defaults stored in the database
+    pub async fn load_locales(&self, database: &Database) -> Result<(), Error> {
+        let mut users = HashMap::new();
+        for model in database.get_user_locales().await? {
+            users.insert(UserId::new(model.user_id.parse()?), model.locale);
+        }
+
+        let mut guilds = HashMap::new();
+        for model in database.get_setting_values(Setting::Locale).await? {
+            guilds.insert(GuildId::new(model.guild_id.parse()?), model.value);
+        }
+
+        *self.users.write().unwrap() = users;
+        *self.guilds.write().unwrap() = guilds;
+        Ok(())
+    }
+
+    pub fn user_locale(&self, user_id: UserId) -> Option<String> {
+        self.users.read().unwrap().get(&user_id).cloned()
+    }
+
+    pub fn guild_locale(&self, guild_id: GuildId) -> Option<String> {
+        self.guilds.read().unwrap().get(&guild_id).cloned()
+    }
+
+    /// Updates the cached preference of a user, `None` clears it
+    pub fn set_user_locale(&self, user_id: UserId, locale: Option<String>) {
+        let mut users = self.users.write().unwrap();
+        match locale {
+            Some(locale) => users.insert(user_id, locale),
+            None => users.remove(&user_id),
+        };
+    }
+
+    /// Updates the cached default of a guild, `None` clears it
+    pub fn set_guild_locale(&self, guild_id: GuildId, locale: Option<String>) {
+        let mut guilds = self.guilds.write().unwrap();
+        match locale {
+            Some(locale) => guilds.insert(guild_id, locale),
+            None => guilds.remove(&guild_id),
+        };
+    }
+
+    /// Picks the language of a message: the user's preference, then the guild's default, then
+    /// the locale of the user's client. `None` means the main translation (en-US).
+    pub fn resolve_locale(
+        &self,
+        user_id: Option<UserId>,
+        guild_id: Option<GuildId>,
+        client_locale: Option<&str>,
+    ) -> Option<String> {
+        user_id
+            .and_then(|user_id| self.user_locale(user_id))
+            .or_else(|| guild_id.and_then(|guild_id| self.guild_locale(guild_id)))
+            .or_else(|| client_locale.map(str::to_owned))
+            .filter(|locale| self.has_locale(locale))
+    }
+
+    /// Retrieves the language file of the locale and calls [`format`], falling back on the main
+    /// translation
+    pub fn get(
+        &self,
+        locale: Option<&str>,
+        id: &str,
+        attr: Option<&str>,
+        args: Option<&fluent::FluentArgs<'_>>,
+    ) -> String {
+        let bundles = self.bundles.read().unwrap();
+        locale
+            // Try to get the language-specific translation
+            .and_then(|locale| format(bundles.other.get(locale)?, id, attr, args))
+            // Otherwise, fall back on main translation
+            .or_else(|| format(&bundles.main, id, attr, args))
+            // If this message ID is not present in any translation files whatsoever
+            .unwrap_or_else(|| {
+                tracing::warn!("unknown fluent message identifier `{}`", id);
+                id.to_string()
+            })
+    }
 }
 
 /// Macro to retrieve a translation, optionally with arguments. Use like:
@@ -28,6 +150,20 @@ macro_rules! tr {
 }
 pub(crate) use tr;
 
+/// Like [`tr!`], for messages sent outside of a command, like DMs and scheduled jobs. Takes the
+/// translations and a locale picked with [`Translations::resolve_locale`]:
+/// - `tr_in!(translations, locale, "identifier", arg1: VALUE1)`
+macro_rules! tr_in {
+    ( $translations:expr, $locale:expr, $id:expr $(, $argname:ident: $argvalue:expr )* $(,)? ) => {{
+        #[allow(unused_mut)]
+        let mut args = fluent::FluentArgs::new();
+        $( args.set(stringify!($argname), $argvalue); )*
+
+        $translations.get($locale, $id, None, Some(&args))
+    }};
+}
+pub(crate) use tr_in;
+
 /// Given a language file and message identifier, returns the translation
 pub fn format(
     bundle: &FluentBundle,
@@ -44,29 +180,56 @@ pub fn format(
     Some(formatted.into_owned())
 }
 
-/// Retrieves the appropriate language file depending on user locale and calls [`format`]
+/// Retrieves the appropriate language file for the author of the command and calls [`format`]
 pub fn get(
     ctx: Context,
     id: &str,
     attr: Option<&str>,
     args: Option<&fluent::FluentArgs<'_>>,
 ) -> String {
+    let locale = locale(ctx);
+    ctx.data()
+        .translations
+        .get(locale.as_deref(), id, attr, args)
+}
+
+/// Locale the author of the command is answered in, for use with [`tr_in!`]
+pub fn locale(ctx: Context) -> Option<String> {
     let translations = &ctx.data().translations;
-    ctx.locale()
-        // Try to get the language-specific translation
-        .and_then(|locale| format(translations.other.get(locale)?, id, attr, args))
-        // Otherwise, fall back on main translation
-        .or_else(|| format(&translations.main, id, attr, args))
-        // If this message ID is not present in any translation files whatsoever
-        .unwrap_or_else(|| {
-            tracing::warn!("unknown fluent message identifier `{}`", id);
-            id.to_string()
-        })
+    translations.resolve_locale(Some(ctx.author().id), ctx.guild_id(), ctx.locale())
+}
+
+/// Localized name of a punishment, like in `Punishment type: Ban`
+pub fn punishment_name(ctx: Context, punishment: &Punishment) -> String {
+    let punishment = format!("{:?}", punishment).to_lowercase();
+    tr!(ctx, "punishment-name", punishment: punishment)
+}
+
+/// Localized name of the action a case recorded
+pub fn case_action_name(ctx: Context, action: &CaseAction) -> String {
+    let action = format!("{:?}", action).to_lowercase();
+    tr!(ctx, "case-action-name", action: action)
+}
+
+/// Localized name of an infraction severity
+pub fn severity_name(ctx: Context, severity: &Severity) -> String {
+    let severity = format!("{:?}", severity).to_lowercase();
+    tr!(ctx, "severity-name", severity: severity)
 }
 
 /// Parses the `translations/` folder into a set of language files (FluentBundle)
 pub fn read_ftl() -> Result<Translations, Error> {
-    fn read_single_ftl(path: &std::path::Path) -> Result<(String, FluentBundle), Error> {
+    Ok(Translations {
+        bundles: RwLock::new(read_bundles()?),
+        users: RwLock::new(HashMap::new()),
+        guilds: RwLock::new(HashMap::new()),
+    })
+}
+
+fn read_bundles() -> Result<Bundles, Error> {
+    fn read_single_ftl(
+        path: &std::path::Path,
+    ) -> Result<(String, FluentBundle, BTreeSet<String>), Error> {
         // Extract locale from filename
         let locale = path.file_stem().ok_or("invalid .ftl filename")?;
         let locale = locale.to_str().ok_or("invalid filename UTF-8")?;
@@ -76,33 +239,179 @@ pub fn read_ftl() -> Result<Translations, Error> {
         let resource = fluent::FluentResource::try_new(file_contents)
             .map_err(|(_, e)| format!("failed to parse {:?}: {:?}", path, e))?;
 
+        let ids = resource
+            .entries()
+            .filter_map(|entry| match entry {
+                fluent_syntax::ast::Entry::Message(message) => Some(message.id.name.to_owned()),
+                _ => None,
+            })
+            .collect();
+
         // Associate .ftl resource with locale and bundle it
         let mut bundle = FluentBundle::new_concurrent(vec![locale
             .parse()
             .map_err(|e| format!("invalid locale `{}`: {}", locale, e))?]);
+        // Unicode isolation marks around arguments would end up inside mentions and code spans
+        bundle.set_use_isolating(false);
         bundle
             .add_resource(resource)
             .map_err(|e| format!("failed to add resource to bundle: {:?}", e))?;
 
-        Ok((locale.to_string(), bundle))
+        Ok((locale.to_string(), bundle, ids))
     }
 
-    Ok(Translations {
-        main: read_single_ftl("translations/en-US.ftl".as_ref())?.1,
-        other: std::fs::read_dir("translations")?
-            .map(|file| read_single_ftl(&file?.path()))
-            .collect::<Result<_, _>>()?,
+    let mut other = HashMap::new();
+    let mut ids = HashMap::new();
+
+    for file in std::fs::read_dir("translations")? {
+        let (locale, bundle, locale_ids) = read_single_ftl(&file?.path())?;
+        other.insert(locale.clone(), bundle);
+        ids.insert(locale, locale_ids);
+    }
+
+    if !ids.contains_key(MAIN_LOCALE) {
+        return Err(format!("missing main translation {MAIN_LOCALE}.ftl").into());
+    }
+
+    Ok(Bundles {
+        main: read_single_ftl(format!("translations/{MAIN_LOCALE}.ftl").as_ref())?.1,
+        other,
+        ids,
     })
 }
 
-/// Given a set of language files, fills in command strings and their localizations accordingly
+/// Compares every language file against the main one and the command tree, returning a line
+/// for each missing or extra message and attribute. Commands without an entry in the main
+/// translation keep their hardcoded names, so no translation is expected for them.
+pub fn check_translations(
+    translations: &Translations,
+    commands: &[poise::Command<Data, Error>],
+) -> Vec<String> {
+    let bundles = translations.bundles.read().unwrap();
+    let main_ids = &bundles.ids[MAIN_LOCALE];
+
+    let expected = commands
+        .iter()
+        .filter(|command| main_ids.contains(&command.name))
+        .map(|command| (&command.name, command_attributes(command)))
+        .collect::<Vec<_>>();
+
+    let mut locales = bundles.other.keys().collect::<Vec<_>>();
+    locales.sort();
+
+    let mut problems = vec![];
+
+    for locale in locales {
+        let bundle = &bundles.other[locale];
+        let ids = &bundles.ids[locale];
+
+        for id in main_ids.difference(ids) {
+            problems.push(format!("{locale}: missing message `{id}`"));
+        }
+
+        for id in ids.difference(main_ids) {
+            problems.push(format!("{locale}: extra message `{id}`"));
+        }
+
+        for (name, attributes) in expected.iter() {
+            let message = match bundle.get_message(name) {
+                Some(message) => message,
+                None => continue,
+            };
+
+            let found = message
+                .attributes()
+                .map(|attribute| attribute.id().to_owned())
+                .collect::<BTreeSet<_>>();
+
+            for attribute in attributes.difference(&found) {
+                problems.push(format!("{locale}: missing attribute `{name}.{attribute}`"));
+            }
+
+            for attribute in found.difference(attributes) {
+                problems.push(format!("{locale}: extra attribute `{name}.{attribute}`"));
+            }
+
+            // Discord refuses to register commands with longer descriptions
+            for attribute in found.iter().filter(|a| a.ends_with("description")) {
+                let description = format(bundle, name, Some(attribute), None).unwrap_or_default();
+
+                if description.chars().count() > 100 {
+                    problems.push(format!(
+                        "{locale}: attribute `{name}.{attribute}` is longer than 100 characters"
+                    ));
+                }
+            }
+        }
+    }
+
+    // Setting descriptions are looked up by key, so only this check notices a missing one
+    for setting in Setting::ALL {
+        let id = format!("setting-{}", setting.key());
+
+        if !main_ids.contains(&id) {
+            problems.push(format!("{MAIN_LOCALE}: missing message `{id}`"));
+        }
+    }
+
+    problems
+}
+
+/// Attributes [`apply_translations`] looks up for a command
+fn command_attributes(command: &poise::Command<Data, Error>) -> BTreeSet<String> {
+    fn add_parameter(
+        attributes: &mut BTreeSet<String>,
+        prefix: &str,
+        parameter: &poise::CommandParameter<Data, Error>,
+    ) {
+        attributes.insert(format!("{}{}", prefix, parameter.name));
+        attributes.insert(format!("{}{}-description", prefix, parameter.name));
+
+        for choice in parameter.choices.iter() {
+            attributes.insert(format!("{}{}-{}", prefix, parameter.name, choice.name));
+        }
+    }
+
+    let mut attributes = BTreeSet::from(["description".to_owned()]);
+
+    for parameter in command.parameters.iter() {
+        add_parameter(&mut attributes, "", parameter);
+    }
+
+    for subcommand in command.subcommands.iter() {
+        attributes.insert(subcommand.name.clone());
+        attributes.insert(format!("{}-description", subcommand.name));
+
+        for parameter in subcommand.parameters.iter() {
+            add_parameter(&mut attributes, &format!("{}-", subcommand.name), parameter);
+        }
+    }
+
+    attributes
+}
+
+/// Looks up a command attribute, warning about it instead of panicking if it's missing
+fn attribute(bundle: &FluentBundle, locale: &str, command: &str, attr: &str) -> Option<String> {
+    let value = format(bundle, command, Some(attr), None);
+
+    if value.is_none() {
+        println!("Missing translation `{}.{}` for {}", command, attr, locale);
+    }
+
+    value
+}
+
+/// Given a set of language files, fills in command strings and their localizations accordingly.
+/// Missing attributes are skipped, leaving the hardcoded strings in place.
 pub fn apply_translations(
     translations: &Translations,
     commands: &mut [poise::Command<Data, Error>],
 ) {
+    let bundles = translations.bundles.read().unwrap();
+
     for command in &mut *commands {
         // Add localizations
-        for (locale, bundle) in &translations.other {
+        for (locale, bundle) in &bundles.other {
             // Insert localized command name and description
             let localized_command_name = match format(bundle, &command.name, None, None) {
                 Some(x) => x,
@@ -111,101 +420,65 @@ pub fn apply_translations(
             command
                 .name_localizations
                 .insert(locale.clone(), localized_command_name);
-            command.description_localizations.insert(
-                locale.clone(),
-                format(bundle, &command.name, Some("description"), None).unwrap(),
-            );
+
+            if let Some(x) = attribute(bundle, locale, &command.name, "description") {
+                command.description_localizations.insert(locale.clone(), x);
+            }
 
             for subcommand in &mut command.subcommands {
-                subcommand.name_localizations.insert(
-                    locale.clone(),
-                    format(bundle, &command.name, Some(&subcommand.name), None).unwrap(),
-                );
-
-                subcommand.description_localizations.insert(
-                    locale.clone(),
-                    format(
-                        bundle,
-                        &command.name,
-                        Some(&format!("{}-description", subcommand.name)),
-                        None,
-                    )
-                    .unwrap(),
-                );
+                if let Some(x) = attribute(bundle, locale, &command.name, &subcommand.name) {
+                    subcommand.name_localizations.insert(locale.clone(), x);
+                }
+
+                let attr = format!("{}-description", subcommand.name);
+                if let Some(x) = attribute(bundle, locale, &command.name, &attr) {
+                    subcommand
+                        .description_localizations
+                        .insert(locale.clone(), x);
+                }
 
                 for parameter in &mut subcommand.parameters {
-                    parameter.name_localizations.insert(
-                        locale.clone(),
-                        format(
-                            bundle,
-                            &command.name,
-                            Some(&format!("{}-{}", subcommand.name, parameter.name)),
-                            None,
-                        )
-                        .unwrap(),
-                    );
-
-                    parameter.description_localizations.insert(
-                        locale.clone(),
-                        format(
-                            bundle,
-                            &command.name,
-                            Some(&format!(
-                                "{}-{}-description",
-                                subcommand.name, parameter.name
-                            )),
-                            None,
-                        )
-                        .unwrap(),
-                    );
+                    let attr = format!("{}-{}", subcommand.name, parameter.name);
+                    if let Some(x) = attribute(bundle, locale, &command.name, &attr) {
+                        parameter.name_localizations.insert(locale.clone(), x);
+                    }
+
+                    let attr = format!("{}-{}-description", subcommand.name, parameter.name);
+                    if let Some(x) = attribute(bundle, locale, &command.name, &attr) {
+                        parameter
+                            .description_localizations
+                            .insert(locale.clone(), x);
+                    }
 
                     for choice in &mut parameter.choices {
-                        choice.localizations.insert(
-                            locale.clone(),
-                            format(
-                                bundle,
-                                &command.name,
-                                Some(&format!(
-                                    "{}-{}-{}",
-                                    subcommand.name, parameter.name, choice.name
-                                )),
-                                None,
-                            )
-                            .unwrap(),
-                        );
+                        let attr =
+                            format!("{}-{}-{}", subcommand.name, parameter.name, choice.name);
+                        if let Some(x) = attribute(bundle, locale, &command.name, &attr) {
+                            choice.localizations.insert(locale.clone(), x);
+                        }
                     }
                 }
             }
 
             for parameter in &mut command.parameters {
                 // Insert localized parameter name and description
-                parameter.name_localizations.insert(
-                    locale.clone(),
-                    format(bundle, &command.name, Some(&parameter.name), None).unwrap(),
-                );
-                parameter.description_localizations.insert(
-                    locale.clone(),
-                    format(
-                        bundle,
-                        &command.name,
-                        Some(&format!("{}-description", parameter.name)),
-                        None,
-                    )
-                    .unwrap(),
-                );
+                if let Some(x) = attribute(bundle, locale, &command.name, &parameter.name) {
+                    parameter.name_localizations.insert(locale.clone(), x);
+                }
+
+                let attr = format!("{}-description", parameter.name);
+                if let Some(x) = attribute(bundle, locale, &command.name, &attr) {
+                    parameter
+                        .description_localizations
+                        .insert(locale.clone(), x);
+                }
 
                 // If this is a choice parameter, insert its localized variants
                 for choice in &mut parameter.choices {
-                    choice.localizations.insert(
-                        locale.clone(),
-                        format(
-                            bundle,
-                            &command.name,
-                            Some(&format!("{}-{}", parameter.name, choice.name)),
-                            None,
-                        )
-                        .unwrap(),
-                    );
+                    let attr = format!("{}-{}", parameter.name, choice.name);
+                    if let Some(x) = attribute(bundle, locale, &command.name, &attr) {
+                        choice.localizations.insert(locale.clone(), x);
+                    }
                 }
             }
         }
@@ -215,86 +488,76 @@ pub fn apply_translations(
         // we use the "main" translation file (en-US) as the non-localized strings.
 
         // Set fallback command name and description to en-US
-        let bundle = &translations.main;
+        let bundle = &bundles.main;
         match format(bundle, &command.name, None, None) {
             Some(x) => command.name = x,
             None => continue, // no localization entry => keep hardcoded names
         }
-        command.description =
-            Some(format(bundle, &command.name, Some("description"), None).unwrap());
+        if let Some(x) = attribute(bundle, MAIN_LOCALE, &command.name, "description") {
+            command.description = Some(x);
+        }
 
         for subcommand in &mut command.subcommands {
-            subcommand.name = format(bundle, &command.name, Some(&subcommand.name), None).unwrap();
-            subcommand.description = Some(
-                format(
-                    bundle,
-                    &command.name,
-                    Some(&format!("{}-description", subcommand.name)),
-                    None,
-                )
-                .unwrap(),
-            );
+            if let Some(x) = attribute(bundle, MAIN_LOCALE, &command.name, &subcommand.name) {
+                subcommand.name = x;
+            }
+
+            let attr = format!("{}-description", subcommand.name);
+            if let Some(x) = attribute(bundle, MAIN_LOCALE, &command.name, &attr) {
+                subcommand.description = Some(x);
+            }
 
             for parameter in &mut subcommand.parameters {
-                parameter.name = format(
-                    bundle,
-                    &command.name,
-                    Some(&format!("{}-{}", subcommand.name, parameter.name)),
-                    None,
-                )
-                .unwrap();
-
-                parameter.description = Some(
-                    format(
-                        bundle,
-                        &command.name,
-                        Some(&format!(
-                            "{}-{}-description",
-                            subcommand.name, parameter.name
-                        )),
-                        None,
-                    )
-                    .unwrap(),
-                );
+                let attr = format!("{}-{}", subcommand.name, parameter.name);
+                if let Some(x) = attribute(bundle, MAIN_LOCALE, &command.name, &attr) {
+                    parameter.name = x;
+                }
+
+                let attr = format!("{}-{}-description", subcommand.name, parameter.name);
+                if let Some(x) = attribute(bundle, MAIN_LOCALE, &command.name, &attr) {
+                    parameter.description = Some(x);
+                }
 
                 for choice in &mut parameter.choices {
-                    choice.name = format(
-                        bundle,
-                        &command.name,
-                        Some(&format!(
-                            "{}-{}-{}",
-                            subcommand.name, parameter.name, choice.name
-                        )),
-                        None,
-                    )
-                    .unwrap();
+                    let attr = format!("{}-{}-{}", subcommand.name, parameter.name, choice.name);
+                    if let Some(x) = attribute(bundle, MAIN_LOCALE, &command.name, &attr) {
+                        choice.name = x;
+                    }
                 }
             }
         }
 
         for parameter in &mut command.parameters {
             // Set fallback parameter name and description to en-US
-            parameter.name = format(bundle, &command.name, Some(&parameter.name), None).unwrap();
-            parameter.description = Some(
-                format(
-                    bundle,
-                    &command.name,
-                    Some(&format!("{}-description", parameter.name)),
-                    None,
-                )
-                .unwrap(),
-            );
+            if let Some(x) = attribute(bundle, MAIN_LOCALE, &command.name, &parameter.name) {
+                parameter.name = x;
+            }
+
+            let attr = format!("{}-description", parameter.name);
+            if let Some(x) = attribute(bundle, MAIN_LOCALE, &command.name, &attr) {
+                parameter.description = Some(x);
+            }
 
             // If this is a choice parameter, set the choice names to en-US
             for choice in &mut parameter.choices {
-                choice.name = format(
-                    bundle,
-                    &command.name,
-                    Some(&format!("{}-{}", parameter.name, choice.name)),
-                    None,
-                )
-                .unwrap();
+                let attr = format!("{}-{}", parameter.name, choice.name);
+                if let Some(x) = attribute(bundle, MAIN_LOCALE, &command.name, &attr) {
+                    choice.name = x;
+                }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translations_are_complete() {
+        let translations = read_ftl().expect("failed to read translation files");
+        let problems = check_translations(&translations, &crate::commands());
+
+        assert!(problems.is_empty(), "{}", problems.join("\n"));
+    }
+}
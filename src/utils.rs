@@ -1,7 +1,9 @@
 use std::str::FromStr;
 
 use regex::Regex;
+use serenity::all::HttpError;
 use serenity::model::id::UserId;
+use sqlx::types::chrono::{DateTime, Utc};
 
 pub fn user_ids_from(message: &str) -> Vec<UserId> {
     let re = Regex::new(r"(?P<id>[0-9]+)").unwrap();
@@ -11,3 +13,46 @@ pub fn user_ids_from(message: &str) -> Vec<UserId> {
         .map(|(u, _)| UserId::from_str(u).unwrap())
         .collect()
 }
+
+/// Discord error code of members that aren't in the guild
+pub const UNKNOWN_MEMBER: isize = 10007;
+
+/// Discord error code of users that aren't banned
+pub const UNKNOWN_BAN: isize = 10026;
+
+/// Whether a Discord request failed with one of the given error codes
+pub fn is_discord_error(error: &serenity::Error, codes: &[isize]) -> bool {
+    match error {
+        serenity::Error::Http(HttpError::UnsuccessfulRequest(response)) => {
+            codes.contains(&response.error.code)
+        }
+        _ => false,
+    }
+}
+
+/// Time `seconds` from now, or `None` if it's beyond the dates that can be represented
+pub fn from_now(seconds: i64) -> Option<DateTime<Utc>> {
+    let timestamp = Utc::now().timestamp().checked_add(seconds)?;
+
+    DateTime::from_timestamp(timestamp, 0)
+}
+
+/// Formats seconds as a compact duration, e.g. `1d 2h 30m`
+pub fn format_duration(seconds: i64) -> String {
+    let units = [("d", 60 * 60 * 24), ("h", 60 * 60), ("m", 60), ("s", 1)];
+    let mut remaining = seconds;
+    let mut parts = vec![];
+
+    for (suffix, size) in units {
+        if remaining >= size {
+            parts.push(format!("{}{}", remaining / size, suffix));
+            remaining %= size;
+        }
+    }
+
+    if parts.is_empty() {
+        return "0s".to_owned();
+    }
+
+    parts.join(" ")
+}
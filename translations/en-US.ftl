@@ -27,8 +27,11 @@ infractions = infractions
      .add-punishment-Timeout = Timeout user
      .add-punishment-Ban = Ban user
      .add-punishment-Kick = Kick user
+     .add-punishment-Quarantine = Quarantine user
      .add-duration = duration
      .add-duration-description = Duration of the timeout
+     .add-expires_after = expires_after
+     .add-expires_after-description = Days until the infraction stops counting towards escalations
      .list = list
      .list-description = Infraction table
      .remove = remove
@@ -54,8 +57,63 @@ infractions = infractions
      .edit-punishment-Timeout = Timeout user
      .edit-punishment-Ban = Ban user
      .edit-punishment-Kick = Kick user
+     .edit-punishment-Quarantine = Quarantine user
      .edit-duration = duration
      .edit-duration-description = Duration of the timeout
+     .edit-expires_after = expires_after
+     .edit-expires_after-description = Days until the infraction stops counting towards escalations
+     .pardon = pardon
+     .pardon-description = Pardon a single infraction record of a user
+     .pardon-id = id
+     .pardon-id-description = ID of the user infraction record
+
+config = config
+     .description = Server configuration
+     .get = get
+     .get-description = Show the value of a setting
+     .get-key = key
+     .get-key-description = Name of the setting
+     .set = set
+     .set-description = Change the value of a setting
+     .set-key = key
+     .set-key-description = Name of the setting
+     .set-value = value
+     .set-value-description = New value of the setting
+     .reset = reset
+     .reset-description = Reset a setting to its default value
+     .reset-key = key
+     .reset-key-description = Name of the setting
+     .list = list
+     .list-description = List every setting and its value
+
+escalation = escalation
+     .description = Automatic escalation of repeat infractions
+     .add = add
+     .add-description = Punish members automatically once they reach a number of infractions (direct strikes count as Low)
+     .add-severity = severity
+     .add-severity-description = Severity of the counted infractions
+     .add-severity-Low = Low severity
+     .add-severity-Mid = Mid severity
+     .add-severity-High = High severity
+     .add-threshold = threshold
+     .add-threshold-description = Number of infractions that triggers the escalation
+     .add-days = days
+     .add-days-description = Period in days in which the infractions are counted
+     .add-punishment = punishment
+     .add-punishment-description = Escalated punishment
+     .add-punishment-Strike = Strike user
+     .add-punishment-Timeout = Timeout user
+     .add-punishment-Ban = Ban user
+     .add-punishment-Kick = Kick user
+     .add-punishment-Quarantine = Quarantine user
+     .add-duration = duration
+     .add-duration-description = Duration of the timeout or ban in seconds (0 for a permanent ban)
+     .list = list
+     .list-description = Escalation table
+     .remove = remove
+     .remove-description = Remove escalation from the table
+     .remove-id = id
+     .remove-id-description = ID of the escalation to be removed
 
 kick = kick
      .description = Kick users
@@ -76,6 +134,8 @@ timeout = timeout
      .unit-Minutes = Minutes (time * 60)
      .unit-Hours = Hours (time * 60 * 60)
      .unit-Days = Days (time * 60 * 60 * 24)
+     .reason = reason
+     .reason-description = Reason to time out users
 
 untimeout = untimeout
      .description = Remove time out from users
@@ -88,6 +148,14 @@ ban = ban
      .users-description = Users to be banned
      .reason = reason
      .reason-description = Reason to ban users
+     .time = time
+     .time-description = Ban duration in numbers (leave empty for a permanent ban)
+     .unit = unit
+     .unit-description = Unit of time
+     .unit-Seconds = Seconds (time * 1)
+     .unit-Minutes = Minutes (time * 60)
+     .unit-Hours = Hours (time * 60 * 60)
+     .unit-Days = Days (time * 60 * 60 * 24)
 
 unban = unban
      .description = Unban users from the server
@@ -110,6 +178,95 @@ punish = punish
      .reason = reason
      .reason-description = Reason to punish users
 
+case = case
+     .description = Moderation cases
+     .view = view
+     .view-description = Show a moderation case
+     .view-id = id
+     .view-id-description = Case number
+     .reason = reason
+     .reason-description = Edit the reason of a moderation case
+     .reason-id = id
+     .reason-id-description = Case number
+     .reason-reason = reason
+     .reason-reason-description = New reason of the case
+     .delete = delete
+     .delete-description = Delete a moderation case and its record
+     .delete-id = id
+     .delete-id-description = Case number
+
+history = history
+     .description = Show the full moderation history of a member
+     .member = member
+     .member-description = Member of the guild
+
+lockdown = lockdown
+     .description = Server-wide lockdown
+     .start = start
+     .start-description = Lock every lockdown channel and pause invites
+     .start-slowmode = slowmode
+     .start-slowmode-description = Slowmode in seconds applied to the locked channels
+     .start-reason = reason
+     .start-reason-description = Reason shown in the locked channels
+     .end = end
+     .end-description = End the lockdown and restore every channel
+
+filter = filter
+     .description = Automod word and regex filters
+     .add = add
+     .add-description = Block a word or a regex pattern
+     .add-kind = kind
+     .add-kind-description = Whether the pattern is a whole word or a regex
+     .add-kind-Word = Word
+     .add-kind-Regex = Regex
+     .add-pattern = pattern
+     .add-pattern-description = Word or regex pattern to block
+     .list = list
+     .list-description = List the filters of the server
+     .remove = remove
+     .remove-description = Remove a filter
+     .remove-id = id
+     .remove-id-description = Filter ID
+
+allowlist = allowlist
+     .description = Domains and invites allowed by the link filter
+     .add = add
+     .add-description = Allow a domain or a Discord invite
+     .add-kind = kind
+     .add-kind-description = Whether the value is a domain or an invite
+     .add-kind-Domain = Domain
+     .add-kind-Invite = Invite
+     .add-value = value
+     .add-value-description = Domain, link, invite link or invite code to allow
+     .list = list
+     .list-description = List the allowlist of the server
+     .remove = remove
+     .remove-description = Remove an allowlist entry
+     .remove-id = id
+     .remove-id-description = Allowlist entry ID
+
+language = language
+     .description = Language of the bot's responses
+     .set = set
+     .set-description = Choose the language the bot answers you in
+     .set-locale = locale
+     .set-locale-description = Language, like en-US
+     .reset = reset
+     .reset-description = Go back to the server's or your client's language
+     .server = server
+     .server-description = Choose the default language of the server
+     .server-locale = locale
+     .server-locale-description = Language, like en-US
+
+schedule = schedule
+     .description = Scheduled moderation actions
+     .list = list
+     .list-description = List pending scheduled actions
+     .cancel = cancel
+     .cancel-description = Cancel a pending scheduled action
+     .cancel-id = id
+     .cancel-id-description = ID of the scheduled action
+
 tag = tag
     .description = Tag command
     .add = add
@@ -159,4 +316,557 @@ emoji = emoji
     .remove-emoji-description = Emoji to be removed
 
 # Responses
-Pong = Pong! :ping_pong:
\ No newline at end of file
+Pong = Pong! :ping_pong:
+
+## Shared
+punishment-name = { $punishment ->
+    [ban] Ban
+    [kick] Kick
+    [timeout] Timeout
+    [quarantine] Quarantine
+   *[strike] Strike
+}
+severity-name = { $severity ->
+    [low] Low
+    [mid] Mid
+   *[high] High
+}
+case-action-name = { $action ->
+    [kick] Kick
+    [ban] Ban
+    [unban] Unban
+    [timeout] Timeout
+    [untimeout] Untimeout
+    [quarantine] Quarantine
+   *[strike] Strike
+}
+
+## Moderation
+moderation-no-users = You must provide at least 1 valid user mention or user ID.
+moderation-higher-role = One of the users have a role higher than yours.
+moderation-foreign-channel = :warning: { $channel } isn't a channel of this server!
+moderation-dm-failed = :envelope: **Couldn't DM { $count } { $count ->
+    [one] member
+   *[other] members
+} about the punishment:** { $users }
+kick-success = :white_check_mark: **Successfully kicked { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+kick-failed = :warning: **Failed to kick { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+kick-reason = :information: **Kick reason:** { $reason }
+kick-error = Failed to execute kick command!
+timeout-success = :white_check_mark: **Successfully timed out { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+timeout-failed = :warning: **Failed to time out { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+timeout-duration = :information: **Time out duration:** { $duration }
+timeout-mute-role = :information: **Mechanism:** Mute role { $role }
+timeout-discord = :information: **Mechanism:** Discord timeout
+timeout-clamped = :warning: Discord timeouts can't be longer than 28 days, configure a `mute_role` to mute for longer.
+timeout-reason = :information: **Time out reason:** { $reason }
+timeout-invalid-duration = :warning: That duration is too long.
+untimeout-success = :white_check_mark: **Successfully removed the time out of { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+untimeout-failed = :warning: **Failed to remove the time out of { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+untimeout-error = Failed to execute untimeout command!
+ban-invalid-duration = You must provide a positive `time` together with its `unit` for a temporary ban, ending within the supported dates.
+ban-success = :white_check_mark: **Successfully banned { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+ban-failed = :warning: **Failed to ban { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+ban-duration = :information: **Ban duration:** { $duration }
+ban-reason = :information: **Ban reason:** { $reason }
+ban-error = Failed to execute ban command!
+unban-success = :white_check_mark: **Successfully unbanned { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+unban-failed = :warning: **Failed to unban { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+unban-error = Failed to execute unban command!
+strike-success = :white_check_mark: **Successfully struck { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+strike-failed = :warning: **Failed to strike { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+strike-reason = :information: **Strike reason:** { $reason }
+strike-error = Failed to execute strike command!
+punish-unknown-infraction = This infraction ID doesn't exist!
+punish-success = :white_check_mark: **Successfully punished { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+punish-failed = :warning: **Failed to punish { $count } { $count ->
+    [one] member
+   *[other] members
+}:** { $users }
+punish-type = :information: **Punishment type:** { $punishment }
+punish-duration = :information: **Punishment duration:** { $duration }
+punish-reason = :information: **Punishment reason:** { $reason }
+escalation-reason = Automatic escalation: { $count } { $severity } { $count ->
+    [one] infraction
+   *[other] infractions
+} within { $days } { $days ->
+    [one] day
+   *[other] days
+}
+escalation-success = :arrow_double_up: **Escalated** { $user } to `{ $punishment }` ({ $reason })
+escalation-failed = :warning: **Failed to escalate** { $user } to `{ $punishment }` ({ $reason })
+slowmode-failed = :x: Failed to enable slowmode on channel { $channel }!
+slowmode-success = :white_check_mark: Slowmode of `{ $seconds }` { $seconds ->
+    [one] second
+   *[other] seconds
+} enabled with success on channel { $channel }!
+slowmode-reverts = :information: **Slowmode reverts:** { $time }
+slowmode-schedule-failed = :warning: Failed to schedule slowmode revert!
+lock-already = :warning: Channel { $channel } is already locked!
+lock-failed = :x: Failed to lock channel { $channel }!
+lock-success = :white_check_mark: Channel { $channel } locked with success!
+lock-unlocks = :information: **Channel unlocks:** { $time }
+lock-schedule-failed = :warning: Failed to schedule channel unlock!
+unlock-not-locked = :warning: Channel { $channel } isn't locked!
+unlock-failed = :x: Failed to unlock channel { $channel }!
+unlock-success = :white_check_mark: Channel { $channel } unlocked with success!
+clear-invalid-pattern = :warning: Invalid regex pattern!
+clear-failed = :x: Failed to delete messages!
+clear-not-found = :warning: No matching messages found!
+clear-too-old = :information: Messages older than 14 days can't be deleted in bulk.
+clear-success = :white_check_mark: `{ $count }` { $count ->
+    [one] message was
+   *[other] messages were
+} deleted from the channel { $channel }!
+clear-authors = :information: **Authors:** { $authors }
+clear-stopped = :information: Stopped at messages older than 14 days, which can't be deleted in bulk.
+clear-interrupted = :warning: Failed to delete the remaining messages, try again to delete them.
+clear-archived = :information: The messages were archived as `#{ $archive }`.
+
+case-not-found = :warning: Case `#{ $case }` doesn't exist!
+case-details =
+    :information_source: Case: #{ $case }
+    :information_source: Action: { $action }
+    :information_source: User: { $user }
+    :information_source: Moderator: { $moderator }
+    :information_source: Reason: { $reason }
+case-no-reason = No reason provided
+case-created = :information_source: Created At: { $time }
+case-duration = :information_source: Duration: { $seconds } { $seconds ->
+    [one] second
+   *[other] seconds
+}
+case-expires = :information_source: Expires At: { $time }
+case-infraction = :information_source: Infraction ID: { $id }
+case-reason-updated = :white_check_mark: Reason of case `#{ $case }` updated!
+case-deleted = :white_check_mark: Case `#{ $case }` deleted!
+case-delete-failed = :x: Failed to delete case `#{ $case }`!
+history-failed = :x: Failed to retrieve user history!
+history-clean = :white_check_mark: { $user } has a clean history!
+history-header = **Moderation history of { $user }** ({ $count } { $count ->
+    [one] record
+   *[other] records
+})
+history-case = Case `#{ $case }`
+history-no-case = No case
+history-unknown = Unknown
+history-title = - { $case } | Punishment: `{ $punishment }`
+history-pardoned = {" | "}`Pardoned`
+history-expired = {" | "}`Expired`
+history-infraction = {"  "}Record ID: `{ $record }` | Infraction ID: `{ $infraction }` | Severity: `{ $severity }`
+history-reason = {"  "}Reason: { $reason }
+history-moderator = {"  "}Moderator: { $moderator }
+history-duration = {"  "}Duration: `{ $seconds }` { $seconds ->
+    [one] second
+   *[other] seconds
+}
+history-date = {"  "}Date: { $time }
+history-expires = {"  "}Expires: { $time }
+lockdown-already = :warning: The server is already in lockdown!
+lockdown-start-failed = :x: Failed to start the lockdown!
+lockdown-locked = :white_check_mark: **Successfully locked channels:** { $channels }
+lockdown-lock-failed = :warning: **Failed to lock channels:** { $channels }
+lockdown-reason = :information: **Lockdown reason:** { $reason }
+lockdown-nothing-locked = :information: Lockdown started, but there were no channels to lock.
+lockdown-modlog-start = `{ $locked }` { $locked ->
+    [one] channel
+   *[other] channels
+} locked, `{ $failed }` failed
+lockdown-notice-start = :lock: **This server is in lockdown.**
+lockdown-notice-start-reason = :lock: **This server is in lockdown.** { $reason }
+lockdown-notice-end = :unlock: **The lockdown has ended.**
+lockdown-not-active = :warning: The server isn't in lockdown!
+lockdown-end-failed = :x: Failed to end the lockdown!
+lockdown-restored = :white_check_mark: **Successfully restored channels:** { $channels }
+lockdown-restore-failed = :warning: **Failed to restore channels:** { $channels }
+lockdown-nothing-restored = :information: Lockdown ended, there were no channels to restore.
+lockdown-modlog-end = `{ $restored }` { $restored ->
+    [one] channel
+   *[other] channels
+} restored, `{ $failed }` failed
+
+## Infractions
+infractions-exists = :warning: Infraction ID `{ $id }` already exists!
+infractions-created = :white_check_mark: Infraction created!
+infractions-create-failed = :x: Failed to create infraction ID `{ $id }`!
+infractions-empty = :x: No infractions found!
+infractions-line = - ID: `{ $id }` | Severity: `{ $severity }` | Punishment: `{ $punishment }` | Duration: `{ $duration }` | Expires After: `{ $expires }`
+infractions-details =
+    :information_source: ID: { $id }
+    :information_source: Severity: { $severity }
+    :information_source: Punishment: { $punishment }
+    :information_source: Duration: { $duration }
+    :information_source: Expires After: { $expires }
+infractions-expires-days = { $days } { $days ->
+    [one] day
+   *[other] days
+}
+infractions-never = Never
+infractions-not-found = :warning: There is no infraction ID `{ $id }`!
+infractions-removed = :white_check_mark: { $count ->
+    [one] Infraction
+   *[other] Infractions
+} ID `{ $id }` removed successfully!
+infractions-remove-failed = :x: Failed to remove infraction ID `{ $id }`!
+infractions-updated = :white_check_mark: Infraction ID `{ $id }` updated with success!
+infractions-edit-failed = :x: Failed to edit infraction ID `{ $id }`!
+infractions-banned-until = :information_source: Banned until { $time }
+infractions-user-empty = :x: User has no infractions!
+infractions-record = - ID: `{ $id }` | User ID: `{ $user }` | Infraction ID: `{ $infraction }` | Created At: `{ $created }`
+infractions-record-expires = Expires At: `{ $expires }`
+infractions-record-pardoned = `Pardoned`
+infractions-pardon-not-found = :warning: There is no unpardoned infraction record ID `{ $id }`!
+infractions-pardoned = :white_check_mark: Infraction record ID `{ $id }` pardoned!
+infractions-pardon-failed = :x: Failed to pardon infraction record ID `{ $id }`!
+
+escalation-created = :white_check_mark: Escalation created!
+escalation-create-failed = :x: Failed to create escalation! There may already be one with this severity and threshold.
+escalation-empty = :x: No escalations found!
+escalation-line = - ID: `{ $id }` | `{ $threshold }` { $severity } { $threshold ->
+    [one] infraction
+   *[other] infractions
+} within `{ $days }` { $days ->
+    [one] day
+   *[other] days
+} | Punishment: `{ $punishment }` | Duration: `{ $duration }`
+escalation-not-found = :warning: There is no escalation ID `{ $id }`!
+escalation-removed = :white_check_mark: Escalation ID `{ $id }` removed successfully!
+escalation-remove-failed = :x: Failed to remove escalation ID `{ $id }`!
+
+## Tags
+tag-exists = :warning: Tag `{ $name }` already exists!
+tag-created = :white_check_mark: Tag `{ $name }` created with success!
+tag-create-failed = :x: Cannot create tag `{ $name }`!
+tag-edit-failed = :x: Tag `{ $name }` doesn't exist or you're not the owner of it!
+tag-updated = :white_check_mark: Content of the tag `{ $name }` updated successfully!
+tag-not-found = :x: Tag `{ $name }` doesn't exist!
+tag-server-empty = :x: Server has no tags!
+tag-user-empty = :x: User has no tags!
+tag-empty = :x: No tags!
+tag-deleted = :white_check_mark: Tag `{ $name }` deleted!
+tag-not-owner = :x: You're not the owner of the tag `{ $name }`!
+
+## Emoji
+emoji-create-failed = :x: Failed to create emoji `{ $name }`
+emoji-created = :white_check_mark: Emoji created: { $emoji }
+emoji-empty = :warning: Server has no emojis!
+emoji-list-failed = :x: Failed to retrieve server emojis!
+emoji-delete-failed = :x: Failed to delete emoji `{ $name }`
+emoji-deleted = :white_check_mark: Emoji `{ $name }` deleted with success!
+
+## Stickers
+sticker-create-failed = :x: Failed to create sticker `{ $name }`!
+sticker-created = :white_check_mark: Sticker `{ $name }` created with success!
+sticker-name-or-tags = :warning: Must provide `name` or `tags`!
+sticker-empty = :warning: Guild has no stickers!
+sticker-not-found = :warning: No sticker found!
+sticker-remove-failed = :x: Failed to remove sticker `{ $name }`!
+sticker-removed = :white_check_mark: Sticker `{ $name }` removed successfully!
+sticker-message-empty = :x: No sticker found in the message!
+sticker-image-failed = :x: Failed to retrieve sticker image format!
+
+## Bang
+animal-added = New animal added: { $emoji } `{ $animal }` which is equivalent to `{ $points } { $points ->
+    [one] point
+   *[other] points
+}`!
+animal-add-failed = Failed to add new animal: `{ $animal }`!
+animal-details =
+    Animal: `{ $animal }`
+    Emoji: `{ $emoji }`
+    Points: `{ $points }`
+animal-see-failed = Failed to retrieve animal `{ $animal }`!
+animal-removed = { $count ->
+    [0] No animal removed!
+    [one] Animal removed successfully!
+   *[other] Animals removed successfully!
+}
+animal-remove-failed = Failed to remove animal: `{ $animal }`
+animal-empty = No animals!
+animal-line = - `{ $animal }` | `{ $emoji }` | `{ $points }` { $points ->
+    [one] point
+   *[other] points
+}
+bang-appeared = { $emoji } A wild { $animal } appeared!
+bang-no-channel = There is no bang channel configured! Please provide a channel.
+bang-no-animals = There are no animals to shoot! Add some with `/animal add` first.
+bang-started = Bang mini-game started at channel: { $channel }
+bang-shot = Nice! You just shot a { $emoji } { $animal } and gained `{ $points }` { $points ->
+    [one] point
+   *[other] points
+}!
+bang-failed = Failed to update user points! Stopping bang minigame...
+bang-unavailable = Bang isn't available yet!
+bang-stopped = Bang mini-game stopped!
+bang-ranking-empty = No one is ranked!
+bang-ranking-line = - `{ $user }` | `{ $points }` { $points ->
+    [one] point
+   *[other] points
+}
+
+## Language
+language-unknown = :warning: There is no translation for `{ $locale }`!
+language-set = :white_check_mark: I'll answer you in `{ $locale }` from now on!
+language-set-failed = :x: Failed to save your language!
+language-reset = :white_check_mark: Your language was reset, I'll follow the server's or your client's language.
+language-reset-none = :warning: You haven't chosen a language!
+language-reset-failed = :x: Failed to reset your language!
+language-server = :white_check_mark: The default language of the server is now `{ $locale }`!
+language-server-failed = :x: Failed to update the language of the server!
+
+## Scheduled actions
+scheduler-ban-expired = Temporary ban expired
+scheduler-mute-expired = Mute expired
+scheduler-slowmode-reverted = Slowmode reverted to `{ $seconds }` { $seconds ->
+    [one] second
+   *[other] seconds
+}
+scheduler-lock-expired = Lock duration expired
+
+schedule-empty = :x: No scheduled actions!
+schedule-list-failed = :x: Failed to retrieve scheduled actions!
+schedule-line = - ID: `{ $id }` | Action: `{ $action }` | Target: { $target } | Moderator: { $moderator } | Runs: { $time }
+schedule-action-name = { $action ->
+    [unban] Unban
+    [unmute] Unmute
+    [slowmode] Slowmode
+   *[unlock] Unlock
+}
+schedule-not-found = :warning: There is no scheduled action ID `{ $id }`!
+schedule-cancelled = :white_check_mark: Scheduled action ID `{ $id }` cancelled!
+schedule-cancel-failed = :x: Failed to cancel scheduled action ID `{ $id }`!
+
+## Configuration
+config-unknown = :warning: There is no setting named `{ $key }`!
+config-get-failed = :x: Failed to retrieve setting `{ $key }`!
+config-list-failed = :x: Failed to retrieve settings!
+config-invalid = :warning: Invalid value for `{ $key }`! { $error }
+config-foreign-channel = :warning: { $channel } isn't a channel of this server!
+config-foreign-role = :warning: `{ $role }` isn't a role of this server!
+config-set = :white_check_mark: Setting `{ $key }` updated!
+config-set-failed = :x: Failed to update setting `{ $key }`!
+config-reset = :white_check_mark: Setting `{ $key }` reset!
+config-reset-default = :warning: Setting `{ $key }` is already using its default value!
+config-reset-failed = :x: Failed to reset setting `{ $key }`!
+config-default = { $value } (default)
+config-not-set = Not set
+config-invalid-empty = The value can't be empty!
+config-invalid-too-long = The value can't be longer than { $max } characters!
+config-invalid-spaces = The prefix can't contain spaces!
+config-invalid-placeholder = The only placeholders are: { $placeholders }
+config-invalid-link = The value must be a link starting with `https://`!
+config-invalid-locale = The value must be a locale like `en-US`!
+config-invalid-channel = The value must be a channel mention or ID!
+config-invalid-channels = The value must be a list of channel mentions or IDs!
+config-invalid-role = The value must be a role mention or ID!
+config-invalid-roles = The value must be a list of role mentions or IDs!
+config-invalid-flag = The value must be `true` or `false`!
+config-invalid-pattern = The value must be a valid regex!
+config-invalid-number = The value must be a number from { $min } to { $max }!
+config-invalid-choice = The value must be one of: { $choices }
+setting-prefix = Prefix for text commands
+setting-locale = Default language of the bot's responses
+setting-mod_log_channel = Channel moderation events are logged to
+setting-mod_roles = Roles considered part of the staff team
+setting-bang_channel = Default channel for the bang mini-game
+setting-strike_message = DM sent to members when they receive a strike
+setting-kick_message = DM sent to members before they are kicked
+setting-ban_message = DM sent to members before they are banned
+setting-timeout_message = DM sent to members when they are timed out
+setting-quarantine_message = DM sent to members when they are quarantined
+setting-infraction_message = DM sent to members punished for an infraction, instead of the punishment's DM
+setting-appeal_link = Link shown in punishment DMs for appealing them
+setting-appeal_channel = Channel appeals are posted to for review, appeal buttons are hidden if unset
+setting-lockdown_channels = Channels locked by a lockdown, all text channels if unset
+setting-purge_transcripts = Upload a transcript of purged messages to the mod-log
+setting-antiraid_threshold = Joins within the anti-raid window that count as a raid, disabled if unset
+setting-antiraid_window = Length of the anti-raid window in seconds
+setting-antiraid_action = What to do when a raid is detected
+setting-antiraid_account_age = Accounts younger than this many days are timed out or kicked during a raid
+setting-quarantine_role = Role assigned to quarantined members
+setting-gate_account_age = Minimum account age in days to pass the join gate
+setting-gate_default_avatar = Stop members without a custom avatar at the join gate
+setting-gate_username_pattern = Regex of usernames stopped at the join gate
+setting-gate_action = What to do with members stopped at the join gate
+setting-automod_action = What to do after deleting a message caught by the automod
+setting-automod_timeout = Length in seconds of timeouts issued by the automod
+setting-automod_exempt_roles = Roles ignored by the automod, besides the staff roles
+setting-automod_exempt_channels = Channels ignored by the automod
+setting-spam_filter = Delete spam and punish the members sending it
+setting-spam_window = Length of the spam window in seconds
+setting-spam_messages = Messages within the spam window that count as a flood
+setting-spam_duplicates = Identical messages within the spam window that count as spam
+setting-spam_mentions = Mentions in a single message that count as spam
+setting-spam_emojis = Emojis in a single message that count as spam
+setting-spam_infraction = Infraction ID logged for spam, the automod action is used if unset
+setting-link_filter = Block invites only, or every link outside the allowlist
+setting-mute_role = Role used for timeouts longer than Discord's 28 day limit
+
+## Mod-log
+modlog-slowmode = Slowmode of `{ $seconds }` { $seconds ->
+    [one] second
+   *[other] seconds
+}
+modlog-infraction = Punished for infraction ID `{ $id }`
+modlog-action = { $action ->
+    [kick] Kick
+    [ban] Ban
+    [unban] Unban
+    [timeout] Timeout
+    [untimeout] Untimeout
+    [strike] Strike
+    [quarantine] Quarantine
+    [lock] Lock
+    [unlock] Unlock
+    [slowmode] Slowmode
+    [clear] Clear
+    [lockdownstart] Lockdown started
+    [lockdownend] Lockdown ended
+    [raid] Raid
+   *[automod] Automod
+}
+modlog-case-title = { $action } | Case #{ $case }
+modlog-target = Target
+modlog-moderator = Moderator
+modlog-reason = Reason
+modlog-duration = Duration
+modlog-details = Details
+modlog-no-reason = No reason provided
+modlog-entire-server = Entire server
+modlog-clear = `{ $count }` { $count ->
+    [one] message
+   *[other] messages
+} deleted, archived as `#{ $archive }`
+
+## Automod
+automod-deleted = Message deleted in { $channel }
+automod-warning = { $user } :warning: Your message was removed: { $reason }
+automod-blocked-word = Blocked word `{ $word }`
+automod-blocked-pattern = Matched a blocked pattern
+automod-invite = Discord invite `{ $code }`
+automod-link = Link to `{ $domain }`
+automod-mention-spam = Mention spam: { $count } mentions
+automod-emoji-spam = Emoji spam: { $count } emojis
+automod-flood = Flood: { $count } messages within { $seconds } { $seconds ->
+    [one] second
+   *[other] seconds
+}
+automod-repeated = Repeated the same message { $count } times
+automod-spam-deleted = `{ $count }` { $count ->
+    [one] message
+   *[other] messages
+} deleted in { $channel }
+automod-raid-reason = Anti-raid: joined during a raid
+automod-raid-lockdown-reason = Anti-raid: join rate exceeded the threshold
+automod-raid-detected = Join rate exceeded the anti-raid threshold
+automod-raid-details =
+    `{ $count }` members joined within `{ $seconds }` seconds
+    Action: `{ $action }`
+gate-reason = Join gate: { $checks }
+gate-account-age = account younger than { $days } { $days ->
+    [one] day
+   *[other] days
+}
+gate-default-avatar = default avatar
+gate-username = username matches the blocked pattern
+
+filter-empty-pattern = :warning: The pattern can't be empty!
+filter-invalid-regex = :warning: Invalid regex pattern!
+filter-created = :white_check_mark: Filter created!
+filter-create-failed = :x: Failed to create filter! It may already exist.
+filter-empty = :x: No filters found!
+filter-list-failed = :x: Failed to retrieve filters!
+filter-line = - ID: `{ $id }` | { $kind ->
+    [regex] Regex
+   *[word] Word
+}: `{ $pattern }` | Added by { $user }
+filter-not-found = :warning: There is no filter ID `{ $id }`!
+filter-removed = :white_check_mark: Filter ID `{ $id }` removed successfully!
+filter-remove-failed = :x: Failed to remove filter ID `{ $id }`!
+
+## Translations
+translations-reloaded = :white_check_mark: Reloaded { $count } { $count ->
+    [one] language
+   *[other] languages
+}! Command names and descriptions are only updated on restart.
+translations-reload-failed = :x: Failed to reload the translations, keeping the current ones: { $error }
+
+## Punishment DMs
+dm-kick = :boot: You were kicked from **{ $guild }**.
+dm-ban = :hammer: You were banned from **{ $guild }**.
+dm-timeout = :mute: You were timed out in **{ $guild }**.
+dm-strike = :warning: You received a strike in **{ $guild }**.
+dm-quarantine = :lock: You were quarantined in **{ $guild }**.
+dm-infraction = :scales: You were punished in **{ $guild }** for breaking a rule.
+dm-duration = **Duration:** { $duration }
+dm-reason = **Reason:** { $reason }
+dm-case = **Case:** #{ $case }
+dm-appeal = You can appeal this punishment at { $appeal }
+dm-permanent = permanent
+dm-appeal-button = Appeal
+
+## Appeals
+appeal-unknown-case = :warning: This case doesn't exist anymore or isn't yours to appeal!
+appeal-form-title = Appeal of case #{ $case }
+appeal-form-label = Why should the punishment be lifted?
+appeal-disabled = :warning: This server doesn't accept appeals anymore.
+appeal-already = :warning: You already appealed this case!
+appeal-failed = :x: Failed to send your appeal, try again later!
+appeal-sent = :white_check_mark: Your appeal was sent to the staff, you'll get a DM once it's reviewed.
+appeal-title = :scales: Appeal of case #{ $case }
+appeal-field-member = Member
+appeal-field-action = Punishment
+appeal-field-reason = Reason
+appeal-field-appeal = Appeal
+appeal-field-status = Status
+appeal-no-reason = No reason provided
+appeal-accept = Accept
+appeal-deny = Deny
+appeal-not-allowed = :warning: Only the staff can review appeals!
+appeal-reviewed-already = :warning: This appeal was already reviewed!
+appeal-reversal-failed = :x: Failed to lift the punishment, the appeal is still open!
+appeal-status-accepted = :white_check_mark: Accepted by { $reviewer }
+appeal-status-denied = :x: Denied by { $reviewer }
+appeal-dm-failed = :envelope: The member couldn't be told by DM.
+appeal-reversal-reason = Appeal of case #{ $case } accepted
+appeal-accepted = :white_check_mark: Your appeal of case #{ $case } in **{ $guild }** was accepted, the punishment was lifted.
+appeal-denied = :x: Your appeal of case #{ $case } in **{ $guild }** was denied.
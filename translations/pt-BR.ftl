@@ -27,8 +27,11 @@ infractions = infractions
     .add-punishment-Timeout = Mutar o usuário
     .add-punishment-Ban = Banimento
     .add-punishment-Kick = Expulsar
+    .add-punishment-Quarantine = Colocar em quarentena
     .add-duration = duração
     .add-duration-description = Duração do timeout
+    .add-expires_after = expira_apos
+    .add-expires_after-description = Dias até a infração deixar de contar para escalonamentos
     .list = list
     .list-description = Tabela de infrações
     .remove = remove
@@ -54,8 +57,63 @@ infractions = infractions
     .edit-punishment-Timeout = Mutar o usuário
     .edit-punishment-Ban = Banimento
     .edit-punishment-Kick = Expulsar
+    .edit-punishment-Quarantine = Colocar em quarentena
     .edit-duration = duração
     .edit-duration-description = Duração do timeout
+    .edit-expires_after = expira_apos
+    .edit-expires_after-description = Dias até a infração deixar de contar para escalonamentos
+    .pardon = pardon
+    .pardon-description = Perdoa um único registro de infração de um usuário
+    .pardon-id = id
+    .pardon-id-description = ID do registro de infração do usuário
+
+config = config
+     .description = Configuração do servidor
+     .get = get
+     .get-description = Mostra o valor de uma configuração
+     .get-key = key
+     .get-key-description = Nome da configuração
+     .set = set
+     .set-description = Altera o valor de uma configuração
+     .set-key = key
+     .set-key-description = Nome da configuração
+     .set-value = value
+     .set-value-description = Novo valor da configuração
+     .reset = reset
+     .reset-description = Restaura o valor padrão de uma configuração
+     .reset-key = key
+     .reset-key-description = Nome da configuração
+     .list = list
+     .list-description = Lista todas as configurações e seus valores
+
+escalation = escalation
+     .description = Escalonamento automático de infrações repetidas
+     .add = add
+     .add-description = Pune membros ao atingirem um número de infrações (strikes diretos contam como baixa)
+     .add-severity = severidade
+     .add-severity-description = Severidade das infrações contadas
+     .add-severity-Low = Baixa severidade
+     .add-severity-Mid = Média severidade
+     .add-severity-High = Alta severidade
+     .add-threshold = limite
+     .add-threshold-description = Número de infrações que dispara o escalonamento
+     .add-days = dias
+     .add-days-description = Período em dias no qual as infrações são contadas
+     .add-punishment = punição
+     .add-punishment-description = Punição escalonada
+     .add-punishment-Strike = Advertência
+     .add-punishment-Timeout = Mutar o usuário
+     .add-punishment-Ban = Banimento
+     .add-punishment-Kick = Expulsar
+     .add-punishment-Quarantine = Colocar em quarentena
+     .add-duration = duração
+     .add-duration-description = Duração do timeout ou banimento em segundos (0 para banimento permanente)
+     .list = list
+     .list-description = Tabela de escalonamentos
+     .remove = remove
+     .remove-description = Remover escalonamento da tabela
+     .remove-id = id
+     .remove-id-description = ID do escalonamento a ser removido
 
 kick = kick
      .description = Expulsar usuários
@@ -76,6 +134,8 @@ timeout = timeout
      .unit-Minutes = Minutos (time * 60)
      .unit-Hours = Horas (time * 60 * 60)
      .unit-Days = Dias (time * 60 * 60 * 24)
+     .reason = reason
+     .reason-description = Motivo para mutar os usuários
 
 untimeout = untimeout
      .description = Desmutar usuários
@@ -88,6 +148,14 @@ ban = ban
      .users-description = Usuários que serão banidos
      .reason = reason
      .reason-description = Motivo para banir os usuários
+     .time = time
+     .time-description = Duração do banimento em números (deixe vazio para banimento permanente)
+     .unit = unit
+     .unit-description = Unidade de tempo
+     .unit-Seconds = Segundos (time * 1)
+     .unit-Minutes = Minutos (time * 60)
+     .unit-Hours = Horas (time * 60 * 60)
+     .unit-Days = Dias (time * 60 * 60 * 24)
 
 unban = unban
      .description = Desbanir usuários do servidor
@@ -110,6 +178,95 @@ punish = punish
      .reason = reason
      .reason-description = Motivo para punir os usuários
 
+case = case
+     .description = Casos de moderação
+     .view = view
+     .view-description = Mostra um caso de moderação
+     .view-id = id
+     .view-id-description = Número do caso
+     .reason = reason
+     .reason-description = Edita o motivo de um caso de moderação
+     .reason-id = id
+     .reason-id-description = Número do caso
+     .reason-reason = reason
+     .reason-reason-description = Novo motivo do caso
+     .delete = delete
+     .delete-description = Deleta um caso de moderação e seu registro
+     .delete-id = id
+     .delete-id-description = Número do caso
+
+history = history
+     .description = Mostra o histórico completo de moderação de um membro
+     .member = membro
+     .member-description = Membro do servidor
+
+lockdown = lockdown
+     .description = Bloqueio de todo o servidor
+     .start = start
+     .start-description = Bloqueia os canais de bloqueio e pausa os convites
+     .start-slowmode = slowmode
+     .start-slowmode-description = Modo lento em segundos aplicado aos canais bloqueados
+     .start-reason = reason
+     .start-reason-description = Motivo exibido nos canais bloqueados
+     .end = end
+     .end-description = Encerra o bloqueio e restaura todos os canais
+
+filter = filter
+     .description = Filtros de palavras e regex do automod
+     .add = add
+     .add-description = Bloqueia uma palavra ou um padrão regex
+     .add-kind = tipo
+     .add-kind-description = Se o padrão é uma palavra inteira ou um regex
+     .add-kind-Word = Palavra
+     .add-kind-Regex = Regex
+     .add-pattern = padrao
+     .add-pattern-description = Palavra ou padrão regex a bloquear
+     .list = list
+     .list-description = Lista os filtros do servidor
+     .remove = remove
+     .remove-description = Remove um filtro
+     .remove-id = id
+     .remove-id-description = ID do filtro
+
+allowlist = allowlist
+     .description = Domínios e convites permitidos pelo filtro de links
+     .add = add
+     .add-description = Permite um domínio ou um convite do Discord
+     .add-kind = tipo
+     .add-kind-description = Se o valor é um domínio ou um convite
+     .add-kind-Domain = Domínio
+     .add-kind-Invite = Convite
+     .add-value = valor
+     .add-value-description = Domínio, link, link de convite ou código de convite a permitir
+     .list = list
+     .list-description = Lista os itens permitidos do servidor
+     .remove = remove
+     .remove-description = Remove um item permitido
+     .remove-id = id
+     .remove-id-description = ID do item permitido
+
+language = language
+     .description = Idioma das respostas do bot
+     .set = set
+     .set-description = Escolhe o idioma em que o bot responde a você
+     .set-locale = idioma
+     .set-locale-description = Idioma, como pt-BR
+     .reset = reset
+     .reset-description = Volta a usar o idioma do servidor ou do seu cliente
+     .server = server
+     .server-description = Escolhe o idioma padrão do servidor
+     .server-locale = idioma
+     .server-locale-description = Idioma, como pt-BR
+
+schedule = schedule
+     .description = Ações de moderação agendadas
+     .list = list
+     .list-description = Lista as ações agendadas pendentes
+     .cancel = cancel
+     .cancel-description = Cancela uma ação agendada pendente
+     .cancel-id = id
+     .cancel-id-description = ID da ação agendada
+
 tag = tag
     .description = Comando de tag
     .add = add
@@ -159,4 +316,566 @@ emoji = emoji
     .remove-emoji-description = Emoji que será removido
 
 # Respostas
-Pong = Pong! :ping_pong:
\ No newline at end of file
+Pong = Pong! :ping_pong:
+
+## Compartilhadas
+punishment-name = { $punishment ->
+    [ban] Banimento
+    [kick] Expulsão
+    [timeout] Castigo
+    [quarantine] Quarentena
+   *[strike] Advertência
+}
+severity-name = { $severity ->
+    [low] Baixa
+    [mid] Média
+   *[high] Alta
+}
+case-action-name = { $action ->
+    [kick] Expulsão
+    [ban] Banimento
+    [unban] Desbanimento
+    [timeout] Castigo
+    [untimeout] Remoção de castigo
+    [quarantine] Quarentena
+   *[strike] Advertência
+}
+
+## Moderação
+moderation-no-users = Você deve informar pelo menos 1 menção ou ID de usuário válido.
+moderation-higher-role = Um dos usuários tem um cargo mais alto que o seu.
+moderation-foreign-channel = :warning: { $channel } não é um canal deste servidor!
+moderation-dm-failed = :envelope: **Não foi possível enviar DM sobre a punição para { $count ->
+    [one] o membro
+   *[other] { $count } membros
+}:** { $users }
+kick-success = :white_check_mark: **{ $count ->
+    [one] Membro expulso
+   *[other] { $count } membros expulsos
+} com sucesso:** { $users }
+kick-failed = :warning: **Falha ao expulsar { $count ->
+    [one] o membro
+   *[other] { $count } membros
+}:** { $users }
+kick-reason = :information: **Motivo da expulsão:** { $reason }
+kick-error = Falha ao executar o comando de expulsão!
+timeout-success = :white_check_mark: **{ $count ->
+    [one] Membro colocado
+   *[other] { $count } membros colocados
+} de castigo com sucesso:** { $users }
+timeout-failed = :warning: **Falha ao colocar de castigo { $count ->
+    [one] o membro
+   *[other] { $count } membros
+}:** { $users }
+timeout-duration = :information: **Duração do castigo:** { $duration }
+timeout-mute-role = :information: **Mecanismo:** Cargo de silenciamento { $role }
+timeout-discord = :information: **Mecanismo:** Castigo do Discord
+timeout-clamped = :warning: Castigos do Discord não podem passar de 28 dias, configure um `mute_role` para silenciar por mais tempo.
+timeout-reason = :information: **Motivo do castigo:** { $reason }
+timeout-invalid-duration = :warning: Essa duração é longa demais.
+untimeout-success = :white_check_mark: **Castigo removido com sucesso de { $count ->
+    [one] um membro
+   *[other] { $count } membros
+}:** { $users }
+untimeout-failed = :warning: **Falha ao remover o castigo de { $count ->
+    [one] um membro
+   *[other] { $count } membros
+}:** { $users }
+untimeout-error = Falha ao executar o comando de remoção de castigo!
+ban-invalid-duration = Você deve informar um `time` positivo junto com a sua `unit` para um banimento temporário, terminando dentro das datas suportadas.
+ban-success = :white_check_mark: **{ $count ->
+    [one] Membro banido
+   *[other] { $count } membros banidos
+} com sucesso:** { $users }
+ban-failed = :warning: **Falha ao banir { $count ->
+    [one] o membro
+   *[other] { $count } membros
+}:** { $users }
+ban-duration = :information: **Duração do banimento:** { $duration }
+ban-reason = :information: **Motivo do banimento:** { $reason }
+ban-error = Falha ao executar o comando de banimento!
+unban-success = :white_check_mark: **{ $count ->
+    [one] Membro desbanido
+   *[other] { $count } membros desbanidos
+} com sucesso:** { $users }
+unban-failed = :warning: **Falha ao desbanir { $count ->
+    [one] o membro
+   *[other] { $count } membros
+}:** { $users }
+unban-error = Falha ao executar o comando de desbanimento!
+strike-success = :white_check_mark: **{ $count ->
+    [one] Membro advertido
+   *[other] { $count } membros advertidos
+} com sucesso:** { $users }
+strike-failed = :warning: **Falha ao advertir { $count ->
+    [one] o membro
+   *[other] { $count } membros
+}:** { $users }
+strike-reason = :information: **Motivo da advertência:** { $reason }
+strike-error = Falha ao executar o comando de advertência!
+punish-unknown-infraction = Esse ID de infração não existe!
+punish-success = :white_check_mark: **{ $count ->
+    [one] Membro punido
+   *[other] { $count } membros punidos
+} com sucesso:** { $users }
+punish-failed = :warning: **Falha ao punir { $count ->
+    [one] o membro
+   *[other] { $count } membros
+}:** { $users }
+punish-type = :information: **Tipo de punição:** { $punishment }
+punish-duration = :information: **Duração da punição:** { $duration }
+punish-reason = :information: **Motivo da punição:** { $reason }
+escalation-reason = Escalonamento automático: { $count } { $count ->
+    [one] infração
+   *[other] infrações
+} de gravidade { $severity } em { $days } { $days ->
+    [one] dia
+   *[other] dias
+}
+escalation-success = :arrow_double_up: **Escalonado** { $user } para `{ $punishment }` ({ $reason })
+escalation-failed = :warning: **Falha ao escalonar** { $user } para `{ $punishment }` ({ $reason })
+slowmode-failed = :x: Falha ao ativar o modo lento no canal { $channel }!
+slowmode-success = :white_check_mark: Modo lento de `{ $seconds }` { $seconds ->
+    [one] segundo
+   *[other] segundos
+} ativado com sucesso no canal { $channel }!
+slowmode-reverts = :information: **O modo lento será revertido:** { $time }
+slowmode-schedule-failed = :warning: Falha ao agendar a reversão do modo lento!
+lock-already = :warning: O canal { $channel } já está bloqueado!
+lock-failed = :x: Falha ao bloquear o canal { $channel }!
+lock-success = :white_check_mark: Canal { $channel } bloqueado com sucesso!
+lock-unlocks = :information: **O canal será desbloqueado:** { $time }
+lock-schedule-failed = :warning: Falha ao agendar o desbloqueio do canal!
+unlock-not-locked = :warning: O canal { $channel } não está bloqueado!
+unlock-failed = :x: Falha ao desbloquear o canal { $channel }!
+unlock-success = :white_check_mark: Canal { $channel } desbloqueado com sucesso!
+clear-invalid-pattern = :warning: Padrão regex inválido!
+clear-failed = :x: Falha ao apagar as mensagens!
+clear-not-found = :warning: Nenhuma mensagem correspondente encontrada!
+clear-too-old = :information: Mensagens com mais de 14 dias não podem ser apagadas em massa.
+clear-success = :white_check_mark: `{ $count }` { $count ->
+    [one] mensagem foi apagada
+   *[other] mensagens foram apagadas
+} do canal { $channel }!
+clear-authors = :information: **Autores:** { $authors }
+clear-stopped = :information: A busca parou em mensagens com mais de 14 dias, que não podem ser apagadas em massa.
+clear-interrupted = :warning: Falha ao apagar as mensagens restantes, tente novamente para apagá-las.
+clear-archived = :information: As mensagens foram arquivadas como `#{ $archive }`.
+
+case-not-found = :warning: O caso `#{ $case }` não existe!
+case-details =
+    :information_source: Caso: #{ $case }
+    :information_source: Ação: { $action }
+    :information_source: Usuário: { $user }
+    :information_source: Moderador: { $moderator }
+    :information_source: Motivo: { $reason }
+case-no-reason = Nenhum motivo informado
+case-created = :information_source: Criado em: { $time }
+case-duration = :information_source: Duração: { $seconds } { $seconds ->
+    [one] segundo
+   *[other] segundos
+}
+case-expires = :information_source: Expira em: { $time }
+case-infraction = :information_source: ID da infração: { $id }
+case-reason-updated = :white_check_mark: Motivo do caso `#{ $case }` atualizado!
+case-deleted = :white_check_mark: Caso `#{ $case }` apagado!
+case-delete-failed = :x: Falha ao apagar o caso `#{ $case }`!
+history-failed = :x: Falha ao obter o histórico do usuário!
+history-clean = :white_check_mark: { $user } tem um histórico limpo!
+history-header = **Histórico de moderação de { $user }** ({ $count } { $count ->
+    [one] registro
+   *[other] registros
+})
+history-case = Caso `#{ $case }`
+history-no-case = Sem caso
+history-unknown = Desconhecida
+history-title = - { $case } | Punição: `{ $punishment }`
+history-pardoned = {" | "}`Perdoado`
+history-expired = {" | "}`Expirado`
+history-infraction = {"  "}ID do registro: `{ $record }` | ID da infração: `{ $infraction }` | Gravidade: `{ $severity }`
+history-reason = {"  "}Motivo: { $reason }
+history-moderator = {"  "}Moderador: { $moderator }
+history-duration = {"  "}Duração: `{ $seconds }` { $seconds ->
+    [one] segundo
+   *[other] segundos
+}
+history-date = {"  "}Data: { $time }
+history-expires = {"  "}Expira: { $time }
+lockdown-already = :warning: O servidor já está em lockdown!
+lockdown-start-failed = :x: Falha ao iniciar o lockdown!
+lockdown-locked = :white_check_mark: **Canais bloqueados com sucesso:** { $channels }
+lockdown-lock-failed = :warning: **Falha ao bloquear os canais:** { $channels }
+lockdown-reason = :information: **Motivo do lockdown:** { $reason }
+lockdown-nothing-locked = :information: Lockdown iniciado, mas não havia canais para bloquear.
+lockdown-modlog-start = `{ $locked }` { $locked ->
+    [one] canal bloqueado
+   *[other] canais bloqueados
+}, `{ $failed }` { $failed ->
+    [one] falhou
+   *[other] falharam
+}
+lockdown-notice-start = :lock: **O servidor está em lockdown.**
+lockdown-notice-start-reason = :lock: **O servidor está em lockdown.** { $reason }
+lockdown-notice-end = :unlock: **O lockdown foi encerrado.**
+lockdown-not-active = :warning: O servidor não está em lockdown!
+lockdown-end-failed = :x: Falha ao encerrar o lockdown!
+lockdown-restored = :white_check_mark: **Canais restaurados com sucesso:** { $channels }
+lockdown-restore-failed = :warning: **Falha ao restaurar os canais:** { $channels }
+lockdown-nothing-restored = :information: Lockdown encerrado, não havia canais para restaurar.
+lockdown-modlog-end = `{ $restored }` { $restored ->
+    [one] canal restaurado
+   *[other] canais restaurados
+}, `{ $failed }` { $failed ->
+    [one] falhou
+   *[other] falharam
+}
+
+## Infrações
+infractions-exists = :warning: O ID de infração `{ $id }` já existe!
+infractions-created = :white_check_mark: Infração criada!
+infractions-create-failed = :x: Falha ao criar o ID de infração `{ $id }`!
+infractions-empty = :x: Nenhuma infração encontrada!
+infractions-line = - ID: `{ $id }` | Gravidade: `{ $severity }` | Punição: `{ $punishment }` | Duração: `{ $duration }` | Expira após: `{ $expires }`
+infractions-details =
+    :information_source: ID: { $id }
+    :information_source: Gravidade: { $severity }
+    :information_source: Punição: { $punishment }
+    :information_source: Duração: { $duration }
+    :information_source: Expira após: { $expires }
+infractions-expires-days = { $days } { $days ->
+    [one] dia
+   *[other] dias
+}
+infractions-never = Nunca
+infractions-not-found = :warning: Não existe o ID de infração `{ $id }`!
+infractions-removed = :white_check_mark: { $count ->
+    [one] Infração
+   *[other] Infrações
+} de ID `{ $id }` { $count ->
+    [one] removida
+   *[other] removidas
+} com sucesso!
+infractions-remove-failed = :x: Falha ao remover o ID de infração `{ $id }`!
+infractions-updated = :white_check_mark: ID de infração `{ $id }` atualizado com sucesso!
+infractions-edit-failed = :x: Falha ao editar o ID de infração `{ $id }`!
+infractions-banned-until = :information_source: Banido até { $time }
+infractions-user-empty = :x: O usuário não tem infrações!
+infractions-record = - ID: `{ $id }` | ID do usuário: `{ $user }` | ID da infração: `{ $infraction }` | Criado em: `{ $created }`
+infractions-record-expires = Expira em: `{ $expires }`
+infractions-record-pardoned = `Perdoado`
+infractions-pardon-not-found = :warning: Não existe um registro de infração não perdoado com ID `{ $id }`!
+infractions-pardoned = :white_check_mark: Registro de infração de ID `{ $id }` perdoado!
+infractions-pardon-failed = :x: Falha ao perdoar o registro de infração de ID `{ $id }`!
+
+escalation-created = :white_check_mark: Escalonamento criado!
+escalation-create-failed = :x: Falha ao criar o escalonamento! Talvez já exista um com essa gravidade e limite.
+escalation-empty = :x: Nenhum escalonamento encontrado!
+escalation-line = - ID: `{ $id }` | `{ $threshold }` { $threshold ->
+    [one] infração
+   *[other] infrações
+} de gravidade { $severity } em `{ $days }` { $days ->
+    [one] dia
+   *[other] dias
+} | Punição: `{ $punishment }` | Duração: `{ $duration }`
+escalation-not-found = :warning: Não existe o ID de escalonamento `{ $id }`!
+escalation-removed = :white_check_mark: ID de escalonamento `{ $id }` removido com sucesso!
+escalation-remove-failed = :x: Falha ao remover o ID de escalonamento `{ $id }`!
+
+## Tags
+tag-exists = :warning: A tag `{ $name }` já existe!
+tag-created = :white_check_mark: Tag `{ $name }` criada com sucesso!
+tag-create-failed = :x: Não foi possível criar a tag `{ $name }`!
+tag-edit-failed = :x: A tag `{ $name }` não existe ou você não é o dono dela!
+tag-updated = :white_check_mark: Conteúdo da tag `{ $name }` atualizado com sucesso!
+tag-not-found = :x: A tag `{ $name }` não existe!
+tag-server-empty = :x: O servidor não tem tags!
+tag-user-empty = :x: O usuário não tem tags!
+tag-empty = :x: Nenhuma tag!
+tag-deleted = :white_check_mark: Tag `{ $name }` apagada!
+tag-not-owner = :x: Você não é o dono da tag `{ $name }`!
+
+## Emoji
+emoji-create-failed = :x: Falha ao criar o emoji `{ $name }`
+emoji-created = :white_check_mark: Emoji criado: { $emoji }
+emoji-empty = :warning: O servidor não tem emojis!
+emoji-list-failed = :x: Falha ao obter os emojis do servidor!
+emoji-delete-failed = :x: Falha ao apagar o emoji `{ $name }`
+emoji-deleted = :white_check_mark: Emoji `{ $name }` apagado com sucesso!
+
+## Figurinhas
+sticker-create-failed = :x: Falha ao criar a figurinha `{ $name }`!
+sticker-created = :white_check_mark: Figurinha `{ $name }` criada com sucesso!
+sticker-name-or-tags = :warning: Informe `name` ou `tags`!
+sticker-empty = :warning: O servidor não tem figurinhas!
+sticker-not-found = :warning: Nenhuma figurinha encontrada!
+sticker-remove-failed = :x: Falha ao remover a figurinha `{ $name }`!
+sticker-removed = :white_check_mark: Figurinha `{ $name }` removida com sucesso!
+sticker-message-empty = :x: Nenhuma figurinha encontrada na mensagem!
+sticker-image-failed = :x: Falha ao obter o formato da imagem da figurinha!
+
+## Bang
+animal-added = Novo animal adicionado: { $emoji } `{ $animal }`, que vale `{ $points } { $points ->
+    [one] ponto
+   *[other] pontos
+}`!
+animal-add-failed = Falha ao adicionar o novo animal: `{ $animal }`!
+animal-details =
+    Animal: `{ $animal }`
+    Emoji: `{ $emoji }`
+    Pontos: `{ $points }`
+animal-see-failed = Falha ao obter o animal `{ $animal }`!
+animal-removed = { $count ->
+    [0] Nenhum animal removido!
+    [one] Animal removido com sucesso!
+   *[other] Animais removidos com sucesso!
+}
+animal-remove-failed = Falha ao remover o animal: `{ $animal }`
+animal-empty = Nenhum animal!
+animal-line = - `{ $animal }` | `{ $emoji }` | `{ $points }` { $points ->
+    [one] ponto
+   *[other] pontos
+}
+bang-appeared = { $emoji } Um { $animal } selvagem apareceu!
+bang-no-channel = Não há um canal de bang configurado! Informe um canal.
+bang-no-animals = Não há animais para acertar! Adicione alguns com `/animal add` primeiro.
+bang-started = Mini-jogo bang iniciado no canal: { $channel }
+bang-shot = Boa! Você acertou um { $emoji } { $animal } e ganhou `{ $points }` { $points ->
+    [one] ponto
+   *[other] pontos
+}!
+bang-failed = Falha ao atualizar os pontos do usuário! Parando o mini-jogo bang...
+bang-unavailable = O bang ainda não está disponível!
+bang-stopped = Mini-jogo bang parado!
+bang-ranking-empty = Ninguém está no ranking!
+bang-ranking-line = - `{ $user }` | `{ $points }` { $points ->
+    [one] ponto
+   *[other] pontos
+}
+
+## Idioma
+language-unknown = :warning: Não há tradução para `{ $locale }`!
+language-set = :white_check_mark: A partir de agora vou responder você em `{ $locale }`!
+language-set-failed = :x: Falha ao salvar o seu idioma!
+language-reset = :white_check_mark: O seu idioma foi redefinido, vou seguir o idioma do servidor ou do seu cliente.
+language-reset-none = :warning: Você não escolheu um idioma!
+language-reset-failed = :x: Falha ao redefinir o seu idioma!
+language-server = :white_check_mark: O idioma padrão do servidor agora é `{ $locale }`!
+language-server-failed = :x: Falha ao atualizar o idioma do servidor!
+
+## Ações agendadas
+scheduler-ban-expired = Banimento temporário expirado
+scheduler-mute-expired = Silenciamento expirado
+scheduler-slowmode-reverted = Modo lento revertido para `{ $seconds }` { $seconds ->
+    [one] segundo
+   *[other] segundos
+}
+scheduler-lock-expired = Duração do bloqueio expirada
+
+schedule-empty = :x: Nenhuma ação agendada!
+schedule-list-failed = :x: Falha ao obter as ações agendadas!
+schedule-line = - ID: `{ $id }` | Ação: `{ $action }` | Alvo: { $target } | Moderador: { $moderator } | Executa: { $time }
+schedule-action-name = { $action ->
+    [unban] Desbanimento
+    [unmute] Fim do silenciamento
+    [slowmode] Modo lento
+   *[unlock] Desbloqueio
+}
+schedule-not-found = :warning: Não existe o ID de ação agendada `{ $id }`!
+schedule-cancelled = :white_check_mark: ID de ação agendada `{ $id }` cancelado!
+schedule-cancel-failed = :x: Falha ao cancelar o ID de ação agendada `{ $id }`!
+
+## Configuração
+config-unknown = :warning: Não existe uma configuração chamada `{ $key }`!
+config-get-failed = :x: Falha ao obter a configuração `{ $key }`!
+config-list-failed = :x: Falha ao obter as configurações!
+config-invalid = :warning: Valor inválido para `{ $key }`! { $error }
+config-foreign-channel = :warning: { $channel } não é um canal deste servidor!
+config-foreign-role = :warning: `{ $role }` não é um cargo deste servidor!
+config-set = :white_check_mark: Configuração `{ $key }` atualizada!
+config-set-failed = :x: Falha ao atualizar a configuração `{ $key }`!
+config-reset = :white_check_mark: Configuração `{ $key }` redefinida!
+config-reset-default = :warning: A configuração `{ $key }` já está usando o valor padrão!
+config-reset-failed = :x: Falha ao redefinir a configuração `{ $key }`!
+config-default = { $value } (padrão)
+config-not-set = Não definido
+config-invalid-empty = O valor não pode ser vazio!
+config-invalid-too-long = O valor não pode ter mais de { $max } caracteres!
+config-invalid-spaces = O prefixo não pode conter espaços!
+config-invalid-placeholder = Os únicos marcadores são: { $placeholders }
+config-invalid-link = O valor deve ser um link começando com `https://`!
+config-invalid-locale = O valor deve ser um idioma como `en-US`!
+config-invalid-channel = O valor deve ser uma menção ou ID de canal!
+config-invalid-channels = O valor deve ser uma lista de menções ou IDs de canais!
+config-invalid-role = O valor deve ser uma menção ou ID de cargo!
+config-invalid-roles = O valor deve ser uma lista de menções ou IDs de cargos!
+config-invalid-flag = O valor deve ser `true` ou `false`!
+config-invalid-pattern = O valor deve ser um regex válido!
+config-invalid-number = O valor deve ser um número de { $min } a { $max }!
+config-invalid-choice = O valor deve ser um de: { $choices }
+setting-prefix = Prefixo dos comandos de texto
+setting-locale = Idioma padrão das respostas do bot
+setting-mod_log_channel = Canal onde os eventos de moderação são registrados
+setting-mod_roles = Cargos considerados parte da equipe
+setting-bang_channel = Canal padrão do minijogo bang
+setting-strike_message = DM enviada aos membros quando recebem uma advertência
+setting-kick_message = DM enviada aos membros antes de serem expulsos
+setting-ban_message = DM enviada aos membros antes de serem banidos
+setting-timeout_message = DM enviada aos membros quando são castigados
+setting-quarantine_message = DM enviada aos membros quando são colocados em quarentena
+setting-infraction_message = DM enviada aos membros punidos por uma infração, no lugar da DM da punição
+setting-appeal_link = Link mostrado nas DMs de punição para recorrer delas
+setting-appeal_channel = Canal onde os recursos são enviados para revisão, os botões de recurso ficam ocultos se não definido
+setting-lockdown_channels = Canais bloqueados por um lockdown, todos os canais de texto se não definido
+setting-purge_transcripts = Enviar uma transcrição das mensagens apagadas ao registro de moderação
+setting-antiraid_threshold = Entradas dentro da janela do anti-raid que contam como raid, desativado se não definido
+setting-antiraid_window = Duração da janela do anti-raid em segundos
+setting-antiraid_action = O que fazer quando um raid é detectado
+setting-antiraid_account_age = Contas mais novas que esta quantidade de dias são castigadas ou expulsas durante um raid
+setting-quarantine_role = Cargo dado aos membros em quarentena
+setting-gate_account_age = Idade mínima da conta em dias para passar pela barreira de entrada
+setting-gate_default_avatar = Barrar na entrada membros sem um avatar personalizado
+setting-gate_username_pattern = Regex dos nomes de usuário barrados na entrada
+setting-gate_action = O que fazer com os membros barrados na entrada
+setting-automod_action = O que fazer após apagar uma mensagem pega pela moderação automática
+setting-automod_timeout = Duração em segundos dos castigos dados pela moderação automática
+setting-automod_exempt_roles = Cargos ignorados pela moderação automática, além dos cargos da equipe
+setting-automod_exempt_channels = Canais ignorados pela moderação automática
+setting-spam_filter = Apagar spam e punir os membros que o enviam
+setting-spam_window = Duração da janela de spam em segundos
+setting-spam_messages = Mensagens dentro da janela de spam que contam como flood
+setting-spam_duplicates = Mensagens idênticas dentro da janela de spam que contam como spam
+setting-spam_mentions = Menções em uma única mensagem que contam como spam
+setting-spam_emojis = Emojis em uma única mensagem que contam como spam
+setting-spam_infraction = ID da infração registrada por spam, a ação da moderação automática é usada se não definido
+setting-link_filter = Bloquear apenas convites, ou todo link fora da lista de permitidos
+setting-mute_role = Cargo usado para castigos além do limite de 28 dias do Discord
+
+## Registro de moderação
+modlog-slowmode = Modo lento de `{ $seconds }` { $seconds ->
+    [one] segundo
+   *[other] segundos
+}
+modlog-infraction = Punido pela infração de ID `{ $id }`
+modlog-action = { $action ->
+    [kick] Expulsão
+    [ban] Banimento
+    [unban] Desbanimento
+    [timeout] Castigo
+    [untimeout] Remoção de castigo
+    [strike] Advertência
+    [quarantine] Quarentena
+    [lock] Bloqueio
+    [unlock] Desbloqueio
+    [slowmode] Modo lento
+    [clear] Limpeza
+    [lockdownstart] Lockdown iniciado
+    [lockdownend] Lockdown encerrado
+    [raid] Raid
+   *[automod] Moderação automática
+}
+modlog-case-title = { $action } | Caso #{ $case }
+modlog-target = Alvo
+modlog-moderator = Moderador
+modlog-reason = Motivo
+modlog-duration = Duração
+modlog-details = Detalhes
+modlog-no-reason = Nenhum motivo informado
+modlog-entire-server = Servidor inteiro
+modlog-clear = `{ $count }` { $count ->
+    [one] mensagem apagada
+   *[other] mensagens apagadas
+}, arquivadas como `#{ $archive }`
+
+## Moderação automática
+automod-deleted = Mensagem apagada em { $channel }
+automod-warning = { $user } :warning: Sua mensagem foi removida: { $reason }
+automod-blocked-word = Palavra bloqueada `{ $word }`
+automod-blocked-pattern = Corresponde a um padrão bloqueado
+automod-invite = Convite do Discord `{ $code }`
+automod-link = Link para `{ $domain }`
+automod-mention-spam = Spam de menções: { $count } menções
+automod-emoji-spam = Spam de emojis: { $count } emojis
+automod-flood = Flood: { $count } mensagens em { $seconds } { $seconds ->
+    [one] segundo
+   *[other] segundos
+}
+automod-repeated = Repetiu a mesma mensagem { $count } vezes
+automod-spam-deleted = `{ $count }` { $count ->
+    [one] mensagem apagada
+   *[other] mensagens apagadas
+} em { $channel }
+automod-raid-reason = Anti-raid: entrou durante um raid
+automod-raid-lockdown-reason = Anti-raid: a taxa de entradas excedeu o limite
+automod-raid-detected = A taxa de entradas excedeu o limite do anti-raid
+automod-raid-details =
+    `{ $count }` membros entraram em `{ $seconds }` segundos
+    Ação: `{ $action }`
+gate-reason = Barreira de entrada: { $checks }
+gate-account-age = conta com menos de { $days } { $days ->
+    [one] dia
+   *[other] dias
+}
+gate-default-avatar = avatar padrão
+gate-username = nome de usuário corresponde ao padrão bloqueado
+
+filter-empty-pattern = :warning: O padrão não pode ser vazio!
+filter-invalid-regex = :warning: Padrão regex inválido!
+filter-created = :white_check_mark: Filtro criado!
+filter-create-failed = :x: Falha ao criar o filtro! Talvez ele já exista.
+filter-empty = :x: Nenhum filtro encontrado!
+filter-list-failed = :x: Falha ao obter os filtros!
+filter-line = - ID: `{ $id }` | { $kind ->
+    [regex] Regex
+   *[word] Palavra
+}: `{ $pattern }` | Adicionado por { $user }
+filter-not-found = :warning: Não existe o ID de filtro `{ $id }`!
+filter-removed = :white_check_mark: ID de filtro `{ $id }` removido com sucesso!
+filter-remove-failed = :x: Falha ao remover o ID de filtro `{ $id }`!
+
+## Traduções
+translations-reloaded = :white_check_mark: { $count ->
+    [one] Recarregado { $count } idioma
+   *[other] Recarregados { $count } idiomas
+}! Os nomes e descrições dos comandos só são atualizados ao reiniciar.
+translations-reload-failed = :x: Falha ao recarregar as traduções, mantendo as atuais: { $error }
+
+## DMs de punição
+dm-kick = :boot: Você foi expulso de **{ $guild }**.
+dm-ban = :hammer: Você foi banido de **{ $guild }**.
+dm-timeout = :mute: Você foi silenciado em **{ $guild }**.
+dm-strike = :warning: Você recebeu um strike em **{ $guild }**.
+dm-quarantine = :lock: Você foi colocado em quarentena em **{ $guild }**.
+dm-infraction = :scales: Você foi punido em **{ $guild }** por quebrar uma regra.
+dm-duration = **Duração:** { $duration }
+dm-reason = **Motivo:** { $reason }
+dm-case = **Caso:** #{ $case }
+dm-appeal = Você pode recorrer desta punição em { $appeal }
+dm-permanent = permanente
+dm-appeal-button = Recorrer
+
+## Recursos
+appeal-unknown-case = :warning: Este caso não existe mais ou não é seu para recorrer!
+appeal-form-title = Recurso do caso #{ $case }
+appeal-form-label = Por que a punição deveria ser retirada?
+appeal-disabled = :warning: Este servidor não aceita mais recursos.
+appeal-already = :warning: Você já recorreu deste caso!
+appeal-failed = :x: Falha ao enviar o seu recurso, tente novamente mais tarde!
+appeal-sent = :white_check_mark: O seu recurso foi enviado para a staff, você receberá uma DM quando ele for analisado.
+appeal-title = :scales: Recurso do caso #{ $case }
+appeal-field-member = Membro
+appeal-field-action = Punição
+appeal-field-reason = Motivo
+appeal-field-appeal = Recurso
+appeal-field-status = Situação
+appeal-no-reason = Nenhum motivo informado
+appeal-accept = Aceitar
+appeal-deny = Negar
+appeal-not-allowed = :warning: Apenas a staff pode analisar recursos!
+appeal-reviewed-already = :warning: Este recurso já foi analisado!
+appeal-reversal-failed = :x: Falha ao retirar a punição, o recurso continua aberto!
+appeal-status-accepted = :white_check_mark: Aceito por { $reviewer }
+appeal-status-denied = :x: Negado por { $reviewer }
+appeal-dm-failed = :envelope: Não foi possível avisar o membro por DM.
+appeal-reversal-reason = Recurso do caso #{ $case } aceito
+appeal-accepted = :white_check_mark: O seu recurso do caso #{ $case } em **{ $guild }** foi aceito, a punição foi retirada.
+appeal-denied = :x: O seu recurso do caso #{ $case } em **{ $guild }** foi negado.